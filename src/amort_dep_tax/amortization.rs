@@ -0,0 +1,860 @@
+use crate::amort_dep_tax::{AmortizationPeriod, AmortizationSummary};
+use crate::tvm::pmt;
+use crate::FloatLike;
+use crate::RoundingMode;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Prepayment
+///
+/// An extra principal payment made on top of the scheduled payment. Periods are numbered from 1 as in
+/// `AmortizationPeriod`, and extra payments are capped at the balance left after the scheduled payment.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Prepayment<T> {
+    /// A one-off extra payment of `amount` in `period`
+    Once { period: u32, amount: T },
+    /// An extra payment of `amount` every `interval` periods starting in period `start`, e.g. an interval of 1 for
+    /// monthly or 12 for annual extra payments on a monthly loan. An interval of 0 is treated as 1.
+    Recurring { start: u32, interval: u32, amount: T },
+    /// An extra payment of `percent` of the balance left after the scheduled payment, every `interval` periods
+    /// starting in period `start`, e.g. 0.01 for 1% of the balance. An interval of 0 is treated as 1.
+    BalancePercent { start: u32, interval: u32, percent: T },
+}
+
+impl<T: FloatLike> Prepayment<T> {
+    /// The extra payment due in a period given the balance left after the scheduled payment
+    fn amount(&self, period: u32, balance: T) -> T {
+        let due = |start: u32, interval: u32| period >= start && (period - start) % interval.max(1) == 0;
+        match *self {
+            Prepayment::Once {
+                period: due_period,
+                amount,
+            } if due_period == period => amount,
+            Prepayment::Recurring {
+                start,
+                interval,
+                amount,
+            } if due(start, interval) => amount,
+            Prepayment::BalancePercent {
+                start,
+                interval,
+                percent,
+            } if due(start, interval) => balance * percent,
+            _ => T::zero(),
+        }
+    }
+}
+
+/// Rate Path
+///
+/// The interest rate per period over the life of an amortizing loan. Whenever the rate resets, the scheduled
+/// payment is recast with `tvm::pmt` to pay off the remaining balance over the remaining periods, so extra
+/// principal paid before a reset lowers the payment after it rather than shortening the term.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RatePath<'a, T> {
+    /// The initial rate applies for the life of the loan
+    Fixed,
+    /// An explicit rate for each period starting with period 1, the last rate applies beyond the end of the slice.
+    /// The payment is recast whenever the rate differs from the previous period.
+    Periodic(&'a [T]),
+    /// An adjustable rate resetting to an index plus a margin, subject to caps and floors
+    Adjustable(AdjustableRate<'a, T>),
+}
+
+impl<T: FloatLike> RatePath<'_, T> {
+    /// The new rate if the rate resets in a period, given the rate of the previous period and the initial rate
+    fn reset(&self, period: u32, previous: T, initial: T) -> Option<T> {
+        match self {
+            RatePath::Fixed => None,
+            RatePath::Periodic(rates) => rates
+                .get(period as usize - 1)
+                .or(rates.last())
+                .copied()
+                .filter(|&rate| rate != previous),
+            RatePath::Adjustable(adjustable) => adjustable.reset(period, previous, initial),
+        }
+    }
+}
+
+/// Adjustable Rate
+///
+/// The reset terms of an adjustable-rate mortgage (ARM) or line of credit. The loan pays its initial rate until
+/// the first reset, then the rate resets every `reset_interval` periods to the index plus the margin, limited to a
+/// change of the initial cap at the first reset and of the periodic cap at later resets, and kept within the
+/// lifetime cap over the initial rate and the lifetime floor. The payment is recast at every reset.
+///
+/// All rates, caps and floors are per period, in the same units as the rate of the loan, e.g. 0.02 / 12 for a 2%
+/// cap on a monthly loan.
+///
+/// # Examples
+/// * 5/1 ARM, resetting after 5 years and then annually to the index plus 2.75% with 2/2/5 caps
+/// ```
+/// use rust_finprim::amort_dep_tax::{AdjustableRate, Amortization, RatePath};
+/// use rust_finprim::tvm::pmt;
+///
+/// let index = [0.04 / 12.0, 0.055 / 12.0, 0.05 / 12.0];
+/// let arm = AdjustableRate {
+///     initial_cap: Some(0.02 / 12.0),
+///     periodic_cap: Some(0.02 / 12.0),
+///     lifetime_cap: Some(0.05 / 12.0),
+///     lifetime_floor: Some(0.0275 / 12.0),
+///     ..AdjustableRate::new(&index, 0.0275 / 12.0, 61, 12)
+/// };
+///
+/// let rate = 0.045 / 12.0;
+/// let pmt = pmt(rate, 360.0, 400_000.0, None, None);
+/// let loan = Amortization::new(rate, 360, 400_000.0, pmt).with_rate_path(RatePath::Adjustable(arm));
+/// let summary = loan.summary();
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AdjustableRate<'a, T> {
+    /// The index rate at each reset in order, the last value applies to later resets.
+    pub index: &'a [T],
+
+    /// The margin added to the index.
+    pub margin: T,
+
+    /// The first period paying the reset rate, e.g. 61 for a 5/1 ARM with monthly payments.
+    pub first_reset: u32,
+
+    /// The number of periods between resets, e.g. 12 for annual resets on a monthly loan. 0 is treated as 1.
+    pub reset_interval: u32,
+
+    /// The largest change in rate at the first reset, defaults to none.
+    pub initial_cap: Option<T>,
+
+    /// The largest change in rate at each later reset, defaults to none.
+    pub periodic_cap: Option<T>,
+
+    /// The largest increase in rate over the initial rate, defaults to none.
+    pub lifetime_cap: Option<T>,
+
+    /// The lowest rate, defaults to none.
+    pub lifetime_floor: Option<T>,
+}
+
+impl<'a, T: FloatLike> AdjustableRate<'a, T> {
+    /// Creates a new `AdjustableRate` instance without caps or floors.
+    ///
+    /// # Arguments
+    /// * `index` - The index rate at each reset, the last value applies to later resets
+    /// * `margin` - The margin added to the index
+    /// * `first_reset` - The first period paying the reset rate
+    /// * `reset_interval` - The number of periods between resets
+    pub fn new(index: &'a [T], margin: T, first_reset: u32, reset_interval: u32) -> Self {
+        Self {
+            index,
+            margin,
+            first_reset,
+            reset_interval,
+            initial_cap: None,
+            periodic_cap: None,
+            lifetime_cap: None,
+            lifetime_floor: None,
+        }
+    }
+
+    /// The new rate if the rate resets in a period, given the rate of the previous period and the initial rate
+    fn reset(&self, period: u32, previous: T, initial: T) -> Option<T> {
+        let interval = self.reset_interval.max(1);
+        if period < self.first_reset || (period - self.first_reset) % interval != 0 {
+            return None;
+        }
+        let reset = ((period - self.first_reset) / interval) as usize;
+        let index = *self.index.get(reset).or(self.index.last())?;
+
+        let mut rate = index + self.margin;
+        let cap = if reset == 0 {
+            self.initial_cap
+        } else {
+            self.periodic_cap
+        };
+        if let Some(cap) = cap {
+            rate = rate.max(previous - cap).min(previous + cap);
+        }
+        if let Some(cap) = self.lifetime_cap {
+            rate = rate.min(initial + cap);
+        }
+        if let Some(floor) = self.lifetime_floor {
+            rate = rate.max(floor);
+        }
+        Some(rate)
+    }
+}
+
+/// Amortization Style
+///
+/// How the scheduled payment of an amortizing loan repays the principal.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AmortizationStyle<'a, T> {
+    /// Level payments of `pmt` (annuity), the principal portion growing as the interest falls
+    Level,
+    /// Equal principal repayments of the balance over the remaining periods (linear amortization) plus the interest,
+    /// so the payment declines over the life of the loan
+    ConstantPrincipal,
+    /// A custom principal profile, the fraction of the original principal repaid in each period starting with
+    /// period 1, plus the interest. Periods beyond the end of the slice repay no principal, and the fractions
+    /// should sum to 1 to pay off the loan.
+    Custom(&'a [T]),
+    /// A sculpted repayment where the payment of each period is the cash flow available for debt service of the
+    /// period divided by the target debt service coverage ratio (DSCR). Periods beyond the end of the slice have no
+    /// cash flow, and any interest a payment does not cover capitalizes.
+    Sculpted { cash_flows: &'a [T], dscr: T },
+}
+
+/// Amortization
+///
+/// A builder for the amortization schedule of a loan or mortgage with extra principal payments and a variable
+/// rate. Each period the scheduled payment pays the interest charged on the remaining balance and reduces the
+/// principal, then any `Prepayment` due in the period reduces the principal further. The schedule stops early once
+/// the loan is paid off, and the final payment is reduced to the remaining balance. The rate follows the
+/// `RatePath`, fixed at `rate` by default, and the payment is recast at each reset.
+///
+/// The builder also covers non-amortizing structures: interest-only periods followed by amortization over the
+/// remaining periods, balloon loans maturing before the end of the amortization term, and payment-option loans
+/// where a payment below the interest charged leaves the shortfall to capitalize into the balance until the
+/// payment is recast. Any balance left at the end of the term is paid as a balloon payment in the final period.
+///
+/// Level payments are the default, `AmortizationStyle` selects constant principal, custom and sculpted
+/// repayments instead, with the same rounding and the other options applying.
+///
+/// With a fixed rate and without prepayments the schedule matches `amort_schedule`. The `AmortizationSummary`
+/// compares the schedule with the same loan without prepayments to report the interest saved and the new payoff
+/// period.
+///
+/// # Examples
+/// * 5% rate, 30 year term (360 months), $250,000 loan, an extra $200 a month and $10,000 at the end of year 1
+/// ```
+/// use rust_finprim::amort_dep_tax::{Amortization, AmortizationPeriod, Prepayment};
+/// use rust_finprim::tvm::pmt;
+///
+/// let rate = 0.05 / 12.0;
+/// let pmt = pmt(rate, 360.0, 250_000.0, None, None);
+/// let prepayments = [
+///     Prepayment::Recurring { start: 1, interval: 1, amount: 200.0 },
+///     Prepayment::Once { period: 12, amount: 10_000.0 },
+/// ];
+/// let loan = Amortization::new(rate, 360, 250_000.0, pmt).with_prepayments(&prepayments);
+///
+/// let mut schedule = [AmortizationPeriod::default(); 360];
+/// let summary = loan.schedule_into(&mut schedule);
+/// assert_eq!(summary.payoff_period, 253);
+/// assert_eq!(summary.periods_saved, 107);
+/// assert_eq!(schedule[252].remaining_balance, 0.0);
+/// ```
+///
+/// * 7 year balloon loan amortizing over 30 years, interest-only for the first 2 years
+/// ```
+/// use rust_finprim::amort_dep_tax::{Amortization, AmortizationPeriod};
+///
+/// let rate = 0.06 / 12.0;
+/// let loan = Amortization::new(rate, 360, 1_000_000.0, 0.0)
+///     .with_interest_only(24)
+///     .with_balloon(84);
+///
+/// let mut schedule = [AmortizationPeriod::default(); 84];
+/// let summary = loan.schedule_into(&mut schedule);
+/// assert_eq!(summary.payoff_period, 84);
+/// assert_eq!(schedule[0].scheduled_payment, 5_000.0);
+/// assert!(schedule[83].balloon_payment > 900_000.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Amortization<'a, T> {
+    /// The interest rate per period, the initial rate of a variable rate loan.
+    pub rate: T,
+
+    /// The total number of payment periods.
+    pub nper: u32,
+
+    /// The principal amount of the loan, positive as a cash inflow.
+    pub principal: T,
+
+    /// The scheduled payment per period, negative as a cash outflow. It is recast at the end of the
+    /// interest-only periods, at each rate reset and at the recast period. Only used by level payments.
+    pub pmt: T,
+
+    /// How the scheduled payment repays the principal, defaults to `AmortizationStyle::Level`.
+    pub style: AmortizationStyle<'a, T>,
+
+    /// The extra principal payments, defaults to none.
+    pub prepayments: &'a [Prepayment<T>],
+
+    /// The path of the interest rate, defaults to `RatePath::Fixed`.
+    pub rate_path: RatePath<'a, T>,
+
+    /// The number of interest-only periods at the start of the loan, defaults to none. The payment is recast to
+    /// amortize the balance over the remaining periods once they end.
+    pub interest_only: u32,
+
+    /// The period the loan matures with a balloon payment of the remaining balance, defaults to none. A period of
+    /// zero or after `nper` is ignored, and any balance left at `nper` is paid as a balloon payment in the final
+    /// period, e.g. the capitalized interest of a payment-option loan that is never recast.
+    pub balloon: Option<u32>,
+
+    /// The period the payment is recast to amortize the balance over the remaining periods, defaults to none, e.g.
+    /// the end of the minimum payment periods of a payment-option loan.
+    pub recast: Option<u32>,
+
+    /// The number of decimal places, rounding strategy and epsilon `(dp, RoundingMode, epsilon)` applied to the
+    /// amounts, defaults to no rounding. The final principal payment zeroes out the balance when rounding.
+    pub round: Option<(u32, RoundingMode, T)>,
+}
+
+impl<'a, T: FloatLike> Amortization<'a, T> {
+    /// Creates a new `Amortization` instance without prepayments or rounding.
+    ///
+    /// # Arguments
+    /// * `rate` - The interest rate per period
+    /// * `nper` - The total number of payment periods
+    /// * `principal` - The principal amount of the loan (should be positive as cash inflow for a mortgage/loan)
+    /// * `pmt` - The payment amount per period (should be negative as cash outflow, can be calculated using `pmt`
+    ///   function)
+    pub fn new(rate: T, nper: u32, principal: T, pmt: T) -> Self {
+        Self {
+            rate,
+            nper,
+            principal,
+            pmt,
+            style: AmortizationStyle::Level,
+            prepayments: &[],
+            rate_path: RatePath::Fixed,
+            interest_only: 0,
+            balloon: None,
+            recast: None,
+            round: None,
+        }
+    }
+
+    /// Sets how the scheduled payment repays the principal.
+    pub fn with_style(self, style: AmortizationStyle<'a, T>) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Sets the extra principal payments.
+    pub fn with_prepayments(self, prepayments: &'a [Prepayment<T>]) -> Self {
+        Self { prepayments, ..self }
+    }
+
+    /// Sets the path of the interest rate.
+    pub fn with_rate_path(self, rate_path: RatePath<'a, T>) -> Self {
+        Self { rate_path, ..self }
+    }
+
+    /// Sets the number of interest-only periods at the start of the loan.
+    pub fn with_interest_only(self, interest_only: u32) -> Self {
+        Self { interest_only, ..self }
+    }
+
+    /// Sets the period the loan matures with a balloon payment.
+    pub fn with_balloon(self, maturity: u32) -> Self {
+        Self {
+            balloon: Some(maturity),
+            ..self
+        }
+    }
+
+    /// Sets the period the payment is recast.
+    pub fn with_recast(self, period: u32) -> Self {
+        Self {
+            recast: Some(period),
+            ..self
+        }
+    }
+
+    /// Sets the rounding of the amounts `(dp, RoundingMode, epsilon)`.
+    pub fn with_rounding(self, round: (u32, RoundingMode, T)) -> Self {
+        Self {
+            round: Some(round),
+            ..self
+        }
+    }
+
+    /// Runs the schedule with the given prepayments, passing each period to `emit`, and returns the payoff period
+    /// and the total interest charged
+    fn run<F: FnMut(AmortizationPeriod<T>)>(&self, prepayments: &[Prepayment<T>], mut emit: F) -> (u32, T) {
+        let round = |amount: T| match self.round {
+            Some((dp, rounding, epsilon)) => amount.round_with_mode(dp, rounding, epsilon),
+            None => amount,
+        };
+        let mut payment = round(-self.pmt);
+        let mut installment = round(self.principal / T::from_usize(self.nper.max(1) as usize));
+        let mut rate = self.rate;
+
+        let maturity = self
+            .balloon
+            .filter(|maturity| (1..=self.nper).contains(maturity))
+            .unwrap_or(self.nper);
+        let mut remaining_balance = self.principal;
+        let mut total_interest = T::zero();
+        for period in 1..=maturity {
+            let mut recast =
+                self.recast == Some(period) || (self.interest_only > 0 && period == self.interest_only + 1);
+            if let Some(reset) = self.rate_path.reset(period, rate, self.rate) {
+                rate = reset;
+                recast = true;
+            }
+            if recast {
+                // Recast the payment over the remaining periods
+                let remaining_periods = T::from_usize((self.nper - period + 1) as usize);
+                payment = round(-pmt(rate, remaining_periods, remaining_balance, None, None));
+                installment = round(remaining_balance / remaining_periods);
+            }
+
+            let interest = round(remaining_balance * rate);
+            let index = period as usize - 1;
+            let scheduled_payment = if period <= self.interest_only {
+                interest
+            } else {
+                match self.style {
+                    AmortizationStyle::Level => payment,
+                    AmortizationStyle::ConstantPrincipal => installment + interest,
+                    AmortizationStyle::Custom(profile) => {
+                        let fraction = profile.get(index).copied().unwrap_or(T::zero());
+                        round(self.principal * fraction) + interest
+                    }
+                    AmortizationStyle::Sculpted { cash_flows, dscr } => {
+                        round(cash_flows.get(index).copied().unwrap_or(T::zero()) / dscr)
+                    }
+                }
+            };
+            // A payment below the interest charged capitalizes the shortfall
+            let interest_payment = interest.min(scheduled_payment);
+            let capitalized_interest = interest - interest_payment;
+            remaining_balance += capitalized_interest;
+
+            let mut principal_payment = round(scheduled_payment - interest_payment);
+            let extra: T = prepayments
+                .iter()
+                .map(|prepayment| prepayment.amount(period, remaining_balance - principal_payment))
+                .sum();
+            principal_payment = (principal_payment + round(extra)).min(remaining_balance);
+            // Zero out the final balance when rounding is enabled
+            if period == self.nper && self.round.is_some() {
+                principal_payment = remaining_balance;
+            }
+            remaining_balance -= principal_payment;
+
+            let balloon_payment = if period == maturity {
+                remaining_balance.max(T::zero())
+            } else {
+                T::zero()
+            };
+            remaining_balance -= balloon_payment;
+
+            total_interest += interest;
+            emit(AmortizationPeriod {
+                period,
+                scheduled_payment,
+                principal_payment,
+                interest_payment,
+                capitalized_interest,
+                balloon_payment,
+                remaining_balance,
+            });
+            if remaining_balance <= T::zero() {
+                return (period, total_interest);
+            }
+        }
+        (maturity, total_interest)
+    }
+
+    /// Summarizes the schedule against the same loan without prepayments
+    fn summarize(&self, payoff_period: u32, total_interest: T) -> AmortizationSummary<T> {
+        let (original_payoff_period, original_interest) = self.run(&[], |_| {});
+        AmortizationSummary::new(
+            payoff_period,
+            total_interest,
+            original_interest - total_interest,
+            original_payoff_period.saturating_sub(payoff_period),
+        )
+    }
+
+    /// Amortization Schedule
+    ///
+    /// Calculates the amortization schedule, which ends in the period the loan is paid off.
+    ///
+    /// # Feature
+    /// This function requires the `std` feature to be enabled as it uses `std::Vec`. `schedule_into` can be used
+    /// in `no_std` environments as any allocation is done by the caller.
+    ///
+    /// # Returns
+    /// * A vector of `AmortizationPeriod` instances representing each period up to the payoff period.
+    #[cfg(feature = "std")]
+    pub fn schedule(&self) -> Vec<AmortizationPeriod<T>> {
+        let mut periods = Vec::with_capacity(self.nper as usize);
+        self.run(self.prepayments, |period| periods.push(period));
+        periods
+    }
+
+    /// Amortization Schedule Into
+    ///
+    /// Calculates the amortization schedule, mutating a slice of `AmortizationPeriod`.
+    ///
+    /// # Arguments
+    /// * `slice` - A mutable slice of `AmortizationPeriod` instances to be filled with the amortization schedule,
+    ///   should be `nper` long. Only the periods up to the payoff period are written, and if the slice is shorter
+    ///   than the schedule only the first periods are written.
+    ///
+    /// # Returns
+    /// * The `AmortizationSummary` of the schedule.
+    pub fn schedule_into(&self, slice: &mut [AmortizationPeriod<T>]) -> AmortizationSummary<T> {
+        let mut periods = slice.iter_mut();
+        let (payoff_period, total_interest) = self.run(self.prepayments, |period| {
+            if let Some(item) = periods.next() {
+                *item = period;
+            }
+        });
+        self.summarize(payoff_period, total_interest)
+    }
+
+    /// Amortization Summary
+    ///
+    /// The payoff period, total interest paid, interest saved and periods saved by the prepayments, without
+    /// allocating the schedule.
+    pub fn summary(&self) -> AmortizationSummary<T> {
+        let (payoff_period, total_interest) = self.run(self.prepayments, |_| {});
+        self.summarize(payoff_period, total_interest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_amortization() {
+        let rate = 0.05 / 12.0;
+        let principal = 250_000.0;
+        let pmt = crate::tvm::pmt(rate, 360.0, principal, None, None);
+
+        // Without prepayments the schedule matches `amort_schedule_into`
+        let loan = Amortization::new(rate, 360, principal, pmt);
+        let mut expected = [AmortizationPeriod::default(); 360];
+        crate::amort_dep_tax::amort_schedule_into(&mut expected, rate, principal, pmt, None);
+        let mut schedule = [AmortizationPeriod::default(); 360];
+        let summary = loan.schedule_into(&mut schedule);
+        assert_eq!(summary, AmortizationSummary::new(360, summary.total_interest, 0.0, 0));
+        for (result, expected) in schedule.iter().zip(&expected) {
+            assert!((result.remaining_balance - expected.remaining_balance).abs() < 1e-6);
+        }
+        assert_eq!(schedule[359].remaining_balance, 0.0);
+
+        struct TestCase {
+            prepayments: [Prepayment<f64>; 2],
+            // Payoff period and interest saved
+            expected: (u32, f64),
+        }
+        impl TestCase {
+            fn new(prepayments: [Prepayment<f64>; 2], expected: (u32, f64)) -> TestCase {
+                TestCase { prepayments, expected }
+            }
+        }
+        let none = Prepayment::Once { period: 0, amount: 0.0 };
+        let cases = [
+            TestCase::new(
+                [
+                    Prepayment::Recurring {
+                        start: 1,
+                        interval: 1,
+                        amount: 200.0,
+                    },
+                    Prepayment::Once {
+                        period: 12,
+                        amount: 10_000.0,
+                    },
+                ],
+                (253, 84009.05759955448),
+            ),
+            TestCase::new(
+                [
+                    Prepayment::Recurring {
+                        start: 12,
+                        interval: 12,
+                        amount: 5_000.0,
+                    },
+                    none,
+                ],
+                (219, 100328.4567076108),
+            ),
+            TestCase::new(
+                [
+                    Prepayment::BalancePercent {
+                        start: 1,
+                        interval: 1,
+                        percent: 0.001,
+                    },
+                    none,
+                ],
+                (283, 64131.33576216697),
+            ),
+        ];
+        for case in &cases {
+            let summary = loan.with_prepayments(&case.prepayments).summary();
+            let result = (summary.payoff_period, summary.interest_saved);
+            assert!(
+                result.0 == case.expected.0 && (result.1 - case.expected.1).abs() < 1e-6,
+                "Failed on case: {:?}. Expected: {:?}, Result: {:?}",
+                case.prepayments,
+                case.expected,
+                result
+            );
+            assert_eq!(summary.periods_saved, 360 - case.expected.0);
+            #[cfg(feature = "std")]
+            assert_eq!(
+                loan.with_prepayments(&case.prepayments).schedule().len(),
+                case.expected.0 as usize
+            );
+        }
+
+        // Variable rates recast the payment and still pay off at the end of the term
+        let mut rates = [0.05 / 12.0; 61];
+        rates[60] = 0.07 / 12.0;
+        let variable = loan.with_rate_path(RatePath::Periodic(&rates));
+        let mut schedule = [AmortizationPeriod::default(); 360];
+        let summary = variable.schedule_into(&mut schedule);
+        assert_eq!(summary.payoff_period, 360);
+        assert!((summary.total_interest - 317293.02507568395).abs() < 1e-6);
+        assert!(schedule[61].principal_payment + schedule[61].interest_payment > -pmt);
+
+        // 5/1 ARM with 2/2/5 caps and a 3% floor
+        let index = [0.04 / 12.0, 0.055 / 12.0, 0.075 / 12.0, 0.0];
+        let arm = AdjustableRate {
+            initial_cap: Some(0.02 / 12.0),
+            periodic_cap: Some(0.02 / 12.0),
+            lifetime_cap: Some(0.05 / 12.0),
+            lifetime_floor: Some(0.03 / 12.0),
+            ..AdjustableRate::new(&index, 0.0275 / 12.0, 61, 12)
+        };
+        let rate = 0.045 / 12.0;
+        let arm_pmt = crate::tvm::pmt(rate, 360.0, 400_000.0, None, None);
+        let arm_loan = Amortization::new(rate, 360, 400_000.0, arm_pmt).with_rate_path(RatePath::Adjustable(arm));
+        let mut schedule = [AmortizationPeriod::default(); 360];
+        let summary = arm_loan.schedule_into(&mut schedule);
+        assert!((summary.total_interest - 329488.30823237693).abs() < 1e-6);
+        assert_eq!(schedule[359].remaining_balance, 0.0);
+        // Initial cap, periodic cap, lifetime cap, then down to the floor
+        let expected = [4.5, 6.5, 8.25, 9.5, 7.5, 5.5, 3.5, 3.0, 3.0];
+        for (i, period) in [2, 61, 73, 85, 97, 109, 121, 133, 145].iter().enumerate() {
+            let balance = schedule[period - 2].remaining_balance;
+            let result = schedule[period - 1].interest_payment / balance * 1200.0;
+            assert!(
+                (result - expected[i]).abs() < 1e-9,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                period,
+                expected[i],
+                result
+            );
+        }
+
+        // Rounded schedules pay off exactly
+        let prepayments = [Prepayment::Once {
+            period: 1,
+            amount: 50_000.0,
+        }];
+        let loan = loan
+            .with_prepayments(&prepayments)
+            .with_rounding((2, RoundingMode::HalfToEven, 1e-8));
+        let mut schedule = [AmortizationPeriod::default(); 360];
+        let summary = loan.schedule_into(&mut schedule);
+        let last = schedule[summary.payoff_period as usize - 1];
+        assert_eq!(last.remaining_balance, 0.0);
+        assert!(last.principal_payment + last.interest_payment <= 1342.06);
+        let paid: f64 = schedule.iter().map(|period| period.principal_payment).sum();
+        assert!((paid - principal).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_loan_structures() {
+        let rate = 0.05 / 12.0;
+        let principal = 250_000.0;
+        let pmt = crate::tvm::pmt(rate, 360.0, principal, None, None);
+
+        struct TestCase {
+            loan: Amortization<'static, f64>,
+            // Payoff period, total interest and the period checked
+            expected: (u32, f64, AmortizationPeriod<f64>),
+        }
+        impl TestCase {
+            fn new(loan: Amortization<'static, f64>, expected: (u32, f64, AmortizationPeriod<f64>)) -> TestCase {
+                TestCase { loan, expected }
+            }
+        }
+        let structure_cases = [
+            TestCase::new(
+                Amortization::new(rate, 360, principal, 0.0).with_interest_only(120),
+                (
+                    360,
+                    270973.44352999364,
+                    AmortizationPeriod {
+                        period: 121,
+                        scheduled_payment: 1649.8893480416468,
+                        principal_payment: 608.2226813749801,
+                        interest_payment: 1041.6666666666667,
+                        capitalized_interest: 0.0,
+                        balloon_payment: 0.0,
+                        remaining_balance: 249391.777318625,
+                    },
+                ),
+            ),
+            TestCase::new(
+                Amortization::new(rate, 360, principal, pmt).with_balloon(84),
+                (
+                    84,
+                    82595.07866891703,
+                    AmortizationPeriod {
+                        period: 84,
+                        scheduled_payment: 1342.0540575303494,
+                        principal_payment: 424.19268037724567,
+                        interest_payment: 917.8613771531037,
+                        capitalized_interest: 0.0,
+                        balloon_payment: 219862.53783636764,
+                        remaining_balance: 0.0,
+                    },
+                ),
+            ),
+            TestCase::new(
+                Amortization::new(rate, 360, principal, -800.0).with_recast(61),
+                (
+                    360,
+                    265265.39825425815,
+                    AmortizationPeriod {
+                        period: 60,
+                        scheduled_payment: 800.0,
+                        principal_payment: 0.0,
+                        interest_payment: 800.0,
+                        capitalized_interest: 308.85810520001564,
+                        balloon_payment: 0.0,
+                        remaining_balance: 266434.80335320375,
+                    },
+                ),
+            ),
+            TestCase::new(
+                Amortization::new(rate, 360, principal, -800.0),
+                (
+                    360,
+                    489129.1702123558,
+                    AmortizationPeriod {
+                        period: 360,
+                        scheduled_payment: 800.0,
+                        principal_payment: 0.0,
+                        interest_payment: 800.0,
+                        capitalized_interest: 1075.2247726653768,
+                        balloon_payment: 451129.1702123558,
+                        remaining_balance: 0.0,
+                    },
+                ),
+            ),
+        ];
+        for case in &structure_cases {
+            let mut schedule = [AmortizationPeriod::default(); 360];
+            let summary = case.loan.schedule_into(&mut schedule);
+            let (payoff_period, total_interest, expected) = case.expected;
+            let result = schedule[expected.period as usize - 1];
+            let close = |a: f64, b: f64| (a - b).abs() < 1e-6;
+            assert!(
+                summary.payoff_period == payoff_period
+                    && close(summary.total_interest, total_interest)
+                    && close(result.scheduled_payment, expected.scheduled_payment)
+                    && close(result.principal_payment, expected.principal_payment)
+                    && close(result.interest_payment, expected.interest_payment)
+                    && close(result.capitalized_interest, expected.capitalized_interest)
+                    && close(result.balloon_payment, expected.balloon_payment)
+                    && close(result.remaining_balance, expected.remaining_balance),
+                "Failed on case: {:?}. Expected: {:?}, Result: {:?} {:?}",
+                case.loan,
+                case.expected,
+                summary,
+                result
+            );
+            assert!(schedule[summary.payoff_period as usize - 1].remaining_balance.abs() < 1e-6);
+        }
+
+        // A balloon after the end of the term is ignored
+        let loan = Amortization::new(rate, 360, principal, pmt);
+        assert_eq!(loan.with_balloon(400).summary(), loan.summary());
+    }
+
+    #[test]
+    fn test_amortization_styles() {
+        // Constant principal, the interest falls by the same amount every period
+        let loan = Amortization::new(0.005, 120, 120_000.0, 0.0).with_style(AmortizationStyle::ConstantPrincipal);
+        let mut schedule = [AmortizationPeriod::default(); 120];
+        let summary = loan.schedule_into(&mut schedule);
+        assert!((summary.total_interest - 36_300.0).abs() < 1e-6);
+        assert_eq!(schedule[0].scheduled_payment, 1_600.0);
+        assert!((schedule[119].scheduled_payment - 1_005.0).abs() < 1e-9);
+        assert!(schedule
+            .iter()
+            .all(|period| (period.principal_payment - 1_000.0).abs() < 1e-9));
+
+        // Rounded constant principal pays the remainder in the final period
+        let loan = Amortization::new(0.01, 7, 100_000.0, 0.0)
+            .with_style(AmortizationStyle::ConstantPrincipal)
+            .with_rounding((2, RoundingMode::HalfToEven, 1e-8));
+        let mut schedule = [AmortizationPeriod::default(); 7];
+        loan.schedule_into(&mut schedule);
+        assert_eq!(schedule[0].principal_payment, 14_285.71);
+        assert_eq!(schedule[0].interest_payment, 1_000.0);
+        assert!((schedule[6].principal_payment - 14_285.74).abs() < 1e-9);
+        assert_eq!(schedule[6].remaining_balance, 0.0);
+
+        struct TestCase {
+            style: AmortizationStyle<'static, f64>,
+            // Payoff period, total interest and principal payments
+            expected: (u32, f64, [f64; 6]),
+        }
+        impl TestCase {
+            fn new(style: AmortizationStyle<'static, f64>, expected: (u32, f64, [f64; 6])) -> TestCase {
+                TestCase { style, expected }
+            }
+        }
+        let cases = [
+            TestCase::new(
+                AmortizationStyle::Custom(&[0.1, 0.2, 0.3, 0.4]),
+                (4, 30.0, [100.0, 200.0, 300.0, 400.0, 0.0, 0.0]),
+            ),
+            TestCase::new(
+                AmortizationStyle::Custom(&[0.0, 0.5]),
+                (6, 40.0, [0.0, 500.0, 0.0, 0.0, 0.0, 0.0]),
+            ),
+        ];
+        for case in &cases {
+            let loan = Amortization::new(0.01, 6, 1_000.0, 0.0).with_style(case.style);
+            let mut schedule = [AmortizationPeriod::default(); 6];
+            let summary = loan.schedule_into(&mut schedule);
+            let principal_payments = schedule.map(|period| period.principal_payment);
+            assert!(
+                summary.payoff_period == case.expected.0
+                    && (summary.total_interest - case.expected.1).abs() < 1e-9
+                    && principal_payments
+                        .iter()
+                        .zip(case.expected.2)
+                        .all(|(result, expected)| (result - expected).abs() < 1e-9),
+                "Failed on case: {:?}. Expected: {:?}, Result: {:?}",
+                case.style,
+                case.expected,
+                (summary, principal_payments)
+            );
+        }
+
+        // Sculpted to a 1.3x DSCR, paid off early once the cash flows cover the balance
+        let cash_flows = [1_300.0, 1_560.0, 1_820.0, 1_560.0, 1_300.0, 1_300.0];
+        let loan = Amortization::new(0.05, 6, 5_000.0, 0.0).with_style(AmortizationStyle::Sculpted {
+            cash_flows: &cash_flows,
+            dscr: 1.3,
+        });
+        let mut schedule = [AmortizationPeriod::default(); 6];
+        let summary = loan.schedule_into(&mut schedule);
+        assert_eq!(summary.payoff_period, 5);
+        assert!((summary.total_interest - 773.2515625).abs() < 1e-9);
+        assert!((schedule[2].scheduled_payment - 1_400.0).abs() < 1e-9);
+        assert!((schedule[2].principal_payment - 1_236.875).abs() < 1e-9);
+        assert!((schedule[4].principal_payment - 926.90625).abs() < 1e-9);
+    }
+}
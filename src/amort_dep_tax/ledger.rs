@@ -0,0 +1,400 @@
+use crate::amort_dep_tax::LedgerEntry;
+use crate::dates::{Date, DayCount};
+use crate::FloatLike;
+use crate::RoundingMode;
+
+/// Loan Ledger
+///
+/// A daily simple interest loan ledger, as used for consumer auto and personal loans. Interest accrues on the
+/// outstanding principal between the actual payment dates under a day count convention, and is never added to the
+/// principal. Each payment pays any outstanding late fees first, then the accrued interest, then the principal, so
+/// a late payment pays more interest and less principal than an early one. Interest a payment does not cover is
+/// carried forward as unpaid interest, and any amount beyond what is owed, including payments made after the loan
+/// is paid off, is reported as an overpayment due back to the borrower.
+///
+/// Late fees are charged against a schedule of amounts due. An installment is late if the total paid by the end of
+/// the grace period after its due date is less than the total due up to and including it, and each late
+/// installment is charged one late fee. No late fees are charged once the loan is paid off.
+///
+/// # Examples
+/// * $10,000 at 12% on ACT/365F, with a $25 late fee on monthly installments of $888.49 after a 10 day grace period
+/// ```
+/// use rust_finprim::amort_dep_tax::{LedgerEntry, LoanLedger};
+/// use rust_finprim::dates::{Date, DayCount};
+///
+/// let date = |m: u8, d: u8| Date::new(2024, m, d).unwrap();
+/// let due = [(888.49, date(2, 1)), (888.49, date(3, 1)), (888.49, date(4, 1)), (888.49, date(5, 1))];
+/// let payments = [(888.49, date(2, 1)), (888.49, date(3, 5)), (888.49, date(4, 20))];
+/// let loan = LoanLedger::new(10_000.0, 0.12, date(1, 1), DayCount::Act365F, &payments)
+///     .with_late_fees(&due, 25.0, 10);
+///
+/// let mut ledger = [LedgerEntry::default(); 3];
+/// loan.ledger_into(&mut ledger);
+/// assert_eq!(ledger[2].late_fees, 25.0); // The April installment was paid after the grace period
+/// let payoff = loan.payoff(date(5, 15));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LoanLedger<'a, T> {
+    /// The principal amount of the loan, positive.
+    pub principal: T,
+
+    /// The annual simple interest rate.
+    pub rate: T,
+
+    /// The date the loan is funded and interest starts to accrue.
+    pub start: Date,
+
+    /// The day count convention used to accrue interest.
+    pub day_count: DayCount,
+
+    /// The payments made `(amount, date)`, positive and in date order.
+    pub payments: &'a [(T, Date)],
+
+    /// The installments due `(amount, due date)` in date order, defaults to none.
+    pub due: &'a [(T, Date)],
+
+    /// The fee charged for each late installment, defaults to zero.
+    pub late_fee: T,
+
+    /// The number of days after the due date before an installment is late, defaults to zero.
+    pub grace_days: u32,
+
+    /// The number of decimal places, rounding strategy and epsilon `(dp, RoundingMode, epsilon)` applied to the
+    /// interest accrued between payments, defaults to no rounding.
+    pub round: Option<(u32, RoundingMode, T)>,
+}
+
+impl<'a, T: FloatLike> LoanLedger<'a, T> {
+    /// Creates a new `LoanLedger` instance without late fees or rounding.
+    ///
+    /// # Arguments
+    /// * `principal` - The principal amount of the loan (should be positive)
+    /// * `rate` - The annual simple interest rate
+    /// * `start` - The date the loan is funded and interest starts to accrue
+    /// * `day_count` - The day count convention used to accrue interest, e.g. `DayCount::Act365F`
+    /// * `payments` - The payments made `(amount, date)`, positive and in date order
+    pub fn new(principal: T, rate: T, start: Date, day_count: DayCount, payments: &'a [(T, Date)]) -> Self {
+        Self {
+            principal,
+            rate,
+            start,
+            day_count,
+            payments,
+            due: &[],
+            late_fee: T::zero(),
+            grace_days: 0,
+            round: None,
+        }
+    }
+
+    /// Sets the installments due, the fee charged for each late installment and the grace period in days.
+    pub fn with_late_fees(self, due: &'a [(T, Date)], late_fee: T, grace_days: u32) -> Self {
+        Self {
+            due,
+            late_fee,
+            grace_days,
+            ..self
+        }
+    }
+
+    /// Sets the rounding of the interest accrued `(dp, RoundingMode, epsilon)`.
+    pub fn with_rounding(self, round: (u32, RoundingMode, T)) -> Self {
+        Self {
+            round: Some(round),
+            ..self
+        }
+    }
+
+    /// The interest accrued on a balance between two dates
+    fn accrue(&self, balance: T, start: Date, end: Date) -> T {
+        let interest = balance * self.rate * self.day_count.year_fraction(start, end);
+        match self.round {
+            Some((dp, rounding, epsilon)) => interest.round_with_mode(dp, rounding, epsilon),
+            None => interest,
+        }
+    }
+
+    /// Charges late fees on the installments whose grace period ended before a date, given the total paid before
+    /// the date, moving `next` past them and adding them to the total due
+    fn late_fees(&self, date: Date, paid: T, next: &mut usize, total_due: &mut T) -> T {
+        let mut fees = T::zero();
+        while let Some(&(amount, due_date)) = self.due.get(*next) {
+            if due_date.add_days(self.grace_days as i32) >= date {
+                break;
+            }
+            *total_due += amount;
+            *next += 1;
+            if paid < *total_due {
+                fees += self.late_fee;
+            }
+        }
+        fees
+    }
+
+    /// Applies the payments made on or before `until`, all of them if `None`, passing each entry to `emit`. Returns
+    /// the remaining balance, unpaid interest and unpaid fees after the last payment, with the late fees charged
+    /// before `until`, and the date of the last payment.
+    fn run<F: FnMut(LedgerEntry<T>)>(&self, until: Option<Date>, mut emit: F) -> (T, T, T, Date) {
+        let mut remaining_balance = self.principal;
+        let mut unpaid_interest = T::zero();
+        let mut unpaid_fees = T::zero();
+        let mut last_date = self.start;
+        let (mut paid, mut total_due, mut next) = (T::zero(), T::zero(), 0);
+
+        for &(payment, date) in self.payments {
+            if until.is_some_and(|until| date > until) {
+                break;
+            }
+            let late_fees = if remaining_balance > T::zero() {
+                self.late_fees(date, paid, &mut next, &mut total_due)
+            } else {
+                T::zero()
+            };
+            let interest_accrued = self.accrue(remaining_balance, last_date, date);
+            unpaid_fees += late_fees;
+            unpaid_interest += interest_accrued;
+
+            // Fees first, then interest, then principal
+            let fee_payment = payment.min(unpaid_fees);
+            let interest_payment = (payment - fee_payment).min(unpaid_interest);
+            let principal_payment = (payment - fee_payment - interest_payment).min(remaining_balance);
+            let overpayment = payment - fee_payment - interest_payment - principal_payment;
+            unpaid_fees -= fee_payment;
+            unpaid_interest -= interest_payment;
+            remaining_balance -= principal_payment;
+
+            emit(LedgerEntry {
+                date,
+                payment,
+                days: self.day_count.day_count(last_date, date),
+                interest_accrued,
+                late_fees,
+                fee_payment,
+                interest_payment,
+                principal_payment,
+                overpayment,
+                unpaid_interest,
+                unpaid_fees,
+                remaining_balance,
+            });
+            paid += payment;
+            last_date = date;
+        }
+
+        if let Some(until) = until {
+            if remaining_balance > T::zero() {
+                unpaid_fees += self.late_fees(until, paid, &mut next, &mut total_due);
+            }
+        }
+        (remaining_balance, unpaid_interest, unpaid_fees, last_date)
+    }
+
+    /// Loan Ledger
+    ///
+    /// Calculates the breakdown of each payment into late fees, interest, principal and any overpayment.
+    ///
+    /// # Feature
+    /// This function requires the `std` feature to be enabled as it uses `std::Vec`. `ledger_into` can be used
+    /// in `no_std` environments as any allocation is done by the caller.
+    ///
+    /// # Returns
+    /// * A vector of `LedgerEntry` instances, one for each payment.
+    #[cfg(feature = "std")]
+    pub fn ledger(&self) -> Vec<LedgerEntry<T>> {
+        let mut entries = Vec::with_capacity(self.payments.len());
+        self.run(None, |entry| entries.push(entry));
+        entries
+    }
+
+    /// Loan Ledger Into
+    ///
+    /// Calculates the breakdown of each payment into late fees, interest, principal and any overpayment, mutating a
+    /// slice of `LedgerEntry`.
+    ///
+    /// # Arguments
+    /// * `slice` - A mutable slice of `LedgerEntry` instances to be filled with the ledger, should be as long as the
+    ///   payments. If the slice is shorter only the first entries are written.
+    pub fn ledger_into(&self, slice: &mut [LedgerEntry<T>]) {
+        let mut entries = slice.iter_mut();
+        self.run(None, |entry| {
+            if let Some(item) = entries.next() {
+                *item = entry;
+            }
+        });
+    }
+
+    /// Payoff Amount
+    ///
+    /// The amount that pays off the loan on a date, taking into account the payments made on or before the date:
+    /// the remaining principal, the unpaid interest, the interest accrued since the last payment and any unpaid
+    /// late fees, including those charged on installments late by the date.
+    ///
+    /// # Arguments
+    /// * `date` - The payoff date
+    ///
+    /// # Formula
+    /// $$Payoff = B + I_{unpaid} + B r \tau(t_{last}, t) + F_{unpaid}$$
+    ///
+    /// Where:
+    /// * \\(B\\) = remaining principal balance
+    /// * \\(r\\) = annual interest rate
+    /// * \\(\tau(t_{last}, t)\\) = year fraction from the last payment, or the start, to the payoff date
+    pub fn payoff(&self, date: Date) -> T {
+        let (remaining_balance, unpaid_interest, unpaid_fees, last_date) = self.run(Some(date), |_| {});
+        let interest_accrued = if date > last_date {
+            self.accrue(remaining_balance, last_date, date)
+        } else {
+            T::zero()
+        };
+        remaining_balance + unpaid_interest + interest_accrued + unpaid_fees
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_loan_ledger() {
+        let date = |y: i32, m: u8, d: u8| Date::new(y, m, d).unwrap();
+        let mut due = [(888.49, date(2025, 1, 1)); 12];
+        for (m, installment) in due.iter_mut().take(11).enumerate() {
+            installment.1 = date(2024, m as u8 + 2, 1);
+        }
+        let payments = [
+            (888.49, date(2024, 2, 1)),
+            (888.49, date(2024, 3, 5)),  // Within the grace period
+            (888.49, date(2024, 4, 20)), // Late
+            (2_000.0, date(2024, 5, 1)), // Early
+            (10.0, date(2024, 5, 20)),   // Short of the interest
+        ];
+        let loan = LoanLedger::new(10_000.0, 0.12, date(2024, 1, 1), DayCount::Act365F, &payments)
+            .with_late_fees(&due, 25.0, 10);
+
+        let mut ledger = [LedgerEntry::default(); 5];
+        loan.ledger_into(&mut ledger);
+        #[cfg(feature = "std")]
+        assert!(loan.ledger() == ledger);
+        // Days, interest accrued, late fees, interest paid, principal paid, unpaid interest and remaining balance
+        let expected = [
+            (
+                31,
+                101.91780821917808,
+                0.0,
+                101.91780821917808,
+                786.5721917808219,
+                0.0,
+                9213.427808219178,
+            ),
+            (
+                33,
+                99.95938115218614,
+                0.0,
+                99.95938115218614,
+                788.5306188478139,
+                0.0,
+                8424.897189371364,
+            ),
+            (
+                46,
+                127.41214379542446,
+                25.0,
+                127.41214379542446,
+                736.0778562045756,
+                0.0,
+                7688.819333166789,
+            ),
+            (
+                11,
+                27.806141150082635,
+                0.0,
+                27.806141150082635,
+                1972.1938588499174,
+                0.0,
+                5716.625474316872,
+            ),
+            (
+                19,
+                35.70933172997936,
+                0.0,
+                10.0,
+                0.0,
+                25.70933172997936,
+                5716.625474316872,
+            ),
+        ];
+        for (entry, expected) in ledger.iter().zip(&expected) {
+            let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+            assert!(
+                entry.days == expected.0
+                    && close(entry.interest_accrued, expected.1)
+                    && close(entry.late_fees, expected.2)
+                    && close(entry.fee_payment, expected.2)
+                    && close(entry.interest_payment, expected.3)
+                    && close(entry.principal_payment, expected.4)
+                    && close(entry.unpaid_interest, expected.5)
+                    && close(entry.remaining_balance, expected.6),
+                "Failed on case: {}. Expected: {:?}, Result: {:?}",
+                entry.date,
+                expected,
+                entry
+            );
+        }
+
+        struct TestCase {
+            date: Date,
+            expected: f64,
+        }
+        impl TestCase {
+            fn new(date: Date, expected: f64) -> TestCase {
+                TestCase { date, expected }
+            }
+        }
+        let cases = [
+            TestCase::new(date(2024, 6, 15), 5791.20020736156),
+            // The April installment is late and unpaid
+            TestCase::new(date(2024, 4, 15), 8563.460187102068),
+            TestCase::new(date(2024, 1, 1), 10_000.0),
+        ];
+        for case in &cases {
+            let result = loan.payoff(case.date);
+            assert!(
+                (result - case.expected).abs() < 1e-9,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.date,
+                case.expected,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_overpayment() {
+        let date = |m: u8, d: u8| Date::new(2024, m, d).unwrap();
+        let payments = [
+            (2_000.0, date(2, 1)), // More than the payoff amount
+            (50.0, date(3, 1)),    // After the loan is paid off
+        ];
+        let loan = LoanLedger::new(1_000.0, 0.12, date(1, 1), DayCount::Act365F, &payments);
+
+        let mut ledger = [LedgerEntry::default(); 2];
+        loan.ledger_into(&mut ledger);
+        let interest = 1_000.0 * 0.12 * 31.0 / 365.0;
+        assert!((ledger[0].interest_payment - interest).abs() < 1e-9);
+        assert_eq!(ledger[0].principal_payment, 1_000.0);
+        assert!((ledger[0].overpayment - (1_000.0 - interest)).abs() < 1e-9);
+        assert_eq!(ledger[0].remaining_balance, 0.0);
+
+        // Nothing is owed, so the whole payment is an overpayment
+        assert_eq!(ledger[1].interest_accrued, 0.0);
+        assert_eq!(ledger[1].principal_payment, 0.0);
+        assert_eq!(ledger[1].overpayment, 50.0);
+        assert_eq!(ledger[1].remaining_balance, 0.0);
+        assert_eq!(loan.payoff(date(3, 15)), 0.0);
+    }
+}
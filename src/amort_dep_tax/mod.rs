@@ -2,13 +2,19 @@
 
 // Structs
 mod structs;
-pub use structs::{AmortizationPeriod, DepreciationPeriod};
+pub use structs::{AmortizationPeriod, AmortizationSummary, DepreciationPeriod, LedgerEntry};
 
 // Amortization
 mod amort;
 #[cfg(feature = "std")]
 pub use amort::amort_schedule;
 pub use amort::amort_schedule_into;
+mod amortization;
+pub use amortization::{AdjustableRate, Amortization, AmortizationStyle, Prepayment, RatePath};
+
+// Loan Ledgers
+mod ledger;
+pub use ledger::LoanLedger;
 
 // Depreciation
 mod dep;
@@ -39,6 +45,11 @@ mod tests {
         let clone = period.clone();
         assert_eq!(period, clone);
 
+        let summary: AmortizationSummary<f64> = AmortizationSummary::new(253, 149130.4, 84009.06, 107);
+        let serialized = serde_json::to_string(&summary).unwrap();
+        let deserialized: AmortizationSummary<f64> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(summary, deserialized);
+
         let dep_period: DepreciationPeriod<f64> = DepreciationPeriod::new(1, 100.0, 900.0);
         let serialized = serde_json::to_string(&dep_period).unwrap();
         let deserialized: DepreciationPeriod<f64> = serde_json::from_str(&serialized).unwrap();
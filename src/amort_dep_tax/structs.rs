@@ -1,3 +1,4 @@
+use crate::dates::Date;
 use crate::FloatLike;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -6,21 +7,30 @@ use serde::{Deserialize, Serialize};
 ///
 /// Represents a single period in an amortization schedule.
 ///
-/// An amortization period includes information about the payment period, the portion
-/// of the payment allocated to principal, the portion allocated to interest, and the
-/// remaining balance of the loan or mortgage.
+/// An amortization period includes information about the payment period, the scheduled payment, the portion
+/// of the payment allocated to principal, the portion allocated to interest, any interest added to the balance
+/// or balloon payment, and the remaining balance of the loan or mortgage.
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AmortizationPeriod<T> {
     /// The period number of the amortization schedule.
     pub period: u32,
 
+    /// The scheduled payment for the period, the interest for an interest-only period.
+    pub scheduled_payment: T,
+
     /// The amount of the payment allocated to reduce the principal balance.
     pub principal_payment: T,
 
     /// The amount of the payment allocated to pay interest charges.
     pub interest_payment: T,
 
+    /// The interest charged but not paid by the payment, added to the principal balance (negative amortization).
+    pub capitalized_interest: T,
+
+    /// The balloon payment of the remaining balance at maturity, in addition to the principal payment.
+    pub balloon_payment: T,
+
     /// The remaining balance of the loan or mortgage after the payment.
     pub remaining_balance: T,
 }
@@ -28,6 +38,9 @@ pub struct AmortizationPeriod<T> {
 impl<T: FloatLike> AmortizationPeriod<T> {
     /// Creates a new `AmortizationPeriod` instance.
     ///
+    /// The scheduled payment is the sum of the principal and interest payments, with no capitalized interest or
+    /// balloon payment.
+    ///
     /// # Arguments
     /// * `period`: The period number of the amortization schedule.
     /// * `principal_payment`: The amount allocated to reduce the principal balance.
@@ -44,12 +57,16 @@ impl<T: FloatLike> AmortizationPeriod<T> {
     /// use rust_finprim::amort_dep_tax::AmortizationPeriod;
     ///
     /// let period = AmortizationPeriod::new(1, 100.0, 50.0, 850.0);
+    /// assert_eq!(period.scheduled_payment, 150.0);
     /// ```
     pub fn new(period: u32, principal_payment: T, interest_payment: T, remaining_balance: T) -> Self {
         Self {
             period,
+            scheduled_payment: principal_payment + interest_payment,
             principal_payment,
             interest_payment,
+            capitalized_interest: T::zero(),
+            balloon_payment: T::zero(),
             remaining_balance,
         }
     }
@@ -58,8 +75,119 @@ impl<T: FloatLike> AmortizationPeriod<T> {
     pub fn default() -> Self {
         Self {
             period: 0,
+            scheduled_payment: T::zero(),
             principal_payment: T::zero(),
             interest_payment: T::zero(),
+            capitalized_interest: T::zero(),
+            balloon_payment: T::zero(),
+            remaining_balance: T::zero(),
+        }
+    }
+}
+
+/// Amortization Summary
+///
+/// Summarizes an amortization schedule with extra principal payments against the same loan without them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AmortizationSummary<T> {
+    /// The period in which the loan is paid off, or the last period if it is not paid off.
+    pub payoff_period: u32,
+
+    /// The total interest charged over the schedule, including any capitalized interest.
+    pub total_interest: T,
+
+    /// The interest saved compared to the schedule without extra principal payments.
+    pub interest_saved: T,
+
+    /// The number of periods saved compared to the schedule without extra principal payments.
+    pub periods_saved: u32,
+}
+
+impl<T: FloatLike> AmortizationSummary<T> {
+    /// Creates a new `AmortizationSummary` instance.
+    ///
+    /// # Arguments
+    /// * `payoff_period`: The period in which the loan is paid off.
+    /// * `total_interest`: The total interest charged over the schedule.
+    /// * `interest_saved`: The interest saved compared to the schedule without extra principal payments.
+    /// * `periods_saved`: The number of periods saved compared to the schedule without extra principal payments.
+    ///
+    /// # Returns
+    ///
+    /// A new `AmortizationSummary` instance initialized with the provided values.
+    pub fn new(payoff_period: u32, total_interest: T, interest_saved: T, periods_saved: u32) -> Self {
+        Self {
+            payoff_period,
+            total_interest,
+            interest_saved,
+            periods_saved,
+        }
+    }
+}
+
+/// Ledger Entry
+///
+/// Represents a single payment in a daily simple interest loan ledger.
+///
+/// A ledger entry includes the payment date and amount, the interest accrued and late fees charged since the
+/// previous payment, the portions of the payment allocated to fees, interest and principal, and the unpaid
+/// interest, unpaid fees and remaining balance after the payment.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LedgerEntry<T> {
+    /// The date of the payment.
+    pub date: Date,
+
+    /// The amount of the payment.
+    pub payment: T,
+
+    /// The number of days of interest accrued since the previous payment under the day count convention.
+    pub days: i32,
+
+    /// The interest accrued since the previous payment.
+    pub interest_accrued: T,
+
+    /// The late fees charged since the previous payment.
+    pub late_fees: T,
+
+    /// The amount of the payment allocated to pay late fees.
+    pub fee_payment: T,
+
+    /// The amount of the payment allocated to pay interest.
+    pub interest_payment: T,
+
+    /// The amount of the payment allocated to reduce the principal balance.
+    pub principal_payment: T,
+
+    /// The amount of the payment beyond the fees, interest and principal owed, due back to the borrower.
+    pub overpayment: T,
+
+    /// The accrued interest left unpaid after the payment.
+    pub unpaid_interest: T,
+
+    /// The late fees left unpaid after the payment.
+    pub unpaid_fees: T,
+
+    /// The remaining principal balance of the loan after the payment.
+    pub remaining_balance: T,
+}
+
+/// Default implementation for `LedgerEntry`, dated at day number zero (1899-12-30).
+impl<T: FloatLike> Default for LedgerEntry<T> {
+    fn default() -> Self {
+        Self {
+            date: Date::from_day_number(0),
+            payment: T::zero(),
+            days: 0,
+            interest_accrued: T::zero(),
+            late_fees: T::zero(),
+            fee_payment: T::zero(),
+            interest_payment: T::zero(),
+            principal_payment: T::zero(),
+            overpayment: T::zero(),
+            unpaid_interest: T::zero(),
+            unpaid_fees: T::zero(),
             remaining_balance: T::zero(),
         }
     }
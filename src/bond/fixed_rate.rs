@@ -0,0 +1,721 @@
+use crate::dates::{Date, DayCount, Frequency};
+use crate::utils::halley;
+use crate::FinPrimError;
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fixed Rate Bond
+///
+/// A bullet bond paying a fixed coupon at a regular frequency and redeemed at maturity, settled on a given date.
+///
+/// Coupon dates are rolled back from the maturity, following the end-of-month rule when the maturity is the last
+/// day of its month, as Excel does. Prices and accrued interest are amounts for the face value of the bond, a face
+/// value of 100 gives quotes per 100 like the Excel `PRICE`, `YIELD` and `ACCRINT` functions.
+///
+/// The day count conventions correspond to the Excel `basis` argument as follows:
+/// * 0 (US 30/360) - `DayCount::Thirty360`
+/// * 1 (Actual/actual) - `DayCount::ActActIcma`
+/// * 2 (Actual/360) - `DayCount::Act360`
+/// * 3 (Actual/365) - `DayCount::Act365F`
+/// * 4 (European 30/360) - `DayCount::ThirtyE360`
+///
+/// # Examples
+/// * 10 year 5.75% semi-annual bond priced at a 6.5% yield
+/// ```
+/// use rust_finprim::bond::FixedRateBond;
+/// use rust_finprim::dates::{Date, DayCount, Frequency};
+///
+/// let bond = FixedRateBond::new(
+///     100.0,
+///     0.0575,
+///     Frequency::SemiAnnual,
+///     Date::new(2008, 2, 15).unwrap(),
+///     Date::new(2017, 11, 15).unwrap(),
+///     DayCount::Thirty360,
+/// );
+/// let price = bond.clean_price(0.065);
+/// let yld = bond.yield_from_price(price, None, None, None).unwrap();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedRateBond<T> {
+    /// The face (par) value of the bond.
+    pub face: T,
+
+    /// The annual coupon rate.
+    pub coupon_rate: T,
+
+    /// The coupon frequency.
+    pub frequency: Frequency,
+
+    /// The settlement date, the date the bond is traded to the buyer.
+    pub settlement: Date,
+
+    /// The maturity date, the date of the final coupon and redemption.
+    pub maturity: Date,
+
+    /// The day count convention used to accrue interest.
+    pub day_count: DayCount,
+
+    /// The amount repaid at maturity, the face value unless set otherwise.
+    pub redemption: T,
+}
+
+impl<T: FloatLike> FixedRateBond<T> {
+    /// Creates a new `FixedRateBond` instance redeemed at its face value.
+    ///
+    /// # Arguments
+    /// * `face` - The face (par) value of the bond
+    /// * `coupon_rate` - The annual coupon rate
+    /// * `frequency` - The coupon frequency
+    /// * `settlement` - The settlement date, should be before the maturity
+    /// * `maturity` - The maturity date
+    /// * `day_count` - The day count convention used to accrue interest
+    pub fn new(
+        face: T,
+        coupon_rate: T,
+        frequency: Frequency,
+        settlement: Date,
+        maturity: Date,
+        day_count: DayCount,
+    ) -> Self {
+        Self {
+            face,
+            coupon_rate,
+            frequency,
+            settlement,
+            maturity,
+            day_count,
+            redemption: face,
+        }
+    }
+
+    /// The coupon amount paid each period
+    pub fn coupon(&self) -> T {
+        self.face * self.coupon_rate / T::from_u16(self.frequency.periods_per_year())
+    }
+
+    /// The last coupon date on or before settlement, similar to the Excel `COUPPCD` function
+    pub fn previous_coupon_date(&self) -> Date {
+        self.coupon_date(self.coupons_remaining() as i32)
+    }
+
+    /// The first coupon date after settlement, similar to the Excel `COUPNCD` function
+    pub fn next_coupon_date(&self) -> Date {
+        self.coupon_date(self.coupons_remaining() as i32 - 1)
+    }
+
+    /// The number of coupons paid after settlement up to and including maturity, similar to the Excel
+    /// `COUPNUM` function. Zero if the bond settles on or after maturity.
+    pub fn coupons_remaining(&self) -> u32 {
+        let days = self.settlement.days_until(self.maturity);
+        if days <= 0 {
+            return 0;
+        }
+        // Estimate the count from the days to maturity, then step to the first coupon date on or before
+        // settlement, which takes at most a couple of steps
+        let mut n = days * self.frequency.periods_per_year() as i32 / 365;
+        while n > 0 && self.coupon_date(n - 1) <= self.settlement {
+            n -= 1;
+        }
+        while self.coupon_date(n) > self.settlement {
+            n += 1;
+        }
+        n as u32
+    }
+
+    /// Accrued Interest
+    ///
+    /// The coupon interest accrued from the previous coupon date to settlement, owed by the buyer to the seller,
+    /// similar to the Excel `ACCRINT` function with the previous coupon date as the issue date.
+    ///
+    /// # Examples
+    /// * $1000 10% semi-annual 30/360 bond settling 2 months into a coupon period
+    /// ```
+    /// use rust_finprim::bond::FixedRateBond;
+    /// use rust_finprim::dates::{Date, DayCount, Frequency};
+    ///
+    /// let bond = FixedRateBond::new(
+    ///     1000.0,
+    ///     0.1,
+    ///     Frequency::SemiAnnual,
+    ///     Date::new(2008, 5, 1).unwrap(),
+    ///     Date::new(2010, 9, 1).unwrap(),
+    ///     DayCount::Thirty360,
+    /// );
+    /// let accrued = bond.accrued_interest(); // 16.67
+    /// ```
+    ///
+    /// # Formula
+    /// $$AI = C \frac{A}{E}$$
+    ///
+    /// Where:
+    /// * \\(C\\) = coupon amount per period
+    /// * \\(A\\) = days from the previous coupon date to settlement under the day count convention
+    /// * \\(E\\) = days in the coupon period under the day count convention
+    pub fn accrued_interest(&self) -> T {
+        self.accrued_interest_with(self.coupons_remaining())
+    }
+
+    /// Dirty Price
+    ///
+    /// The full price of the bond at a yield, the present value of the remaining coupons and the redemption
+    /// including accrued interest.
+    ///
+    /// # Arguments
+    /// * `yld` - The annual yield to maturity, compounded at the coupon frequency
+    ///
+    /// # Returns
+    /// * The dirty price for the face value of the bond, zero if the bond settles on or after maturity
+    ///
+    /// # Formula
+    /// With more than one coupon remaining:
+    /// $$P = \sum_{k=1}^{N} \frac{C}{(1+\frac{y}{f})^{k-1+\frac{DSC}{E}}} + \frac{R}{(1+\frac{y}{f})^{N-1+\frac{DSC}{E}}}$$
+    ///
+    /// In the final coupon period simple interest is used:
+    /// $$P = \frac{C + R}{1 + \frac{DSC}{E}\frac{y}{f}}$$
+    ///
+    /// Where:
+    /// * \\(C\\) = coupon amount per period
+    /// * \\(R\\) = redemption amount
+    /// * \\(y\\) = yield to maturity
+    /// * \\(f\\) = coupon frequency
+    /// * \\(N\\) = number of coupons remaining
+    /// * \\(DSC\\) = days from settlement to the next coupon date
+    /// * \\(E\\) = days in the coupon period
+    pub fn dirty_price(&self, yld: T) -> T {
+        self.dirty_price_derivatives(yld, self.coupons_remaining()).0
+    }
+
+    /// Clean Price
+    ///
+    /// The quoted price of the bond at a yield, the dirty price less accrued interest, similar to the Excel
+    /// `PRICE` function.
+    ///
+    /// # Arguments
+    /// * `yld` - The annual yield to maturity, compounded at the coupon frequency
+    ///
+    /// # Returns
+    /// * The clean price for the face value of the bond
+    ///
+    /// # Examples
+    /// * 5.75% semi-annual bond priced at a 6.5% yield
+    /// ```
+    /// use rust_finprim::bond::FixedRateBond;
+    /// use rust_finprim::dates::{Date, DayCount, Frequency};
+    ///
+    /// let bond = FixedRateBond::new(
+    ///     100.0,
+    ///     0.0575,
+    ///     Frequency::SemiAnnual,
+    ///     Date::new(2008, 2, 15).unwrap(),
+    ///     Date::new(2017, 11, 15).unwrap(),
+    ///     DayCount::Thirty360,
+    /// );
+    /// let price = bond.clean_price(0.065); // 94.63436
+    /// ```
+    pub fn clean_price(&self, yld: T) -> T {
+        let n = self.coupons_remaining();
+        self.dirty_price_derivatives(yld, n).0 - self.accrued_interest_with(n)
+    }
+
+    /// Yield from Price
+    ///
+    /// The annual yield to maturity implied by a clean price, similar to the Excel `YIELD` function.
+    ///
+    /// # Arguments
+    /// * `clean_price` - The clean price for the face value of the bond
+    /// * `guess` (optional) - A guess for the yield, defaults to the coupon rate
+    /// * `tolerance` (optional) - The tolerance/maximum error bound for the price per unit of face value, defaults to
+    ///   1e-10
+    /// * `max_iter` (optional) - The maximum number of iterations to perform, defaults to 20.
+    ///
+    /// # Returns
+    /// * Result of the yield calculation, compounded at the coupon frequency
+    /// * `FinPrimError::NoSolution` if the bond settles on or after maturity
+    /// * If the calculation fails, it returns a `FinPrimError::RootFindingError` with the last estimated yield and
+    ///   the pricing error at that yield
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::bond::FixedRateBond;
+    /// use rust_finprim::dates::{Date, DayCount, Frequency};
+    ///
+    /// let bond = FixedRateBond::new(
+    ///     100.0,
+    ///     0.0575,
+    ///     Frequency::SemiAnnual,
+    ///     Date::new(2008, 2, 15).unwrap(),
+    ///     Date::new(2016, 11, 15).unwrap(),
+    ///     DayCount::Thirty360,
+    /// );
+    /// let yld = bond.yield_from_price(95.04287, None, None, None); // 0.065
+    /// ```
+    ///
+    /// In the final coupon period the yield is solved in closed form, otherwise this function uses the Halley
+    /// method with the analytic derivatives of the price, maxing out at 20 iterations by default.
+    pub fn yield_from_price(
+        &self,
+        clean_price: T,
+        guess: Option<T>,
+        tolerance: Option<T>,
+        max_iter: Option<u16>,
+    ) -> Result<T, FinPrimError<T>> {
+        let n = self.coupons_remaining();
+        if n == 0 {
+            return Err(FinPrimError::NoSolution);
+        }
+        let dirty_price = clean_price + self.accrued_interest_with(n);
+        let f = T::from_u16(self.frequency.periods_per_year());
+
+        if n == 1 {
+            let (_, period_days, days_to_coupon) = self.period_days(n);
+            let w = days_to_coupon / period_days;
+            return Ok(((self.coupon() + self.redemption) / dirty_price - T::one()) * f / w);
+        }
+
+        let guess = guess.unwrap_or(self.coupon_rate);
+        let tolerance = tolerance.unwrap_or(T::from_f32(1e-10));
+        let max_iter = max_iter.unwrap_or(20);
+        halley(
+            guess,
+            |y| (self.dirty_price_derivatives(y, n).0 - dirty_price) / self.face,
+            |y| self.dirty_price_derivatives(y, n).1 / self.face,
+            |y| self.dirty_price_derivatives(y, n).2 / self.face,
+            tolerance,
+            max_iter,
+        )
+    }
+
+    /// Macaulay Duration
+    ///
+    /// The present value weighted average time to the remaining cash flows in years, similar to the Excel
+    /// `DURATION` function.
+    ///
+    /// # Arguments
+    /// * `yld` - The annual yield to maturity, compounded at the coupon frequency
+    ///
+    /// # Returns
+    /// * The Macaulay duration in years, zero if the bond settles on or after maturity
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::bond::FixedRateBond;
+    /// use rust_finprim::dates::{Date, DayCount, Frequency};
+    ///
+    /// let bond = FixedRateBond::new(
+    ///     100.0,
+    ///     0.08,
+    ///     Frequency::SemiAnnual,
+    ///     Date::new(2018, 7, 1).unwrap(),
+    ///     Date::new(2048, 1, 1).unwrap(),
+    ///     DayCount::ActActIcma,
+    /// );
+    /// let duration = bond.macaulay_duration(0.09); // 10.91915
+    /// ```
+    ///
+    /// # Formula
+    /// $$D_{mac} = \frac{1}{f} \frac{\sum_{k=1}^{N} t_k PV_k}{\sum_{k=1}^{N} PV_k}$$
+    ///
+    /// Where:
+    /// * \\(t_k\\) = time to cash flow \\(k\\) in coupon periods, \\(k - 1 + \frac{DSC}{E}\\)
+    /// * \\(PV_k\\) = present value of cash flow \\(k\\) at the yield compounded at the coupon frequency
+    /// * \\(f\\) = coupon frequency
+    pub fn macaulay_duration(&self, yld: T) -> T {
+        let n = self.coupons_remaining();
+        if n == 0 {
+            return T::zero();
+        }
+        let (p, m1, _) = self.compounded_moments(yld, n);
+        m1 / (p * T::from_u16(self.frequency.periods_per_year()))
+    }
+
+    /// Modified Duration
+    ///
+    /// The Macaulay duration divided by one plus the periodic yield, similar to the Excel `MDURATION` function.
+    ///
+    /// # Arguments
+    /// * `yld` - The annual yield to maturity, compounded at the coupon frequency
+    ///
+    /// # Returns
+    /// * The modified duration in years, zero if the bond settles on or after maturity
+    ///
+    /// # Formula
+    /// $$D_{mod} = \frac{D_{mac}}{1 + \frac{y}{f}}$$
+    pub fn modified_duration(&self, yld: T) -> T {
+        self.macaulay_duration(yld) / (T::one() + yld / T::from_u16(self.frequency.periods_per_year()))
+    }
+
+    /// Convexity
+    ///
+    /// The curvature of the price with respect to the yield, relative to the dirty price.
+    ///
+    /// # Arguments
+    /// * `yld` - The annual yield to maturity, compounded at the coupon frequency
+    ///
+    /// # Returns
+    /// * The convexity in years squared, zero if the bond settles on or after maturity
+    ///
+    /// # Formula
+    /// $$C = \frac{1}{f^2 (1 + \frac{y}{f})^2} \frac{\sum_{k=1}^{N} t_k (t_k + 1) PV_k}{\sum_{k=1}^{N} PV_k}$$
+    pub fn convexity(&self, yld: T) -> T {
+        let n = self.coupons_remaining();
+        if n == 0 {
+            return T::zero();
+        }
+        let f = T::from_u16(self.frequency.periods_per_year());
+        let one_plus_y = T::one() + yld / f;
+        let (p, _, m2) = self.compounded_moments(yld, n);
+        m2 / (p * f * f * one_plus_y * one_plus_y)
+    }
+
+    /// DV01 - Dollar Value of a Basis Point
+    ///
+    /// The decrease in the dirty price for a one basis point (0.01%) increase in the yield, also known as PV01.
+    ///
+    /// # Arguments
+    /// * `yld` - The annual yield to maturity, compounded at the coupon frequency
+    ///
+    /// # Returns
+    /// * The DV01 for the face value of the bond
+    pub fn dv01(&self, yld: T) -> T {
+        -self.dirty_price_derivatives(yld, self.coupons_remaining()).1 * T::from_f32(1e-4)
+    }
+
+    /// The coupon date `n` periods before maturity
+    fn coupon_date(&self, n: i32) -> Date {
+        let end_of_month = self.frequency != Frequency::Weekly && self.maturity.is_end_of_month();
+        self.frequency.advance(self.maturity, -n, end_of_month)
+    }
+
+    /// The accrued interest with `n` coupons remaining
+    fn accrued_interest_with(&self, n: u32) -> T {
+        if n == 0 {
+            return T::zero();
+        }
+        let (accrued_days, period_days, _) = self.period_days(n);
+        self.coupon() * accrued_days / period_days
+    }
+
+    /// Returns the days from the previous coupon date to settlement (A), the days in the coupon period (E) and the
+    /// days from settlement to the next coupon date (DSC) under the day count convention, with `n` coupons remaining
+    fn period_days(&self, n: u32) -> (T, T, T) {
+        let previous = self.coupon_date(n as i32);
+        let next = self.coupon_date(n as i32 - 1);
+        let f = T::from_u16(self.frequency.periods_per_year());
+        let accrued_days = T::from_i32(self.day_count.day_count(previous, self.settlement));
+        let period_days = match self.day_count {
+            DayCount::ActActIsda | DayCount::ActActIcma => T::from_i32(previous.days_until(next)),
+            DayCount::Act365F => T::from_u16(365) / f,
+            DayCount::Act360 | DayCount::Thirty360 | DayCount::ThirtyE360 | DayCount::ThirtyE360Isda => {
+                T::from_u16(360) / f
+            }
+        };
+        let days_to_coupon = match self.day_count {
+            DayCount::Thirty360 | DayCount::ThirtyE360 | DayCount::ThirtyE360Isda => period_days - accrued_days,
+            _ => T::from_i32(self.settlement.days_until(next)),
+        };
+        (accrued_days, period_days, days_to_coupon)
+    }
+
+    /// Returns the dirty price and its first and second derivatives with respect to the yield, with `n` coupons
+    /// remaining
+    fn dirty_price_derivatives(&self, yld: T, n: u32) -> (T, T, T) {
+        if n == 0 {
+            return (T::zero(), T::zero(), T::zero());
+        }
+        let f = T::from_u16(self.frequency.periods_per_year());
+
+        if n == 1 {
+            // Simple interest over the final period
+            let (_, period_days, days_to_coupon) = self.period_days(n);
+            let a = days_to_coupon / period_days / f;
+            let amount = self.coupon() + self.redemption;
+            let d = T::one() + a * yld;
+            return (
+                amount / d,
+                -amount * a / (d * d),
+                T::two() * amount * a * a / (d * d * d),
+            );
+        }
+
+        let one_plus_y = T::one() + yld / f;
+        let (p, m1, m2) = self.compounded_moments(yld, n);
+        (p, -m1 / (f * one_plus_y), m2 / (f * f * one_plus_y * one_plus_y))
+    }
+
+    /// Returns the sums of the present values \(PV_k\), of \(t_k PV_k\) and of \(t_k (t_k + 1) PV_k\) over the
+    /// remaining cash flows discounted with periodic compounding, where \(t_k\) is the time to the cash flow in
+    /// coupon periods, with `n` coupons remaining
+    fn compounded_moments(&self, yld: T, n: u32) -> (T, T, T) {
+        let (_, period_days, days_to_coupon) = self.period_days(n);
+        let w = days_to_coupon / period_days;
+        let one_plus_y = T::one() + yld / T::from_u16(self.frequency.periods_per_year());
+        let coupon = self.coupon();
+
+        let (mut p, mut m1, mut m2) = (T::zero(), T::zero(), T::zero());
+        for k in 1..=n {
+            let t = T::from_usize(k as usize - 1) + w;
+            let amount = if k == n { coupon + self.redemption } else { coupon };
+            let pv = amount / one_plus_y.powf(t);
+            p += pv;
+            m1 += t * pv;
+            m2 += t * (t + T::one()) * pv;
+        }
+        (p, m1, m2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_coupon_dates() {
+        let bond = FixedRateBond::new(
+            100.0,
+            0.05,
+            Frequency::Quarterly,
+            date(2024, 3, 15),
+            date(2025, 11, 30),
+            DayCount::Thirty360,
+        );
+        assert_eq!(bond.previous_coupon_date(), date(2024, 2, 29));
+        assert_eq!(bond.next_coupon_date(), date(2024, 5, 31));
+        assert_eq!(bond.coupons_remaining(), 7);
+
+        // Settling on a coupon date
+        let bond = FixedRateBond {
+            settlement: date(2024, 5, 31),
+            ..bond
+        };
+        assert_eq!(bond.previous_coupon_date(), date(2024, 5, 31));
+        assert_eq!(bond.coupons_remaining(), 6);
+        assert_eq!(bond.accrued_interest(), 0.0);
+
+        // The count matches walking back every coupon date from maturity
+        for frequency in [Frequency::Annual, Frequency::Monthly, Frequency::Weekly] {
+            for offset in (0..12_000).step_by(37) {
+                let bond = FixedRateBond {
+                    frequency,
+                    settlement: date(2016, 2, 29).add_days(offset),
+                    maturity: date(2048, 2, 29),
+                    ..bond
+                };
+                let mut n = 0;
+                while bond.coupon_date(n) > bond.settlement {
+                    n += 1;
+                }
+                assert_eq!(
+                    bond.coupons_remaining(),
+                    n as u32,
+                    "Failed on settlement: {:?}",
+                    bond.settlement
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_price_yield() {
+        struct TestCase {
+            bond: FixedRateBond<f64>,
+            yld: f64,
+            expected_price: f64,
+            expected_accrued: f64,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(
+                bond: FixedRateBond<f64>,
+                yld: f64,
+                expected_price: f64,
+                expected_accrued: f64,
+                description: &'static str,
+            ) -> Self {
+                TestCase {
+                    bond,
+                    yld,
+                    expected_price,
+                    expected_accrued,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                FixedRateBond::new(
+                    100.0,
+                    0.0575,
+                    Frequency::SemiAnnual,
+                    date(2008, 2, 15),
+                    date(2017, 11, 15),
+                    DayCount::Thirty360,
+                ),
+                0.065,
+                94.63436,
+                1.4375,
+                "Excel PRICE example",
+            ),
+            TestCase::new(
+                FixedRateBond::new(
+                    100.0,
+                    0.0575,
+                    Frequency::SemiAnnual,
+                    date(2008, 2, 15),
+                    date(2016, 11, 15),
+                    DayCount::Thirty360,
+                ),
+                0.065,
+                95.04287,
+                1.4375,
+                "Excel YIELD example",
+            ),
+            TestCase::new(
+                FixedRateBond::new(
+                    1000.0,
+                    0.1,
+                    Frequency::SemiAnnual,
+                    date(2008, 5, 1),
+                    date(2010, 9, 1),
+                    DayCount::Thirty360,
+                ),
+                0.1,
+                999.72969,
+                16.66667,
+                "Slightly below par between coupon dates when the yield equals the coupon",
+            ),
+            TestCase::new(
+                FixedRateBond::new(
+                    100.0,
+                    0.04,
+                    Frequency::Annual,
+                    date(2024, 3, 1),
+                    date(2028, 6, 30),
+                    DayCount::ActActIcma,
+                ),
+                0.05,
+                96.16912,
+                2.67760,
+                "Annual ACT/ACT bond",
+            ),
+            TestCase::new(
+                FixedRateBond::new(
+                    100.0,
+                    0.06,
+                    Frequency::Quarterly,
+                    date(2024, 3, 1),
+                    date(2024, 5, 15),
+                    DayCount::Act360,
+                ),
+                0.05,
+                100.20361,
+                0.25,
+                "Final coupon period uses simple interest",
+            ),
+        ];
+
+        for case in &cases {
+            let price = case.bond.clean_price(case.yld);
+            let accrued = case.bond.accrued_interest();
+            assert!(
+                (price - case.expected_price).abs() < 1e-5,
+                "Failed on case: {}. Expected price: {}, Result: {}",
+                case.description,
+                case.expected_price,
+                price
+            );
+            assert!(
+                (accrued - case.expected_accrued).abs() < 1e-5,
+                "Failed on case: {}. Expected accrued interest: {}, Result: {}",
+                case.description,
+                case.expected_accrued,
+                accrued
+            );
+            assert!((case.bond.dirty_price(case.yld) - price - accrued).abs() < 1e-10);
+
+            let yld = case.bond.yield_from_price(price, None, None, None).unwrap();
+            assert!(
+                (yld - case.yld).abs() < 1e-8,
+                "Failed on case: {}. Expected yield: {}, Result: {}",
+                case.description,
+                case.yld,
+                yld
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration() {
+        let bond = FixedRateBond::new(
+            100.0,
+            0.08,
+            Frequency::SemiAnnual,
+            date(2018, 7, 1),
+            date(2048, 1, 1),
+            DayCount::ActActIcma,
+        );
+        let duration = bond.macaulay_duration(0.09);
+        let expected = 10.91915;
+        assert!(
+            (duration - expected).abs() < 1e-5,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Excel DURATION example",
+            expected,
+            duration
+        );
+
+        let bond = FixedRateBond {
+            settlement: date(2008, 1, 1),
+            maturity: date(2016, 1, 1),
+            ..bond
+        };
+        let duration = bond.modified_duration(0.09);
+        let expected = 5.73567;
+        assert!(
+            (duration - expected).abs() < 1e-5,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Excel MDURATION example",
+            expected,
+            duration
+        );
+
+        // Modified duration, convexity and DV01 agree with repricing the bond
+        let yld = 0.09;
+        let h = 1e-5;
+        let price = bond.dirty_price(yld);
+        let (up, down) = (bond.dirty_price(yld + h), bond.dirty_price(yld - h));
+        assert!(((down - up) / (2.0 * h * price) - bond.modified_duration(yld)).abs() < 1e-6);
+        assert!(((up + down - 2.0 * price) / (h * h * price) - bond.convexity(yld)).abs() < 1e-2);
+        assert!(((down - up) / (2.0 * h) * 1e-4 - bond.dv01(yld)).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_matured_bond() {
+        let bond = FixedRateBond::new(
+            100.0,
+            0.05,
+            Frequency::Annual,
+            date(2025, 1, 1),
+            date(2024, 1, 1),
+            DayCount::Act365F,
+        );
+        assert_eq!(bond.coupons_remaining(), 0);
+        assert_eq!(bond.dirty_price(0.05), 0.0);
+        assert_eq!(
+            bond.yield_from_price(100.0, None, None, None),
+            Err(FinPrimError::NoSolution)
+        );
+    }
+}
@@ -0,0 +1,8 @@
+//! This module contains bond pricing and analytics.
+//!
+//! For example, you can price a fixed rate bond from its yield to maturity, solve for the yield given a price,
+//! or calculate the interest accrued since the last coupon.
+
+// Fixed Rate Bonds
+mod fixed_rate;
+pub use fixed_rate::FixedRateBond;
@@ -0,0 +1,358 @@
+use crate::curve::{Compounding, Interpolation, YieldCurve};
+use crate::utils::newton_raphson;
+use crate::FinPrimError;
+use crate::FloatLike;
+use core::cmp::Ordering;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Curve Instrument
+///
+/// A market quote used to bootstrap a yield curve. Times are in years from the valuation date and rates are
+/// annual rates.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Instrument<T> {
+    /// Money market deposit from the valuation date to `maturity` at a simple `rate`
+    Deposit { maturity: T, rate: T },
+    /// Forward rate agreement from `start` to `end` at a simple `rate`
+    Fra { start: T, end: T, rate: T },
+    /// Interest rate future on the period from `start` to `end` quoted at `price`, where 100 less the price is the
+    /// simple forward rate in percent. No convexity adjustment is applied.
+    Future { start: T, end: T, price: T },
+    /// Par swap from the valuation date to `maturity` with a fixed `rate` paid `frequency` times per year, the
+    /// floating leg is assumed to be worth par. A par bond yield can be given as a swap with the coupon frequency.
+    ///
+    /// Fixed payments are rolled back from the maturity, so a short first period is used if the maturity is not a
+    /// whole number of periods.
+    Swap { maturity: T, rate: T, frequency: u16 },
+}
+
+impl<T: FloatLike> Instrument<T> {
+    /// The time of the last cash flow of the instrument, which becomes its curve pillar
+    pub fn maturity(&self) -> T {
+        match *self {
+            Instrument::Deposit { maturity, .. } | Instrument::Swap { maturity, .. } => maturity,
+            Instrument::Fra { end, .. } | Instrument::Future { end, .. } => end,
+        }
+    }
+
+    /// Whether the maturity is greater than zero, and not NaN, and a swap has payments
+    fn is_valid(&self) -> bool {
+        let positive = self.maturity().partial_cmp(&T::zero()) == Some(Ordering::Greater);
+        match *self {
+            Instrument::Swap { frequency, .. } => positive && frequency > 0,
+            _ => positive,
+        }
+    }
+
+    /// The quoted rate of the instrument
+    fn rate(&self) -> T {
+        match *self {
+            Instrument::Deposit { rate, .. } | Instrument::Fra { rate, .. } | Instrument::Swap { rate, .. } => rate,
+            Instrument::Future { price, .. } => (T::from_u16(100) - price) / T::from_u16(100),
+        }
+    }
+
+    /// The value of the instrument discounted off the curve, zero when the curve reprices the quote
+    fn residual(&self, curve: &YieldCurve<T>) -> T {
+        match *self {
+            Instrument::Deposit { maturity, rate } => {
+                curve.discount_factor(maturity) * (T::one() + rate * maturity) - T::one()
+            }
+            Instrument::Fra { start, end, .. } | Instrument::Future { start, end, .. } => {
+                curve.discount_factor(end) * (T::one() + self.rate() * (end - start)) - curve.discount_factor(start)
+            }
+            Instrument::Swap {
+                maturity,
+                rate,
+                frequency,
+            } => {
+                let period = T::one() / T::from_u16(frequency);
+                let epsilon = T::from_f32(1e-9);
+                // Number of fixed payments, rolling back from maturity until the valuation date
+                let mut n = 0;
+                while maturity - T::from_usize(n) * period > epsilon {
+                    n += 1;
+                }
+                let mut annuity = T::zero();
+                let mut previous = T::zero();
+                for k in (0..n).rev() {
+                    let t = maturity - T::from_usize(k) * period;
+                    annuity += (t - previous) * curve.discount_factor(t);
+                    previous = t;
+                }
+                rate * annuity + curve.discount_factor(maturity) - T::one()
+            }
+        }
+    }
+}
+
+/// Bootstrap
+///
+/// Builds a yield curve from market instruments by sequentially solving for the zero rate at each instrument's
+/// maturity so that the instrument reprices, given the pillars already solved for.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it uses `curve::YieldCurve`.
+///
+/// # Arguments
+/// * `instruments` - The market instruments, in any order. Each instrument must have a distinct maturity.
+/// * `interpolation` - The interpolation method of the resulting curve, also used to value instruments with cash
+///   flows between pillars while bootstrapping
+/// * `tolerance` (optional) - The tolerance/maximum error bound for the value of each instrument, defaults to 1e-10
+/// * `max_iter` (optional) - The maximum number of iterations to perform per instrument, defaults to 20.
+///
+/// # Returns
+/// * Result of the bootstrapped yield curve, with a pillar at each instrument's maturity
+/// * If solving for an instrument fails, it returns a `FinPrimError::BootstrapError` with the index of the
+///   instrument in `instruments` and the root finding error details
+/// * `FinPrimError::InvalidInstrument` with the index of the instrument if its maturity is not greater than zero or
+///   is the same as another instrument's maturity, or it is a swap with a frequency of zero
+/// * `FinPrimError::InvalidArgument` if `instruments` is empty
+///
+/// Linear and log-linear interpolation reprice every instrument exactly. A cubic spline is global, so solving for a
+/// later pillar slightly changes the curve between earlier pillars.
+///
+/// # Example
+/// * Deposits, an FRA, a future and par swaps
+/// ```
+/// use rust_finprim::curve::{bootstrap, Compounding, Instrument, Interpolation};
+///
+/// let instruments = [
+///     Instrument::Deposit { maturity: 0.25, rate: 0.050 },
+///     Instrument::Deposit { maturity: 0.5, rate: 0.051 },
+///     Instrument::Fra { start: 0.5, end: 0.75, rate: 0.052 },
+///     Instrument::Future { start: 0.75, end: 1.0, price: 94.75 },
+///     Instrument::Swap { maturity: 2.0, rate: 0.054, frequency: 2 },
+///     Instrument::Swap { maturity: 5.0, rate: 0.056, frequency: 2 },
+/// ];
+/// let curve = bootstrap(&instruments, Interpolation::LogLinear, None, None).unwrap();
+/// let zero = curve.zero_rate(3.0, Compounding::Continuous);
+/// ```
+///
+/// Each pillar is solved with the Newton-Raphson method on the continuously compounded zero rate, using a central
+/// difference for the derivative.
+pub fn bootstrap<T: FloatLike>(
+    instruments: &[Instrument<T>],
+    interpolation: Interpolation,
+    tolerance: Option<T>,
+    max_iter: Option<u16>,
+) -> Result<YieldCurve<T>, FinPrimError<T>> {
+    let tolerance = tolerance.unwrap_or(T::from_f32(1e-10));
+    let max_iter = max_iter.unwrap_or(20);
+    let h = T::from_f32(1e-6);
+    let invalid = |instrument| FinPrimError::InvalidInstrument { instrument };
+
+    if instruments.is_empty() {
+        return Err(FinPrimError::InvalidArgument { name: "instruments" });
+    }
+    // Also rejects NaN maturities, leaving the rest comparable
+    if let Some(index) = instruments.iter().position(|i| !i.is_valid()) {
+        return Err(invalid(index));
+    }
+    let mut order: Vec<usize> = (0..instruments.len()).collect();
+    order.sort_by(|&a, &b| {
+        instruments[a]
+            .maturity()
+            .partial_cmp(&instruments[b].maturity())
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut times: Vec<T> = Vec::with_capacity(instruments.len());
+    let mut rates: Vec<T> = Vec::with_capacity(instruments.len());
+    for index in order {
+        let instrument = instruments[index];
+        let maturity = instrument.maturity();
+        if times.last() == Some(&maturity) {
+            return Err(invalid(index));
+        }
+
+        // The curve with a trial zero rate at the instrument's pillar
+        let residual = |z: T| {
+            let mut trial_times = times.clone();
+            let mut trial_rates = rates.clone();
+            trial_times.push(maturity);
+            trial_rates.push(z);
+            let curve = YieldCurve::from_zero_rates(&trial_times, &trial_rates, Compounding::Continuous, interpolation);
+            instrument.residual(&curve)
+        };
+        let residual_prime = |z: T| (residual(z + h) - residual(z - h)) / (T::two() * h);
+
+        let guess = rates.last().copied().unwrap_or(instrument.rate());
+        let z = newton_raphson(guess, residual, residual_prime, tolerance, max_iter).map_err(|e| match e {
+            FinPrimError::RootFindingError(error) => FinPrimError::BootstrapError {
+                instrument: index,
+                error,
+            },
+            e => e,
+        })?;
+        times.push(maturity);
+        rates.push(z);
+    }
+    Ok(YieldCurve::from_zero_rates(
+        &times,
+        &rates,
+        Compounding::Continuous,
+        interpolation,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RootFindingError;
+
+    #[test]
+    fn test_bootstrap_flat() {
+        // Quotes consistent with a flat 5% continuously compounded curve
+        let r: f64 = 0.05;
+        let df = |t: f64| (-r * t).exp();
+        let instruments = [
+            Instrument::Swap {
+                maturity: 3.0,
+                rate: (1.0 - df(3.0)) / (0.5 * (1..=6).map(|k| df(k as f64 * 0.5)).sum::<f64>()),
+                frequency: 2,
+            },
+            Instrument::Deposit {
+                maturity: 0.5,
+                rate: (1.0 / df(0.5) - 1.0) / 0.5,
+            },
+            Instrument::Fra {
+                start: 0.5,
+                end: 1.0,
+                rate: (df(0.5) / df(1.0) - 1.0) / 0.5,
+            },
+            Instrument::Future {
+                start: 1.0,
+                end: 1.5,
+                price: 100.0 - 100.0 * (df(1.0) / df(1.5) - 1.0) / 0.5,
+            },
+        ];
+
+        for interpolation in [
+            Interpolation::Linear,
+            Interpolation::LogLinear,
+            Interpolation::CubicSpline,
+        ] {
+            let curve = bootstrap(&instruments, interpolation, None, None).unwrap();
+            assert_eq!(curve.times(), &[0.5, 1.0, 1.5, 3.0]);
+            for t in [0.25, 0.5, 1.2, 2.0, 3.0] {
+                let result = curve.discount_factor(t);
+                assert!(
+                    (result - df(t)).abs() < 1e-9,
+                    "Failed on case: {:?} at {}. Expected: {}, Result: {}",
+                    interpolation,
+                    t,
+                    df(t),
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_reprices() {
+        let instruments = [
+            Instrument::Deposit {
+                maturity: 0.25,
+                rate: 0.050,
+            },
+            Instrument::Deposit {
+                maturity: 0.5,
+                rate: 0.051,
+            },
+            Instrument::Fra {
+                start: 0.5,
+                end: 0.75,
+                rate: 0.052,
+            },
+            Instrument::Future {
+                start: 0.75,
+                end: 1.0,
+                price: 94.75,
+            },
+            Instrument::Swap {
+                maturity: 2.0,
+                rate: 0.054,
+                frequency: 2,
+            },
+            Instrument::Swap {
+                maturity: 4.75,
+                rate: 0.056,
+                frequency: 2,
+            },
+        ];
+        let curve = bootstrap(&instruments, Interpolation::LogLinear, Some(1e-12), None).unwrap();
+        for (i, instrument) in instruments.iter().enumerate() {
+            let residual: f64 = instrument.residual(&curve);
+            assert!(
+                residual.abs() < 1e-10,
+                "Failed on case: instrument {}. Expected: 0, Result: {}",
+                i,
+                residual
+            );
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_error() {
+        let instruments = [
+            Instrument::Swap {
+                maturity: 2.0,
+                rate: 0.054,
+                frequency: 2,
+            },
+            Instrument::Deposit {
+                maturity: 0.5,
+                rate: 0.05,
+            },
+        ];
+        // The deposit is solved first and cannot converge without iterations
+        let result = bootstrap(&instruments, Interpolation::Linear, None, Some(0));
+        assert!(matches!(
+            result,
+            Err(FinPrimError::BootstrapError {
+                instrument: 1,
+                error: RootFindingError::FailedToConverge { .. }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_bootstrap_invalid_instruments() {
+        let deposit = |maturity: f64| Instrument::Deposit { maturity, rate: 0.05 };
+        let invalid = |instrument| Err(FinPrimError::InvalidInstrument { instrument });
+        let swap = Instrument::Swap {
+            maturity: 1.0,
+            rate: 0.05,
+            frequency: 2,
+        };
+
+        assert_eq!(
+            bootstrap(&[deposit(0.5), swap, deposit(1.0)], Interpolation::Linear, None, None),
+            invalid(2)
+        );
+        assert_eq!(
+            bootstrap(&[deposit(0.5), deposit(0.0)], Interpolation::Linear, None, None),
+            invalid(1)
+        );
+        assert_eq!(
+            bootstrap(&[deposit(f64::NAN), deposit(1.0)], Interpolation::Linear, None, None),
+            invalid(0)
+        );
+        let no_payments = Instrument::Swap {
+            maturity: 2.0,
+            rate: 0.05,
+            frequency: 0,
+        };
+        assert_eq!(
+            bootstrap(&[deposit(0.5), no_payments], Interpolation::Linear, None, None),
+            invalid(1)
+        );
+        assert_eq!(
+            bootstrap::<f64>(&[], Interpolation::Linear, None, None),
+            Err(FinPrimError::InvalidArgument { name: "instruments" })
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Compounding Convention
+///
+/// Determines how an annual interest rate accrues over time, and with it the relationship between a rate and a
+/// discount factor.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Compounding {
+    /// Simple interest, no compounding
+    Simple,
+    /// Compounded a number of times per year, e.g. `Periodic(2)` for semi-annual compounding. `Periodic(0)` has no
+    /// compounding periods to divide the rate by and is treated as `Continuous`.
+    Periodic(u16),
+    /// Continuous compounding
+    Continuous,
+}
+
+impl Compounding {
+    /// Discount Factor
+    ///
+    /// The present value of 1 paid at time `t` when discounting at `rate` under the compounding convention.
+    ///
+    /// # Arguments
+    /// * `rate` - The annual interest rate
+    /// * `t` - The time in years
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::curve::Compounding;
+    ///
+    /// let df: f64 = Compounding::Periodic(1).discount_factor(0.05, 2.0);
+    /// assert!((df - 1.0 / 1.05f64.powf(2.0)).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Formula
+    /// * Simple: \\(\frac{1}{1 + rt}\\)
+    /// * Periodic: \\((1 + \frac{r}{n})^{-nt}\\)
+    /// * Continuous: \\(e^{-rt}\\)
+    pub fn discount_factor<T: FloatLike>(&self, rate: T, t: T) -> T {
+        match *self {
+            Compounding::Simple => T::one() / (T::one() + rate * t),
+            Compounding::Periodic(0) | Compounding::Continuous => (-rate * t).exp(),
+            Compounding::Periodic(n) => {
+                let n = T::from_u16(n);
+                (T::one() + rate / n).powf(-n * t)
+            }
+        }
+    }
+
+    /// Rate
+    ///
+    /// The annual interest rate under the compounding convention implied by a discount factor at time `t`, the
+    /// inverse of `discount_factor`.
+    ///
+    /// # Arguments
+    /// * `discount_factor` - The discount factor
+    /// * `t` - The time in years, should be greater than zero
+    pub fn rate<T: FloatLike>(&self, discount_factor: T, t: T) -> T {
+        self.equivalent_rate(-discount_factor.ln() / t, t)
+    }
+
+    /// The rate under this convention equivalent to a continuously compounded rate over `t` years
+    pub fn equivalent_rate<T: FloatLike>(&self, rate: T, t: T) -> T {
+        match *self {
+            Compounding::Simple if t.is_zero() => rate,
+            Compounding::Simple => ((rate * t).exp() - T::one()) / t,
+            Compounding::Periodic(0) | Compounding::Continuous => rate,
+            Compounding::Periodic(n) => {
+                let n = T::from_u16(n);
+                n * ((rate / n).exp() - T::one())
+            }
+        }
+    }
+
+    /// The continuously compounded rate equivalent to a rate under this convention over `t` years
+    pub fn continuous_rate<T: FloatLike>(&self, rate: T, t: T) -> T {
+        match *self {
+            Compounding::Simple if t.is_zero() => rate,
+            Compounding::Simple => (T::one() + rate * t).ln() / t,
+            Compounding::Periodic(0) | Compounding::Continuous => rate,
+            Compounding::Periodic(n) => {
+                let n = T::from_u16(n);
+                n * (T::one() + rate / n).ln()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_compounding_round_trip() {
+        let conventions = [
+            Compounding::Simple,
+            Compounding::Periodic(1),
+            Compounding::Periodic(12),
+            Compounding::Continuous,
+        ];
+        for compounding in conventions {
+            let df: f64 = compounding.discount_factor(0.05, 2.5);
+            let rate = compounding.rate(df, 2.5);
+            assert!(
+                (rate - 0.05).abs() < 1e-12,
+                "Failed on case: {:?}. Expected: {}, Result: {}",
+                compounding,
+                0.05,
+                rate
+            );
+            let continuous = compounding.continuous_rate(0.05, 2.5);
+            assert!((Compounding::Continuous.discount_factor(continuous, 2.5) - df).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_periodic_zero() {
+        // No compounding periods falls back to continuous compounding rather than dividing by zero
+        let zero = Compounding::Periodic(0);
+        let df: f64 = zero.discount_factor(0.05, 2.5);
+        assert_eq!(df, Compounding::Continuous.discount_factor(0.05, 2.5));
+        assert_eq!(zero.rate(df, 2.5), Compounding::Continuous.rate(df, 2.5));
+        assert_eq!(zero.continuous_rate(0.05, 2.5), 0.05);
+    }
+}
@@ -0,0 +1,25 @@
+//! This module contains term structures of interest rates.
+//!
+//! For example, you can build a yield curve from zero rates or discount factors and read discount factors,
+//! zero rates and forward rates off it under different compounding conventions, bootstrap one from market
+//! quotes, or fit a Nelson-Siegel or Svensson curve to observed rates.
+
+// Compounding Conventions
+mod compounding;
+pub use compounding::Compounding;
+
+// Parametric Curves
+mod parametric;
+pub use parametric::{NelsonSiegel, Svensson};
+
+// Yield Curves
+#[cfg(feature = "std")]
+mod yield_curve;
+#[cfg(feature = "std")]
+pub use yield_curve::{Interpolation, YieldCurve};
+
+// Bootstrapping
+#[cfg(feature = "std")]
+mod bootstrap;
+#[cfg(feature = "std")]
+pub use bootstrap::{bootstrap, Instrument};
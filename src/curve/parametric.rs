@@ -0,0 +1,573 @@
+use crate::utils::levenberg_marquardt;
+use crate::FinPrimError;
+use crate::FloatLike;
+use core::cmp::Ordering;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// \\(e^{-x}\\), flushed to zero for large `x` where `Decimal` would underflow
+fn decay<T: FloatLike>(x: T) -> T {
+    if x > T::from_u16(60) {
+        T::zero()
+    } else {
+        (-x).exp()
+    }
+}
+
+/// Slope and curvature loadings at time `t` for decay `tau`, \\(\frac{1 - e^{-t/\tau}}{t/\tau}\\) and the same
+/// less \\(e^{-t/\tau}\\)
+fn loadings<T: FloatLike>(t: T, tau: T) -> (T, T) {
+    if t.is_zero() {
+        return (T::one(), T::zero());
+    }
+    let x = t / tau;
+    let decay = decay(x);
+    let slope = (T::one() - decay) / x;
+    (slope, slope - decay)
+}
+
+/// Decays searched for the starting point of a fit, in years
+const TAU_GRID: [f32; 8] = [0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.5, 10.0];
+
+/// Bound on the fitted log decays, keeping \\(\tau\\) between about 5e-5 and 22,000 years so `exp` cannot
+/// overflow `Decimal` while the search wanders
+const LOG_TAU_BOUND: u16 = 10;
+
+/// The decay \\(e^x\\) for a fitted log decay `x`, clamped to `LOG_TAU_BOUND`
+fn tau_from_log<T: FloatLike>(x: T) -> T {
+    let bound = T::from_u16(LOG_TAU_BOUND);
+    x.max(-bound).min(bound).exp()
+}
+
+/// Checks the observations of a fit with `params` parameters and the decays of its guess
+fn check_fit<T: FloatLike, const N: usize>(
+    times: &[T],
+    rates: &[T],
+    params: usize,
+    taus: Option<[T; N]>,
+) -> Result<(), FinPrimError<T>> {
+    if times.len() != rates.len() {
+        return Err(FinPrimError::InvalidArgument { name: "rates" });
+    }
+    if times.len() < params {
+        return Err(FinPrimError::InvalidArgument { name: "times" });
+    }
+    if taus
+        .into_iter()
+        .flatten()
+        .any(|tau| tau.partial_cmp(&T::zero()) != Some(Ordering::Greater))
+    {
+        return Err(FinPrimError::InvalidArgument { name: "guess" });
+    }
+    Ok(())
+}
+
+/// Least squares betas for fixed decays, where `factors` gives the loading of each beta at a time, along with the
+/// sum of squared errors
+fn linear_fit<T: FloatLike, F, const N: usize>(times: &[T], rates: &[T], factors: F) -> Option<([T; N], T)>
+where
+    F: Fn(T) -> [T; N],
+{
+    let residual = |p: &[T; N], i: usize| {
+        let loading = factors(times[i]);
+        (0..N).map(|k| p[k] * loading[k]).sum::<T>() - rates[i]
+    };
+    let betas = levenberg_marquardt([T::zero(); N], residual, times.len(), T::from_f32(1e-10), 50).ok()?;
+    let cost = (0..times.len())
+        .map(|i| {
+            let r = residual(&betas, i);
+            r * r
+        })
+        .sum();
+    Some((betas, cost))
+}
+
+/// Nelson-Siegel Curve
+///
+/// A parametric zero curve with a level `beta0`, slope `beta1` and curvature `beta2`, the slope and curvature
+/// decaying at a rate set by `tau`. Rates are continuously compounded and times are in years.
+///
+/// # Formula
+/// $$r(t) = \beta_0 + \beta_1 \frac{1 - e^{-t/\tau}}{t/\tau} + \beta_2 \left(\frac{1 - e^{-t/\tau}}{t/\tau} -
+/// e^{-t/\tau}\right)$$
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NelsonSiegel<T> {
+    pub beta0: T,
+    pub beta1: T,
+    pub beta2: T,
+    pub tau: T,
+}
+
+impl<T: FloatLike> NelsonSiegel<T> {
+    /// The zero rate at time `t`
+    ///
+    /// # Example
+    /// ```
+    /// use rust_finprim::curve::NelsonSiegel;
+    ///
+    /// let curve = NelsonSiegel { beta0: 0.05f64, beta1: -0.02, beta2: 0.01, tau: 2.0 };
+    /// assert!((curve.zero_rate(0.0) - 0.03).abs() < 1e-12);
+    /// ```
+    pub fn zero_rate(&self, t: T) -> T {
+        let (slope, curvature) = loadings(t, self.tau);
+        self.beta0 + self.beta1 * slope + self.beta2 * curvature
+    }
+
+    /// The instantaneous forward rate at time `t`
+    ///
+    /// # Formula
+    /// $$f(t) = \beta_0 + \beta_1 e^{-t/\tau} + \beta_2 \frac{t}{\tau} e^{-t/\tau}$$
+    pub fn forward_rate(&self, t: T) -> T {
+        let x = t / self.tau;
+        let decay = decay(x);
+        self.beta0 + self.beta1 * decay + self.beta2 * x * decay
+    }
+
+    /// The discount factor at time `t`, \\(e^{-r(t)t}\\)
+    pub fn discount_factor(&self, t: T) -> T {
+        (-self.zero_rate(t) * t).exp()
+    }
+
+    /// Fit - Least Squares Calibration
+    ///
+    /// Fits the curve to observed zero rates by minimizing the sum of squared rate errors with the
+    /// Levenberg-Marquardt method. `tau` is fitted on a log scale so it stays positive.
+    ///
+    /// # Arguments
+    /// * `times` - The times of the observed rates in years
+    /// * `rates` - The observed continuously compounded zero rates, at least four
+    /// * `guess` (optional) - The starting curve, defaults to the best least squares fit of the betas over a grid of
+    ///   `tau` values between 0.25 and 10 years
+    /// * `tolerance` (optional) - The relative parameter change at which the fit has converged, defaults to 1e-10
+    /// * `max_iter` (optional) - The maximum number of iterations, defaults to 200
+    ///
+    /// # Returns
+    /// * Result of the fitted curve, or the `RootFindingError` of `utils::levenberg_marquardt` if it fails
+    /// * `InvalidArgument` if `times` and `rates` have different lengths, there are fewer than four observations,
+    ///   or a decay of the `guess` is not positive
+    ///
+    /// # Example
+    /// ```
+    /// use rust_finprim::curve::NelsonSiegel;
+    ///
+    /// let times: [f64; 10] = [0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0];
+    /// let rates = [0.0410, 0.0418, 0.0431, 0.0447, 0.0459, 0.0471, 0.0478, 0.0482, 0.0485, 0.0483];
+    /// let curve = NelsonSiegel::fit(&times, &rates, None, None, None).unwrap();
+    /// assert!((curve.zero_rate(5.0) - 0.0471).abs() < 5e-4);
+    /// ```
+    pub fn fit(
+        times: &[T],
+        rates: &[T],
+        guess: Option<Self>,
+        tolerance: Option<T>,
+        max_iter: Option<u16>,
+    ) -> Result<Self, FinPrimError<T>> {
+        check_fit(times, rates, 4, guess.map(|g| [g.tau]))?;
+        let guess = guess.unwrap_or_else(|| {
+            let mut best: Option<(Self, T)> = None;
+            for tau in TAU_GRID.map(T::from_f32) {
+                let fit = linear_fit(times, rates, |t| {
+                    let (slope, curvature) = loadings(t, tau);
+                    [T::one(), slope, curvature]
+                });
+                if let Some(([beta0, beta1, beta2], cost)) = fit {
+                    if best.map_or(true, |(_, best_cost)| cost < best_cost) {
+                        best = Some((
+                            NelsonSiegel {
+                                beta0,
+                                beta1,
+                                beta2,
+                                tau,
+                            },
+                            cost,
+                        ));
+                    }
+                }
+            }
+            best.map_or(
+                NelsonSiegel {
+                    beta0: rates[rates.len() - 1],
+                    beta1: rates[0] - rates[rates.len() - 1],
+                    beta2: T::zero(),
+                    tau: T::one(),
+                },
+                |(curve, _)| curve,
+            )
+        });
+        let residual = |p: &[T; 4], i: usize| {
+            let curve = NelsonSiegel {
+                beta0: p[0],
+                beta1: p[1],
+                beta2: p[2],
+                tau: tau_from_log(p[3]),
+            };
+            curve.zero_rate(times[i]) - rates[i]
+        };
+        let [beta0, beta1, beta2, log_tau] = levenberg_marquardt(
+            [guess.beta0, guess.beta1, guess.beta2, guess.tau.ln()],
+            residual,
+            times.len(),
+            tolerance.unwrap_or(T::from_f32(1e-10)),
+            max_iter.unwrap_or(200),
+        )?;
+        Ok(NelsonSiegel {
+            beta0,
+            beta1,
+            beta2,
+            tau: tau_from_log(log_tau),
+        })
+    }
+}
+
+/// Nelson-Siegel-Svensson Curve
+///
+/// Extends the Nelson-Siegel curve with a second curvature term `beta3` decaying at `tau2`, allowing a second hump.
+/// Rates are continuously compounded and times are in years.
+///
+/// # Formula
+/// $$r(t) = \beta_0 + \beta_1 \frac{1 - e^{-t/\tau_1}}{t/\tau_1} + \beta_2 \left(\frac{1 - e^{-t/\tau_1}}{t/\tau_1} -
+/// e^{-t/\tau_1}\right) + \beta_3 \left(\frac{1 - e^{-t/\tau_2}}{t/\tau_2} - e^{-t/\tau_2}\right)$$
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Svensson<T> {
+    pub beta0: T,
+    pub beta1: T,
+    pub beta2: T,
+    pub beta3: T,
+    pub tau1: T,
+    pub tau2: T,
+}
+
+impl<T: FloatLike> Svensson<T> {
+    /// The zero rate at time `t`
+    pub fn zero_rate(&self, t: T) -> T {
+        let (slope, curvature) = loadings(t, self.tau1);
+        let (_, curvature2) = loadings(t, self.tau2);
+        self.beta0 + self.beta1 * slope + self.beta2 * curvature + self.beta3 * curvature2
+    }
+
+    /// The instantaneous forward rate at time `t`
+    ///
+    /// # Formula
+    /// $$f(t) = \beta_0 + \beta_1 e^{-t/\tau_1} + \beta_2 \frac{t}{\tau_1} e^{-t/\tau_1} +
+    /// \beta_3 \frac{t}{\tau_2} e^{-t/\tau_2}$$
+    pub fn forward_rate(&self, t: T) -> T {
+        let x1 = t / self.tau1;
+        let x2 = t / self.tau2;
+        let decay1 = decay(x1);
+        self.beta0 + self.beta1 * decay1 + self.beta2 * x1 * decay1 + self.beta3 * x2 * decay(x2)
+    }
+
+    /// The discount factor at time `t`, \\(e^{-r(t)t}\\)
+    pub fn discount_factor(&self, t: T) -> T {
+        (-self.zero_rate(t) * t).exp()
+    }
+
+    /// Fit - Least Squares Calibration
+    ///
+    /// Fits the curve to observed zero rates by minimizing the sum of squared rate errors with the
+    /// Levenberg-Marquardt method. `tau1` and `tau2` are fitted on a log scale so they stay positive.
+    ///
+    /// # Arguments
+    /// * `times` - The times of the observed rates in years
+    /// * `rates` - The observed continuously compounded zero rates, at least six
+    /// * `guess` (optional) - The starting curve, defaults to the best least squares fit of the betas over a grid of
+    ///   `tau1` and `tau2 > tau1` values between 0.25 and 10 years
+    /// * `tolerance` (optional) - The relative parameter change at which the fit has converged, defaults to 1e-10
+    /// * `max_iter` (optional) - The maximum number of iterations, defaults to 200
+    ///
+    /// # Returns
+    /// * Result of the fitted curve, or the `RootFindingError` of `utils::levenberg_marquardt` if it fails
+    /// * `InvalidArgument` if `times` and `rates` have different lengths, there are fewer than six observations,
+    ///   or a decay of the `guess` is not positive
+    pub fn fit(
+        times: &[T],
+        rates: &[T],
+        guess: Option<Self>,
+        tolerance: Option<T>,
+        max_iter: Option<u16>,
+    ) -> Result<Self, FinPrimError<T>> {
+        check_fit(times, rates, 6, guess.map(|g| [g.tau1, g.tau2]))?;
+        let guess = guess.unwrap_or_else(|| {
+            let mut best: Option<(Self, T)> = None;
+            for tau1 in TAU_GRID.map(T::from_f32) {
+                for tau2 in TAU_GRID.map(T::from_f32).into_iter().filter(|&tau2| tau2 > tau1) {
+                    let fit = linear_fit(times, rates, |t| {
+                        let (slope, curvature) = loadings(t, tau1);
+                        let (_, curvature2) = loadings(t, tau2);
+                        [T::one(), slope, curvature, curvature2]
+                    });
+                    if let Some(([beta0, beta1, beta2, beta3], cost)) = fit {
+                        if best.map_or(true, |(_, best_cost)| cost < best_cost) {
+                            best = Some((
+                                Svensson {
+                                    beta0,
+                                    beta1,
+                                    beta2,
+                                    beta3,
+                                    tau1,
+                                    tau2,
+                                },
+                                cost,
+                            ));
+                        }
+                    }
+                }
+            }
+            best.map_or(
+                Svensson {
+                    beta0: rates[rates.len() - 1],
+                    beta1: rates[0] - rates[rates.len() - 1],
+                    beta2: T::zero(),
+                    beta3: T::zero(),
+                    tau1: T::one(),
+                    tau2: T::from_u16(5),
+                },
+                |(curve, _)| curve,
+            )
+        });
+        let residual = |p: &[T; 6], i: usize| {
+            let curve = Svensson {
+                beta0: p[0],
+                beta1: p[1],
+                beta2: p[2],
+                beta3: p[3],
+                tau1: tau_from_log(p[4]),
+                tau2: tau_from_log(p[5]),
+            };
+            curve.zero_rate(times[i]) - rates[i]
+        };
+        let [beta0, beta1, beta2, beta3, log_tau1, log_tau2] = levenberg_marquardt(
+            [
+                guess.beta0,
+                guess.beta1,
+                guess.beta2,
+                guess.beta3,
+                guess.tau1.ln(),
+                guess.tau2.ln(),
+            ],
+            residual,
+            times.len(),
+            tolerance.unwrap_or(T::from_f32(1e-10)),
+            max_iter.unwrap_or(200),
+        )?;
+        Ok(Svensson {
+            beta0,
+            beta1,
+            beta2,
+            beta3,
+            tau1: tau_from_log(log_tau1),
+            tau2: tau_from_log(log_tau2),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    const TIMES: [f64; 10] = [0.25, 0.5, 1.0, 2.0, 3.0, 5.0, 7.0, 10.0, 20.0, 30.0];
+
+    #[test]
+    fn test_nelson_siegel() {
+        let curve = NelsonSiegel {
+            beta0: 0.05,
+            beta1: -0.02,
+            beta2: 0.01,
+            tau: 2.0,
+        };
+        struct TestCase {
+            t: f64,
+            zero: f64,
+            forward: f64,
+        }
+        impl TestCase {
+            fn new(t: f64, zero: f64, forward: f64) -> TestCase {
+                TestCase { t, zero, forward }
+            }
+        }
+        let cases = [
+            TestCase::new(0.0, 0.03, 0.03),
+            TestCase::new(1.0, 0.0360653, 0.0409020),
+            TestCase::new(5.0, 0.0455075, 0.0504104),
+        ];
+        for case in &cases {
+            let zero = curve.zero_rate(case.t);
+            let forward = curve.forward_rate(case.t);
+            assert!(
+                (zero - case.zero).abs() < 1e-7 && (forward - case.forward).abs() < 1e-7,
+                "Failed on case: {}. Expected: {} and {}, Result: {} and {}",
+                case.t,
+                case.zero,
+                case.forward,
+                zero,
+                forward
+            );
+        }
+
+        // Recover the parameters from exact rates
+        let rates = TIMES.map(|t| curve.zero_rate(t));
+        let fitted = NelsonSiegel::fit(&TIMES, &rates, None, Some(1e-12), None).unwrap();
+        assert!(
+            (fitted.beta0 - 0.05).abs() < 1e-8 && (fitted.tau - 2.0).abs() < 1e-6,
+            "Failed on case: fit. Expected: {:?}, Result: {:?}",
+            curve,
+            fitted
+        );
+
+        // A NaN rate is reported as a root finding error rather than a panic
+        let mut rates = rates;
+        rates[3] = f64::NAN;
+        let result = NelsonSiegel::fit(&TIMES, &rates, None, None, None);
+        assert!(matches!(result, Err(FinPrimError::RootFindingError(_))));
+
+        // Invalid observations and guesses are rejected rather than panicking
+        let rates = TIMES.map(|t| curve.zero_rate(t));
+        struct InvalidCase<'a> {
+            times: &'a [f64],
+            rates: &'a [f64],
+            guess: Option<NelsonSiegel<f64>>,
+            name: &'static str,
+        }
+        let cases = [
+            InvalidCase {
+                times: &TIMES,
+                rates: &rates[..9],
+                guess: None,
+                name: "rates",
+            },
+            InvalidCase {
+                times: &[],
+                rates: &[],
+                guess: None,
+                name: "times",
+            },
+            InvalidCase {
+                times: &TIMES[..3],
+                rates: &rates[..3],
+                guess: None,
+                name: "times",
+            },
+            InvalidCase {
+                times: &TIMES,
+                rates: &rates,
+                guess: Some(NelsonSiegel { tau: 0.0, ..curve }),
+                name: "guess",
+            },
+        ];
+        for case in &cases {
+            let result = NelsonSiegel::fit(case.times, case.rates, case.guess, None, None);
+            assert_eq!(
+                result,
+                Err(FinPrimError::InvalidArgument { name: case.name }),
+                "Failed on case: {}. Expected: InvalidArgument, Result: {:?}",
+                case.name,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_svensson() {
+        let curve = Svensson {
+            beta0: 0.05,
+            beta1: -0.02,
+            beta2: 0.01,
+            beta3: -0.015,
+            tau1: 1.5,
+            tau2: 8.0,
+        };
+        // With no second hump it is a Nelson-Siegel curve
+        let ns = NelsonSiegel {
+            beta0: 0.05,
+            beta1: -0.02,
+            beta2: 0.01,
+            tau: 1.5,
+        };
+        let flat = Svensson { beta3: 0.0, ..curve };
+        assert!((flat.zero_rate(4.0) - ns.zero_rate(4.0)).abs() < 1e-15);
+        assert!((flat.forward_rate(4.0) - ns.forward_rate(4.0)).abs() < 1e-15);
+
+        // The fitted curve reproduces exact rates
+        let rates = TIMES.map(|t| curve.zero_rate(t));
+        let fitted = Svensson::fit(&TIMES, &rates, None, Some(1e-12), Some(500)).unwrap();
+        for (t, rate) in TIMES.iter().zip(rates) {
+            let result = fitted.zero_rate(*t);
+            assert!(
+                (result - rate).abs() < 1e-7,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                t,
+                rate,
+                result
+            );
+        }
+
+        // Six parameters need at least six observations, and both guessed decays must be positive
+        let result = Svensson::fit(&TIMES[..5], &rates[..5], None, None, None);
+        assert_eq!(result, Err(FinPrimError::InvalidArgument { name: "times" }));
+        let result = Svensson::fit(&TIMES, &rates, Some(Svensson { tau2: -1.0, ..curve }), None, None);
+        assert_eq!(result, Err(FinPrimError::InvalidArgument { name: "guess" }));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_fit_decimal() {
+        use rust_decimal::Decimal;
+        use rust_decimal_macros::dec;
+
+        let times = TIMES.map(<Decimal as FloatLike>::from_f64);
+        let curve = NelsonSiegel {
+            beta0: dec!(0.05),
+            beta1: dec!(-0.02),
+            beta2: dec!(0.01),
+            tau: dec!(2),
+        };
+        let rates = times.map(|t| curve.zero_rate(t));
+        let fitted = NelsonSiegel::fit(&times, &rates, None, None, None).unwrap();
+        assert!(
+            (fitted.beta0 - curve.beta0).abs() < dec!(1e-6) && (fitted.tau - curve.tau).abs() < dec!(1e-4),
+            "Failed on case: Nelson-Siegel. Expected: {:?}, Result: {:?}",
+            curve,
+            fitted
+        );
+
+        // A far too long guessed decay is clamped during the search, so the fit returns rather than overflowing exp
+        let guess = NelsonSiegel {
+            tau: dec!(1e20),
+            ..curve
+        };
+        let fitted = NelsonSiegel::fit(&times, &rates, Some(guess), None, None);
+        assert!(
+            matches!(fitted, Ok(_) | Err(FinPrimError::RootFindingError(_))),
+            "Failed on case: long decay. Expected: a fit or a root finding error, Result: {:?}",
+            fitted
+        );
+
+        let curve = Svensson {
+            beta0: dec!(0.05),
+            beta1: dec!(-0.02),
+            beta2: dec!(0.01),
+            beta3: dec!(-0.015),
+            tau1: dec!(1.5),
+            tau2: dec!(8),
+        };
+        let rates = times.map(|t| curve.zero_rate(t));
+        let fitted = Svensson::fit(&times, &rates, None, None, Some(500)).unwrap();
+        for (t, rate) in times.iter().zip(rates) {
+            let result = fitted.zero_rate(*t);
+            assert!(
+                (result - rate).abs() < dec!(1e-6),
+                "Failed on case: {}. Expected: {}, Result: {}",
+                t,
+                rate,
+                result
+            );
+        }
+    }
+}
@@ -0,0 +1,452 @@
+use crate::curve::Compounding;
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Interpolation Method
+///
+/// Determines how a yield curve is interpolated between its pillars.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Interpolation {
+    /// Linear interpolation of the continuously compounded zero rates
+    Linear,
+    /// Linear interpolation of the log discount factors, piecewise constant forward rates between pillars
+    #[default]
+    LogLinear,
+    /// Natural cubic spline interpolation of the continuously compounded zero rates, smooth forward rates
+    CubicSpline,
+}
+
+/// Yield Curve
+///
+/// A term structure of interest rates built from pillar times and zero rates or discount factors, used to
+/// discount cash flows at the rate for their maturity rather than a single flat rate.
+///
+/// Times are in years from the valuation date. Beyond the first and last pillars the zero rate is held flat.
+///
+/// # Feature
+/// This struct requires the `std` feature to be enabled as it stores its pillars in a `std::Vec`.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::curve::{Compounding, Interpolation, YieldCurve};
+///
+/// let times = [0.5, 1.0, 2.0, 5.0];
+/// let rates = [0.040, 0.042, 0.045, 0.050];
+/// let curve = YieldCurve::from_zero_rates(&times, &rates, Compounding::Periodic(1), Interpolation::LogLinear);
+///
+/// let df = curve.discount_factor(3.0);
+/// let zero = curve.zero_rate(3.0, Compounding::Periodic(1));
+/// let fwd = curve.forward_rate(1.0, 2.0, Compounding::Periodic(1));
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "YieldCurveParts<T>",
+        bound(deserialize = "T: FloatLike + Deserialize<'de>")
+    )
+)]
+pub struct YieldCurve<T> {
+    /// Pillar times in years, strictly increasing
+    times: Vec<T>,
+    /// Continuously compounded zero rates at the pillars
+    rates: Vec<T>,
+    interpolation: Interpolation,
+    /// Second derivatives of the cubic spline at the pillars, empty for other interpolation methods
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
+    spline: Vec<T>,
+}
+
+/// Unvalidated yield curve used to validate deserialized curves and rebuild their spline
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct YieldCurveParts<T> {
+    times: Vec<T>,
+    rates: Vec<T>,
+    interpolation: Interpolation,
+}
+
+#[cfg(feature = "serde")]
+impl<T: FloatLike> TryFrom<YieldCurveParts<T>> for YieldCurve<T> {
+    type Error = &'static str;
+
+    fn try_from(parts: YieldCurveParts<T>) -> Result<Self, Self::Error> {
+        if parts.times.is_empty() || parts.times.len() != parts.rates.len() {
+            return Err("a yield curve needs the same number of pillar times and rates, at least one");
+        }
+        if !(parts.times[0] > T::zero() && parts.times.windows(2).all(|w| w[0] < w[1])) {
+            return Err("pillar times must be greater than zero and strictly increasing");
+        }
+        Ok(Self::new(&parts.times, parts.rates, parts.interpolation))
+    }
+}
+
+impl<T: FloatLike> YieldCurve<T> {
+    /// Creates a new `YieldCurve` from zero rates.
+    ///
+    /// # Arguments
+    /// * `times` - The pillar times in years, strictly increasing and greater than zero
+    /// * `rates` - The annual zero rates at the pillars
+    /// * `compounding` - The compounding convention of the zero rates
+    /// * `interpolation` - The interpolation method between pillars
+    ///
+    /// # Panics
+    /// If `times` is empty, not strictly increasing, not greater than zero, or a different length than `rates`.
+    pub fn from_zero_rates(times: &[T], rates: &[T], compounding: Compounding, interpolation: Interpolation) -> Self {
+        assert_eq!(times.len(), rates.len(), "times and rates must be the same length");
+        let rates = times
+            .iter()
+            .zip(rates)
+            .map(|(&t, &r)| compounding.continuous_rate(r, t))
+            .collect();
+        Self::new(times, rates, interpolation)
+    }
+
+    /// Creates a new `YieldCurve` from discount factors.
+    ///
+    /// # Arguments
+    /// * `times` - The pillar times in years, strictly increasing and greater than zero
+    /// * `discount_factors` - The discount factors at the pillars
+    /// * `interpolation` - The interpolation method between pillars
+    ///
+    /// # Panics
+    /// If `times` is empty, not strictly increasing, not greater than zero, or a different length than
+    /// `discount_factors`.
+    pub fn from_discount_factors(times: &[T], discount_factors: &[T], interpolation: Interpolation) -> Self {
+        assert_eq!(
+            times.len(),
+            discount_factors.len(),
+            "times and discount factors must be the same length"
+        );
+        let rates = times
+            .iter()
+            .zip(discount_factors)
+            .map(|(&t, &df)| Compounding::Continuous.rate(df, t))
+            .collect();
+        Self::new(times, rates, interpolation)
+    }
+
+    fn new(times: &[T], rates: Vec<T>, interpolation: Interpolation) -> Self {
+        assert!(!times.is_empty(), "a yield curve needs at least one pillar");
+        assert!(times[0] > T::zero(), "pillar times must be greater than zero");
+        assert!(
+            times.windows(2).all(|w| w[0] < w[1]),
+            "pillar times must be strictly increasing"
+        );
+        let spline = match interpolation {
+            Interpolation::CubicSpline => natural_spline(times, &rates),
+            _ => Vec::new(),
+        };
+        Self {
+            times: times.to_vec(),
+            rates,
+            interpolation,
+            spline,
+        }
+    }
+
+    /// The pillar times in years
+    pub fn times(&self) -> &[T] {
+        &self.times
+    }
+
+    /// The interpolation method between pillars
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Discount Factor
+    ///
+    /// The present value of 1 paid at time `t`.
+    ///
+    /// # Arguments
+    /// * `t` - The time in years
+    pub fn discount_factor(&self, t: T) -> T {
+        (-self.continuous_zero_rate(t) * t).exp()
+    }
+
+    /// Zero Rate
+    ///
+    /// The annual zero (spot) rate for time `t` under the given compounding convention.
+    ///
+    /// # Arguments
+    /// * `t` - The time in years
+    /// * `compounding` - The compounding convention of the returned rate
+    pub fn zero_rate(&self, t: T, compounding: Compounding) -> T {
+        compounding.equivalent_rate(self.continuous_zero_rate(t), t)
+    }
+
+    /// Forward Rate
+    ///
+    /// The annual forward rate between times `t1` and `t2` under the given compounding convention.
+    ///
+    /// # Arguments
+    /// * `t1` - The start of the forward period in years
+    /// * `t2` - The end of the forward period in years, should be greater than `t1`, or equal to it for the
+    ///   instantaneous forward rate at `t1`
+    /// * `compounding` - The compounding convention of the returned rate
+    ///
+    /// # Formula
+    /// The forward rate \\(f\\) satisfies:
+    /// $$DF(t_1, t_2) = \frac{DF(t_2)}{DF(t_1)}$$
+    /// Where \\(DF(t_1, t_2)\\) is the discount factor for \\(f\\) over \\(t_2 - t_1\\) under the compounding
+    /// convention. When \\(t_1 = t_2\\) this is the limit \\(\frac{d}{dt}\left(r(t) t\right)\\), taken from the right
+    /// at a pillar.
+    pub fn forward_rate(&self, t1: T, t2: T, compounding: Compounding) -> T {
+        let tau = t2 - t1;
+        if tau.is_zero() {
+            return compounding.equivalent_rate(self.instantaneous_forward_rate(t1), tau);
+        }
+        let continuous = (self.continuous_zero_rate(t2) * t2 - self.continuous_zero_rate(t1) * t1) / tau;
+        compounding.equivalent_rate(continuous, tau)
+    }
+
+    /// The continuously compounded instantaneous forward rate at time `t`, the derivative of \\(r(t) t\\)
+    fn instantaneous_forward_rate(&self, t: T) -> T {
+        let n = self.times.len();
+        if t < self.times[0] {
+            return self.rates[0];
+        }
+        if t >= self.times[n - 1] {
+            return self.rates[n - 1];
+        }
+        // times[i] <= t < times[i + 1]
+        let i = self.times.partition_point(|&x| x <= t) - 1;
+        let (t0, t1) = (self.times[i], self.times[i + 1]);
+        let (r0, r1) = (self.rates[i], self.rates[i + 1]);
+        let h = t1 - t0;
+        let w = (t - t0) / h;
+        match self.interpolation {
+            Interpolation::Linear => r0 + w * (r1 - r0) + t * (r1 - r0) / h,
+            Interpolation::LogLinear => (r1 * t1 - r0 * t0) / h,
+            Interpolation::CubicSpline => {
+                let (m0, m1) = (self.spline[i], self.spline[i + 1]);
+                let a = T::one() - w;
+                let three = T::from_u16(3);
+                let slope = (r1 - r0) / h
+                    + ((three * w * w - T::one()) * m1 - (three * a * a - T::one()) * m0) * h / T::from_u16(6);
+                self.continuous_zero_rate(t) + t * slope
+            }
+        }
+    }
+
+    /// The continuously compounded zero rate at time `t`
+    fn continuous_zero_rate(&self, t: T) -> T {
+        let n = self.times.len();
+        if t <= self.times[0] {
+            return self.rates[0];
+        }
+        if t >= self.times[n - 1] {
+            return self.rates[n - 1];
+        }
+        // times[i] <= t < times[i + 1]
+        let i = self.times.partition_point(|&x| x <= t) - 1;
+        let (t0, t1) = (self.times[i], self.times[i + 1]);
+        let (r0, r1) = (self.rates[i], self.rates[i + 1]);
+        let h = t1 - t0;
+        let w = (t - t0) / h;
+        match self.interpolation {
+            Interpolation::Linear => r0 + w * (r1 - r0),
+            Interpolation::LogLinear => ((T::one() - w) * r0 * t0 + w * r1 * t1) / t,
+            Interpolation::CubicSpline => {
+                let (m0, m1) = (self.spline[i], self.spline[i + 1]);
+                let a = T::one() - w;
+                a * r0 + w * r1 + ((a * a * a - a) * m0 + (w * w * w - w) * m1) * h * h / T::from_u16(6)
+            }
+        }
+    }
+}
+
+/// Returns the second derivatives of the natural cubic spline through the points, solving the tridiagonal
+/// system with the Thomas algorithm
+fn natural_spline<T: FloatLike>(x: &[T], y: &[T]) -> Vec<T> {
+    let n = x.len();
+    let mut m = vec![T::zero(); n];
+    if n < 3 {
+        return m;
+    }
+    let six = T::from_u16(6);
+    // Forward sweep over the interior points
+    let mut c_prime = vec![T::zero(); n];
+    let mut d_prime = vec![T::zero(); n];
+    for i in 1..n - 1 {
+        let h0 = x[i] - x[i - 1];
+        let h1 = x[i + 1] - x[i];
+        let d = six * ((y[i + 1] - y[i]) / h1 - (y[i] - y[i - 1]) / h0);
+        let b = T::two() * (h0 + h1) - h0 * c_prime[i - 1];
+        c_prime[i] = h1 / b;
+        d_prime[i] = (d - h0 * d_prime[i - 1]) / b;
+    }
+    // Back substitution, the natural end conditions fix m[0] and m[n - 1] at zero
+    for i in (1..n - 1).rev() {
+        m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+    }
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_curve() {
+        let times = [1.0, 5.0, 10.0];
+        let rates = [0.05, 0.05, 0.05];
+        for interpolation in [
+            Interpolation::Linear,
+            Interpolation::LogLinear,
+            Interpolation::CubicSpline,
+        ] {
+            let curve = YieldCurve::from_zero_rates(&times, &rates, Compounding::Periodic(1), interpolation);
+            for t in [0.25, 1.0, 3.7, 10.0, 20.0] {
+                let df: f64 = curve.discount_factor(t);
+                let expected = 1.05f64.powf(-t);
+                assert!(
+                    (df - expected).abs() < 1e-12,
+                    "Failed on case: {:?} at {}. Expected: {}, Result: {}",
+                    interpolation,
+                    t,
+                    expected,
+                    df
+                );
+            }
+            let fwd = curve.forward_rate(2.0, 3.0, Compounding::Periodic(1));
+            assert!((fwd - 0.05).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_interpolation() {
+        struct TestCase {
+            interpolation: Interpolation,
+            t: f64,
+            expected: f64,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(interpolation: Interpolation, t: f64, expected: f64, description: &'static str) -> Self {
+                TestCase {
+                    interpolation,
+                    t,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let times = [1.0, 2.0, 4.0];
+        let rates = [0.02, 0.03, 0.04];
+        let cases = [
+            TestCase::new(Interpolation::Linear, 1.5, 0.025, "Linear zero rate"),
+            TestCase::new(
+                Interpolation::LogLinear,
+                1.5,
+                (0.5 * 0.02 + 0.5 * 0.06) / 1.5,
+                "Log-linear discount factor",
+            ),
+            TestCase::new(
+                Interpolation::CubicSpline,
+                2.0,
+                0.03,
+                "Cubic spline passes through pillars",
+            ),
+            TestCase::new(Interpolation::CubicSpline, 3.0, 0.03625, "Cubic spline between pillars"),
+            TestCase::new(Interpolation::Linear, 0.5, 0.02, "Flat before the first pillar"),
+            TestCase::new(Interpolation::LogLinear, 10.0, 0.04, "Flat after the last pillar"),
+        ];
+
+        for case in &cases {
+            let curve = YieldCurve::from_zero_rates(&times, &rates, Compounding::Continuous, case.interpolation);
+            let result = curve.zero_rate(case.t, Compounding::Continuous);
+            assert!(
+                (result - case.expected).abs() < 1e-12,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.description,
+                case.expected,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_forward_rate() {
+        // Log-linear forwards are constant between pillars
+        let times = [1.0, 2.0];
+        let dfs = [0.97, 0.93];
+        let curve = YieldCurve::from_discount_factors(&times, &dfs, Interpolation::LogLinear);
+        let f1: f64 = curve.forward_rate(1.1, 1.4, Compounding::Continuous);
+        let f2 = curve.forward_rate(1.5, 1.9, Compounding::Continuous);
+        assert!((f1 - f2).abs() < 1e-12);
+        assert!((f1 - (0.97f64 / 0.93).ln()).abs() < 1e-12);
+
+        // Simple forward rate matches the discount factor ratio
+        let simple = curve.forward_rate(1.0, 2.0, Compounding::Simple);
+        assert!((simple - (0.97 / 0.93 - 1.0)).abs() < 1e-12);
+        assert!((curve.discount_factor(2.0) - 0.93).abs() < 1e-12);
+
+        // A zero length period gives the instantaneous forward rate rather than dividing by zero
+        let instantaneous = curve.forward_rate(1.5, 1.5, Compounding::Continuous);
+        assert!((instantaneous - f1).abs() < 1e-12);
+        let times = [1.0, 2.0, 5.0, 10.0];
+        let rates = [0.02, 0.03, 0.035, 0.04];
+        for interpolation in [
+            Interpolation::Linear,
+            Interpolation::LogLinear,
+            Interpolation::CubicSpline,
+        ] {
+            let curve = YieldCurve::from_zero_rates(&times, &rates, Compounding::Continuous, interpolation);
+            for t in [0.5, 1.0, 3.0, 7.5, 12.0] {
+                let expected = curve.forward_rate(t, t + 1e-7, Compounding::Continuous);
+                let result = curve.forward_rate(t, t, Compounding::Continuous);
+                assert!(
+                    (result - expected).abs() < 1e-6,
+                    "Failed on case: {:?} at {}. Expected: {}, Result: {}",
+                    interpolation,
+                    t,
+                    expected,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unsorted_pillars() {
+        YieldCurve::from_zero_rates(
+            &[2.0, 1.0],
+            &[0.01, 0.02],
+            Compounding::Continuous,
+            Interpolation::Linear,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let curve = YieldCurve::from_zero_rates(
+            &[1.0, 2.0, 5.0],
+            &[0.03, 0.035, 0.04],
+            Compounding::Continuous,
+            Interpolation::CubicSpline,
+        );
+        let serialized = serde_json::to_string(&curve).unwrap();
+        assert!(!serialized.contains("spline"));
+        let deserialized: YieldCurve<f64> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(curve, deserialized);
+
+        let invalid = [
+            r#"{"times":[],"rates":[],"interpolation":"Linear"}"#,
+            r#"{"times":[1.0,2.0],"rates":[0.03],"interpolation":"Linear"}"#,
+            r#"{"times":[2.0,1.0],"rates":[0.03,0.04],"interpolation":"CubicSpline"}"#,
+            r#"{"times":[0.0,1.0],"rates":[0.03,0.04],"interpolation":"LogLinear"}"#,
+        ];
+        for json in invalid {
+            let result: Result<YieldCurve<f64>, _> = serde_json::from_str(json);
+            assert!(result.is_err(), "Failed on case: {}", json);
+        }
+    }
+}
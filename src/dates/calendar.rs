@@ -0,0 +1,429 @@
+use crate::dates::Date;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Holiday Calendar
+///
+/// Determines which dates are business days. A date is a business day if it is neither a weekend day nor a
+/// holiday of the calendar.
+///
+/// Implement this trait to provide your own market calendars, only `is_holiday` is required. Weekends default
+/// to Saturday and Sunday.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::dates::{Date, HolidayCalendar, HolidayList};
+///
+/// let holidays = [Date::new(2024, 12, 25).unwrap(), Date::new(2024, 12, 26).unwrap()];
+/// let calendar = HolidayList::new(&holidays);
+/// assert!(!calendar.is_business_day(Date::new(2024, 12, 25).unwrap()));
+/// assert!(calendar.is_business_day(Date::new(2024, 12, 27).unwrap()));
+/// ```
+pub trait HolidayCalendar {
+    /// Returns true if the date is a holiday, not including weekends
+    fn is_holiday(&self, date: Date) -> bool;
+
+    /// Returns true if the date falls on a weekend, Saturday and Sunday by default
+    fn is_weekend(&self, date: Date) -> bool {
+        date.weekday().is_weekend()
+    }
+
+    /// Returns true if the date is neither a weekend day nor a holiday
+    fn is_business_day(&self, date: Date) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+}
+
+impl<C: HolidayCalendar + ?Sized> HolidayCalendar for &C {
+    fn is_holiday(&self, date: Date) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn is_weekend(&self, date: Date) -> bool {
+        (**self).is_weekend(date)
+    }
+
+    fn is_business_day(&self, date: Date) -> bool {
+        (**self).is_business_day(date)
+    }
+}
+
+/// Weekends Only Calendar
+///
+/// A calendar without holidays, every weekday is a business day.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeekendsOnly;
+
+impl HolidayCalendar for WeekendsOnly {
+    fn is_holiday(&self, _date: Date) -> bool {
+        false
+    }
+}
+
+/// Holiday List Calendar
+///
+/// A calendar with weekends and a user-supplied list of holidays, similar to the `holidays` argument of the
+/// Excel `WORKDAY` and `NETWORKDAYS` functions. The list does not need to be sorted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct HolidayList<'a> {
+    holidays: &'a [Date],
+}
+
+impl<'a> HolidayList<'a> {
+    /// Creates a new `HolidayList` calendar from a slice of holidays.
+    pub fn new(holidays: &'a [Date]) -> Self {
+        Self { holidays }
+    }
+}
+
+impl HolidayCalendar for HolidayList<'_> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.holidays.contains(&date)
+    }
+}
+
+/// Joint Calendar
+///
+/// Combines two calendars, a date is a business day only if it is a business day in both calendars. Useful
+/// for cross-border payments which must settle on a day both markets are open.
+///
+/// Joint calendars can be nested to combine more than two calendars.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::dates::{Date, HolidayCalendar, HolidayList, JointCalendar};
+///
+/// let us = [Date::new(2024, 7, 4).unwrap()];
+/// let uk = [Date::new(2024, 8, 26).unwrap()];
+/// let calendar = JointCalendar::new(HolidayList::new(&us), HolidayList::new(&uk));
+/// assert!(calendar.is_holiday(Date::new(2024, 7, 4).unwrap()));
+/// assert!(calendar.is_holiday(Date::new(2024, 8, 26).unwrap()));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JointCalendar<A, B> {
+    pub first: A,
+    pub second: B,
+}
+
+impl<A: HolidayCalendar, B: HolidayCalendar> JointCalendar<A, B> {
+    /// Creates a new `JointCalendar` instance.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A: HolidayCalendar, B: HolidayCalendar> HolidayCalendar for JointCalendar<A, B> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.first.is_holiday(date) || self.second.is_holiday(date)
+    }
+
+    fn is_weekend(&self, date: Date) -> bool {
+        self.first.is_weekend(date) || self.second.is_weekend(date)
+    }
+}
+
+/// Business Day Convention
+///
+/// Determines how a date falling on a non-business day is rolled to a business day.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BusinessDayConvention {
+    /// The date is not adjusted
+    #[default]
+    Unadjusted,
+    /// The next business day
+    Following,
+    /// The next business day, unless it falls in the next month, in which case the previous business day
+    ModifiedFollowing,
+    /// The previous business day
+    Preceding,
+    /// The previous business day, unless it falls in the previous month, in which case the next business day
+    ModifiedPreceding,
+}
+
+impl BusinessDayConvention {
+    /// Adjust
+    ///
+    /// Rolls a date to a business day of the calendar according to the convention. Business days are
+    /// returned unchanged. The search gives up after a year, so a calendar without business days returns the
+    /// date unadjusted.
+    ///
+    /// # Arguments
+    /// * `date` - The date to adjust
+    /// * `calendar` - The holiday calendar determining the business days
+    ///
+    /// # Returns
+    /// * The adjusted date
+    ///
+    /// # Examples
+    /// * 2024-08-31 is a Saturday, the following business day is in September
+    /// ```
+    /// use rust_finprim::dates::{BusinessDayConvention, Date, WeekendsOnly};
+    ///
+    /// let date = Date::new(2024, 8, 31).unwrap();
+    /// let following = BusinessDayConvention::Following.adjust(date, &WeekendsOnly);
+    /// let modified = BusinessDayConvention::ModifiedFollowing.adjust(date, &WeekendsOnly);
+    /// assert_eq!(following, Date::new(2024, 9, 2).unwrap());
+    /// assert_eq!(modified, Date::new(2024, 8, 30).unwrap());
+    /// ```
+    pub fn adjust<C: HolidayCalendar + ?Sized>(&self, date: Date, calendar: &C) -> Date {
+        let adjusted = match self {
+            BusinessDayConvention::Unadjusted => Some(date),
+            BusinessDayConvention::Following => roll(date, 1, calendar),
+            BusinessDayConvention::Preceding => roll(date, -1, calendar),
+            BusinessDayConvention::ModifiedFollowing => roll(date, 1, calendar)
+                .filter(|adjusted| adjusted.month() == date.month())
+                .or_else(|| roll(date, -1, calendar)),
+            BusinessDayConvention::ModifiedPreceding => roll(date, -1, calendar)
+                .filter(|adjusted| adjusted.month() == date.month())
+                .or_else(|| roll(date, 1, calendar)),
+        };
+        adjusted.unwrap_or(date)
+    }
+}
+
+/// The most days `roll` steps over looking for a business day
+const MAX_ROLL_DAYS: u32 = 366;
+
+/// Steps one day at a time in the given direction until a business day is reached, or `None` if there is none
+/// within `MAX_ROLL_DAYS`
+fn roll<C: HolidayCalendar + ?Sized>(mut date: Date, step: i32, calendar: &C) -> Option<Date> {
+    for _ in 0..=MAX_ROLL_DAYS {
+        if calendar.is_business_day(date) {
+            return Some(date);
+        }
+        date = date.add_days(step);
+    }
+    None
+}
+
+/// WORKDAY - Date a number of business days away
+///
+/// The date a number of business days before or after a start date, similar to the Excel `WORKDAY` function.
+/// The start date itself is not counted.
+///
+/// # Arguments
+/// * `start` - The start date
+/// * `days` - The number of business days to move, negative to move backwards
+/// * `calendar` - The holiday calendar determining the business days
+///
+/// # Returns
+/// * The business day `days` business days from `start`, or `start` if `days` is 0 or the calendar has no business
+///   day within a year of a step
+///
+/// # Example
+/// * 10 business days after Friday 2024-12-20 with Christmas and Boxing Day off
+/// ```
+/// use rust_finprim::dates::{workday, Date, HolidayList};
+///
+/// let holidays = [Date::new(2024, 12, 25).unwrap(), Date::new(2024, 12, 26).unwrap()];
+/// let start = Date::new(2024, 12, 20).unwrap();
+/// let date = workday(start, 10, &HolidayList::new(&holidays));
+/// assert_eq!(date, Date::new(2025, 1, 7).unwrap());
+/// ```
+pub fn workday<C: HolidayCalendar + ?Sized>(start: Date, days: i32, calendar: &C) -> Date {
+    let step = if days < 0 { -1 } else { 1 };
+    let mut date = start;
+    for _ in 0..days.unsigned_abs() {
+        let Some(next) = roll(date.add_days(step), step, calendar) else {
+            return start;
+        };
+        date = next;
+    }
+    date
+}
+
+/// NETWORKDAYS - Number of business days between two dates
+///
+/// The number of business days between two dates, including both the start and end dates, similar to the
+/// Excel `NETWORKDAYS` function.
+///
+/// # Arguments
+/// * `start` - The start date
+/// * `end` - The end date
+/// * `calendar` - The holiday calendar determining the business days
+///
+/// # Returns
+/// * The number of business days from `start` to `end` inclusive, negative if `end` is before `start`
+///
+/// # Example
+/// * Business days in January 2024 with New Year's Day off
+/// ```
+/// use rust_finprim::dates::{networkdays, Date, HolidayList};
+///
+/// let holidays = [Date::new(2024, 1, 1).unwrap()];
+/// let start = Date::new(2024, 1, 1).unwrap();
+/// let end = Date::new(2024, 1, 31).unwrap();
+/// assert_eq!(networkdays(start, end, &HolidayList::new(&holidays)), 22);
+/// ```
+pub fn networkdays<C: HolidayCalendar + ?Sized>(start: Date, end: Date, calendar: &C) -> i32 {
+    if end < start {
+        return -networkdays(end, start, calendar);
+    }
+    let mut count = 0;
+    let mut date = start;
+    while date <= end {
+        if calendar.is_business_day(date) {
+            count += 1;
+        }
+        date = date.add_days(1);
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_calendars() {
+        let holidays = [date(2024, 12, 25)];
+        let list = HolidayList::new(&holidays);
+        assert!(list.is_holiday(date(2024, 12, 25)));
+        assert!(!list.is_holiday(date(2024, 12, 28)));
+        assert!(!list.is_business_day(date(2024, 12, 28)));
+        assert!(WeekendsOnly.is_business_day(date(2024, 12, 25)));
+
+        let other = [date(2024, 12, 26)];
+        let joint = JointCalendar::new(list, HolidayList::new(&other));
+        assert!(!joint.is_business_day(date(2024, 12, 25)));
+        assert!(!joint.is_business_day(date(2024, 12, 26)));
+        assert!(joint.is_business_day(date(2024, 12, 27)));
+    }
+
+    #[test]
+    fn test_adjust() {
+        struct TestCase {
+            convention: BusinessDayConvention,
+            date: Date,
+            expected: Date,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(convention: BusinessDayConvention, date: Date, expected: Date, description: &'static str) -> Self {
+                TestCase {
+                    convention,
+                    date,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        // 2024-03-29 is Good Friday, 2024-04-01 is Easter Monday
+        let holidays = [date(2024, 3, 29), date(2024, 4, 1)];
+        let calendar = HolidayList::new(&holidays);
+
+        let cases = [
+            TestCase::new(
+                BusinessDayConvention::Unadjusted,
+                date(2024, 3, 30),
+                date(2024, 3, 30),
+                "Unadjusted Saturday",
+            ),
+            TestCase::new(
+                BusinessDayConvention::Following,
+                date(2024, 3, 30),
+                date(2024, 4, 2),
+                "Following over the weekend and Easter Monday",
+            ),
+            TestCase::new(
+                BusinessDayConvention::ModifiedFollowing,
+                date(2024, 3, 30),
+                date(2024, 3, 28),
+                "Modified following stays in March",
+            ),
+            TestCase::new(
+                BusinessDayConvention::Preceding,
+                date(2024, 3, 31),
+                date(2024, 3, 28),
+                "Preceding over the weekend and Good Friday",
+            ),
+            TestCase::new(
+                BusinessDayConvention::ModifiedPreceding,
+                date(2024, 6, 1),
+                date(2024, 6, 3),
+                "Modified preceding stays in June",
+            ),
+            TestCase::new(
+                BusinessDayConvention::ModifiedFollowing,
+                date(2024, 4, 2),
+                date(2024, 4, 2),
+                "Business days are not adjusted",
+            ),
+        ];
+
+        for case in &cases {
+            let result = case.convention.adjust(case.date, &calendar);
+            assert_eq!(
+                result, case.expected,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.description, case.expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn test_workday() {
+        let holidays = [date(2024, 12, 25), date(2024, 12, 26), date(2025, 1, 1)];
+        let calendar = HolidayList::new(&holidays);
+        let start = date(2024, 12, 20);
+        assert_eq!(workday(start, 0, &calendar), start);
+        assert_eq!(workday(start, 1, &calendar), date(2024, 12, 23));
+        assert_eq!(workday(start, 3, &calendar), date(2024, 12, 27));
+        assert_eq!(workday(start, 10, &calendar), date(2025, 1, 8));
+        assert_eq!(workday(date(2025, 1, 2), -2, &calendar), date(2024, 12, 30));
+        // A weekend start date moves to the next business day
+        assert_eq!(workday(date(2024, 12, 21), 1, &WeekendsOnly), date(2024, 12, 23));
+    }
+
+    #[test]
+    fn test_no_business_days() {
+        // A calendar where every day is a holiday gives up rather than searching forever
+        struct Closed;
+        impl HolidayCalendar for Closed {
+            fn is_holiday(&self, _date: Date) -> bool {
+                true
+            }
+        }
+        let start = date(2024, 12, 21);
+        for convention in [
+            BusinessDayConvention::Following,
+            BusinessDayConvention::ModifiedFollowing,
+            BusinessDayConvention::Preceding,
+            BusinessDayConvention::ModifiedPreceding,
+        ] {
+            assert_eq!(
+                convention.adjust(start, &Closed),
+                start,
+                "Failed on case: {:?}",
+                convention
+            );
+        }
+        assert_eq!(workday(start, 5, &Closed), start);
+        assert_eq!(workday(start, -5, &Closed), start);
+    }
+
+    #[test]
+    fn test_networkdays() {
+        let holidays = [date(2024, 12, 25), date(2024, 12, 26)];
+        let calendar = HolidayList::new(&holidays);
+        let start = date(2024, 12, 1);
+        let end = date(2024, 12, 31);
+        assert_eq!(networkdays(start, end, &WeekendsOnly), 22);
+        assert_eq!(networkdays(start, end, &calendar), 20);
+        assert_eq!(networkdays(end, start, &calendar), -20);
+        assert_eq!(networkdays(start, start, &calendar), 0);
+    }
+}
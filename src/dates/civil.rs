@@ -0,0 +1,106 @@
+//! Proleptic Gregorian calendar arithmetic on plain integers.
+//!
+//! Day numbers are Excel 1900 date serials, i.e. the number of days since 1899-12-30, which agree with
+//! Excel for every date from 1900-03-01 onward.
+
+/// Days between 1899-12-30 and the 0000-03-01 epoch of the era based algorithms below
+const EPOCH_OFFSET: i32 = 693_899;
+
+/// Returns true if the year is a leap year in the proleptic Gregorian calendar
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in the month of the given year
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        _ if is_leap_year(year) => 29,
+        _ => 28,
+    }
+}
+
+/// Returns the number of days in the year
+pub(crate) fn days_in_year(year: i32) -> i32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Converts a civil date to its day number
+///
+/// Based on Howard Hinnant's `days_from_civil` algorithm, which splits the calendar into 400 year eras
+/// starting on March 1st so the leap day falls at the end of the year.
+pub(crate) fn days_from_civil(year: i32, month: u8, day: u8) -> i32 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i32;
+    let mp = if m > 2 { m - 3 } else { m + 9 };
+    let doy = (153 * mp + 2) / 5 + day as i32 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - EPOCH_OFFSET
+}
+
+/// Converts a day number to its civil date `(year, month, day)`
+///
+/// The inverse of `days_from_civil`.
+pub(crate) fn civil_from_days(days: i32) -> (i32, u8, u8) {
+    let z = days + EPOCH_OFFSET;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Adds a number of months to a civil date, clamping the day to the end of the resulting month
+pub(crate) fn add_months(year: i32, month: u8, day: u8, months: i32) -> (i32, u8, u8) {
+    let total = year * 12 + (month as i32 - 1) + months;
+    let year = total.div_euclid(12);
+    let month = (total.rem_euclid(12) + 1) as u8;
+    (year, month, day.min(days_in_month(year, month)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_civil_round_trip() {
+        // Known Excel serials
+        assert_eq!(days_from_civil(1900, 3, 1), 61);
+        assert_eq!(days_from_civil(1970, 1, 1), 25_569);
+        assert_eq!(days_from_civil(2000, 2, 29), 36_585);
+        assert_eq!(days_from_civil(2024, 12, 31), 45_657);
+        assert_eq!(civil_from_days(45_657), (2024, 12, 31));
+        assert_eq!(civil_from_days(0), (1899, 12, 30));
+
+        for days in -800_000..800_000 {
+            let (y, m, d) = civil_from_days(days);
+            assert!((1..=12).contains(&m) && (1..=days_in_month(y, m)).contains(&d));
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_add_months() {
+        assert_eq!(add_months(2024, 1, 31, 1), (2024, 2, 29));
+        assert_eq!(add_months(2023, 1, 31, 1), (2023, 2, 28));
+        assert_eq!(add_months(2024, 3, 15, -3), (2023, 12, 15));
+        assert_eq!(add_months(2024, 12, 31, 12), (2025, 12, 31));
+        assert_eq!(add_months(2024, 5, 31, -15), (2023, 2, 28));
+    }
+}
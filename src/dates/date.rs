@@ -0,0 +1,480 @@
+use crate::dates::civil;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The DateLike trait abstracts over types that can be used as dates by the functions working with
+/// dated cash flows. By default, it is implemented for `Date` and for `i32` day serials.
+///
+/// Dates are reduced to Excel 1900 date serials, the number of days since 1899-12-30. You can implement
+/// the `DateLike` trait for the date type of your time library of choice.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::dates::{Date, DateLike};
+///
+/// let date = Date::new(2024, 1, 15).unwrap();
+/// assert_eq!(date.day_number(), 45306);
+/// assert_eq!(45306.day_number(), 45306);
+/// ```
+pub trait DateLike: Copy {
+    /// The number of days since 1899-12-30
+    fn day_number(self) -> i32;
+}
+
+impl DateLike for i32 {
+    fn day_number(self) -> i32 {
+        self
+    }
+}
+
+impl DateLike for Date {
+    fn day_number(self) -> i32 {
+        civil::days_from_civil(self.year, self.month, self.day)
+    }
+}
+
+/// Excel Date System
+///
+/// Excel workbooks number dates from one of two epochs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExcelDateSystem {
+    /// The 1900 date system, the default on Windows. Serial 1 is 1900-01-01.
+    ///
+    /// Excel treats 1900 as a leap year, so serial 60 is the non-existent 1900-02-29 and every serial
+    /// before it is off by one day from the actual number of days since the epoch.
+    Excel1900,
+    /// The 1904 date system, used by older Mac workbooks. Serial 0 is 1904-01-01.
+    Excel1904,
+}
+
+/// Day of the Week
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns true for Saturday and Sunday
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+/// Date
+///
+/// A civil calendar date in the proleptic Gregorian calendar, with no time zone or time of day.
+///
+/// Dates are ordered chronologically and can be used in place of day serials anywhere a `DateLike`
+/// is accepted, such as the flow tables of `xnpv`, `xirr` and `xmirr`.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::dates::{Date, Weekday};
+///
+/// let date = Date::new(2024, 1, 31).unwrap();
+/// assert_eq!(date.weekday(), Weekday::Wednesday);
+/// assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DateParts"))]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// Unvalidated date used to validate deserialized dates
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct DateParts {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DateParts> for Date {
+    type Error = &'static str;
+
+    fn try_from(parts: DateParts) -> Result<Self, Self::Error> {
+        Date::new(parts.year, parts.month, parts.day).ok_or("invalid calendar date")
+    }
+}
+
+impl Date {
+    /// Creates a new `Date` instance.
+    ///
+    /// # Arguments
+    /// * `year` - The calendar year
+    /// * `month` - The month of the year, 1 to 12
+    /// * `day` - The day of the month, starting at 1
+    ///
+    /// # Returns
+    /// * The date, or `None` if the month or day is out of range
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::Date;
+    ///
+    /// assert!(Date::new(2024, 2, 29).is_some());
+    /// assert!(Date::new(2023, 2, 29).is_none());
+    /// ```
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > civil::days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Creates a `Date` from a year and the day of the year (ordinal date).
+    ///
+    /// # Arguments
+    /// * `year` - The calendar year
+    /// * `ordinal` - The day of the year, 1 to 365 (366 in leap years)
+    ///
+    /// # Returns
+    /// * The date, or `None` if the ordinal is out of range
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::Date;
+    ///
+    /// assert_eq!(Date::from_ordinal(2024, 60), Date::new(2024, 2, 29));
+    /// ```
+    pub fn from_ordinal(year: i32, ordinal: u16) -> Option<Self> {
+        if ordinal == 0 || ordinal as i32 > civil::days_in_year(year) {
+            return None;
+        }
+        Some(Self::from_day_number(
+            civil::days_from_civil(year, 1, 1) + ordinal as i32 - 1,
+        ))
+    }
+
+    /// Creates a `Date` from the number of days since 1899-12-30, the inverse of `DateLike::day_number`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::Date;
+    ///
+    /// assert_eq!(Date::from_day_number(45306), Date::new(2024, 1, 15).unwrap());
+    /// ```
+    pub fn from_day_number(days: i32) -> Self {
+        let (year, month, day) = civil::civil_from_days(days);
+        Self { year, month, day }
+    }
+
+    /// Creates a `Date` from an Excel date serial.
+    ///
+    /// # Arguments
+    /// * `serial` - The Excel date serial
+    /// * `system` - The date system of the workbook the serial is from
+    ///
+    /// # Returns
+    /// * The date, or `None` if the serial does not represent a real date (serials before the epoch and
+    ///   serial 60, Excel's fictitious 1900-02-29, in the 1900 date system)
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::{Date, ExcelDateSystem};
+    ///
+    /// let date = Date::from_excel_serial(45306, ExcelDateSystem::Excel1900);
+    /// assert_eq!(date, Date::new(2024, 1, 15));
+    /// ```
+    pub fn from_excel_serial(serial: i32, system: ExcelDateSystem) -> Option<Self> {
+        match system {
+            ExcelDateSystem::Excel1900 => match serial {
+                i32::MIN..=0 | 60 => None,
+                // Serials before the fictitious leap day count from 1899-12-31
+                1..=59 => Some(Self::from_day_number(serial + 1)),
+                _ => Some(Self::from_day_number(serial)),
+            },
+            ExcelDateSystem::Excel1904 => {
+                if serial < 0 {
+                    None
+                } else {
+                    Some(Self::from_day_number(serial + EXCEL_1904_OFFSET))
+                }
+            }
+        }
+    }
+
+    /// The Excel date serial of the date.
+    ///
+    /// # Arguments
+    /// * `system` - The date system of the workbook the serial is for
+    ///
+    /// # Returns
+    /// * The Excel date serial. Dates before the epoch of the date system have no Excel serial and
+    ///   return values at or below zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::{Date, ExcelDateSystem};
+    ///
+    /// let date = Date::new(2024, 1, 15).unwrap();
+    /// assert_eq!(date.to_excel_serial(ExcelDateSystem::Excel1900), 45306);
+    /// assert_eq!(date.to_excel_serial(ExcelDateSystem::Excel1904), 43844);
+    /// ```
+    pub fn to_excel_serial(&self, system: ExcelDateSystem) -> i32 {
+        let days = self.day_number();
+        match system {
+            // Dates before 1900-03-01 are not shifted by the fictitious leap day
+            ExcelDateSystem::Excel1900 if days < 61 => days - 1,
+            ExcelDateSystem::Excel1900 => days,
+            ExcelDateSystem::Excel1904 => days - EXCEL_1904_OFFSET,
+        }
+    }
+
+    /// The calendar year
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// The month of the year, 1 to 12
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month, starting at 1
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The day of the year, starting at 1 on January 1st
+    pub fn ordinal(&self) -> u16 {
+        (self.day_number() - civil::days_from_civil(self.year, 1, 1) + 1) as u16
+    }
+
+    /// The day of the week
+    pub fn weekday(&self) -> Weekday {
+        // 1899-12-30 was a Saturday
+        match (self.day_number() + 5).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// Returns true if the date falls in a leap year
+    pub fn is_leap_year(&self) -> bool {
+        civil::is_leap_year(self.year)
+    }
+
+    /// The number of days in the month of the date
+    pub fn days_in_month(&self) -> u8 {
+        civil::days_in_month(self.year, self.month)
+    }
+
+    /// Returns true if the date is the last day of its month
+    pub fn is_end_of_month(&self) -> bool {
+        self.day == self.days_in_month()
+    }
+
+    /// The last day of the month of the date, similar to the Excel `EOMONTH` function with 0 months.
+    pub fn end_of_month(&self) -> Self {
+        Self {
+            day: self.days_in_month(),
+            ..*self
+        }
+    }
+
+    /// Adds a number of days to the date, negative to subtract.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::Date;
+    ///
+    /// let date = Date::new(2024, 2, 28).unwrap();
+    /// assert_eq!(date.add_days(2), Date::new(2024, 3, 1).unwrap());
+    /// ```
+    pub fn add_days(&self, days: i32) -> Self {
+        Self::from_day_number(self.day_number() + days)
+    }
+
+    /// Adds a number of months to the date, negative to subtract, similar to the Excel `EDATE` function.
+    ///
+    /// If the day does not exist in the resulting month, the last day of that month is used. For example,
+    /// adding one month to January 31st gives the last day of February.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::Date;
+    ///
+    /// let date = Date::new(2023, 8, 31).unwrap();
+    /// assert_eq!(date.add_months(6), Date::new(2024, 2, 29).unwrap());
+    /// assert_eq!(date.add_months(-2), Date::new(2023, 6, 30).unwrap());
+    /// ```
+    pub fn add_months(&self, months: i32) -> Self {
+        let (year, month, day) = civil::add_months(self.year, self.month, self.day, months);
+        Self { year, month, day }
+    }
+
+    /// The number of days from this date to another date, negative if the other date is earlier.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::Date;
+    ///
+    /// let start = Date::new(2024, 1, 1).unwrap();
+    /// let end = Date::new(2025, 1, 1).unwrap();
+    /// assert_eq!(start.days_until(end), 366);
+    /// ```
+    pub fn days_until(&self, other: Self) -> i32 {
+        other.day_number() - self.day_number()
+    }
+}
+
+/// Days between 1899-12-30 and 1904-01-01
+const EXCEL_1904_OFFSET: i32 = 1462;
+
+/// Formats the date as ISO 8601 `YYYY-MM-DD`
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq, format};
+
+    #[test]
+    fn test_date_new() {
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(1900, 2, 29).is_none());
+        assert!(Date::new(2000, 2, 29).is_some());
+        assert!(Date::new(2024, 4, 31).is_none());
+        assert!(Date::new(2024, 0, 1).is_none());
+        assert!(Date::new(2024, 13, 1).is_none());
+        assert!(Date::new(2024, 1, 0).is_none());
+    }
+
+    #[test]
+    fn test_ordinal() {
+        let date = Date::new(2024, 12, 31).unwrap();
+        assert_eq!(date.ordinal(), 366);
+        assert_eq!(Date::from_ordinal(2024, 366), Some(date));
+        assert_eq!(Date::from_ordinal(2023, 366), None);
+        assert_eq!(Date::from_ordinal(2023, 0), None);
+        assert_eq!(Date::from_ordinal(2023, 32), Date::new(2023, 2, 1));
+    }
+
+    #[test]
+    fn test_excel_serial() {
+        struct TestCase {
+            serial: i32,
+            system: ExcelDateSystem,
+            expected: Option<Date>,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(serial: i32, system: ExcelDateSystem, expected: Option<Date>, description: &'static str) -> Self {
+                TestCase {
+                    serial,
+                    system,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(1, ExcelDateSystem::Excel1900, Date::new(1900, 1, 1), "1900 epoch"),
+            TestCase::new(
+                59,
+                ExcelDateSystem::Excel1900,
+                Date::new(1900, 2, 28),
+                "Before the leap day",
+            ),
+            TestCase::new(60, ExcelDateSystem::Excel1900, None, "Fictitious 1900-02-29"),
+            TestCase::new(
+                61,
+                ExcelDateSystem::Excel1900,
+                Date::new(1900, 3, 1),
+                "After the leap day",
+            ),
+            TestCase::new(45306, ExcelDateSystem::Excel1900, Date::new(2024, 1, 15), "Modern date"),
+            TestCase::new(0, ExcelDateSystem::Excel1900, None, "Before the 1900 epoch"),
+            TestCase::new(0, ExcelDateSystem::Excel1904, Date::new(1904, 1, 1), "1904 epoch"),
+            TestCase::new(
+                43844,
+                ExcelDateSystem::Excel1904,
+                Date::new(2024, 1, 15),
+                "Modern 1904 date",
+            ),
+            TestCase::new(-1, ExcelDateSystem::Excel1904, None, "Before the 1904 epoch"),
+        ];
+
+        for case in &cases {
+            let result = Date::from_excel_serial(case.serial, case.system);
+            assert_eq!(
+                result, case.expected,
+                "Failed on case: {}. Expected: {:?}, Result: {:?}",
+                case.description, case.expected, result
+            );
+            if let Some(date) = result {
+                assert_eq!(date.to_excel_serial(case.system), case.serial);
+            }
+        }
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(Date::new(1899, 12, 30).unwrap().weekday(), Weekday::Saturday);
+        assert_eq!(Date::new(2024, 1, 15).unwrap().weekday(), Weekday::Monday);
+        assert_eq!(Date::new(2000, 1, 1).unwrap().weekday(), Weekday::Saturday);
+        assert_eq!(Date::new(1800, 1, 1).unwrap().weekday(), Weekday::Wednesday);
+        assert!(Date::new(2024, 1, 14).unwrap().weekday().is_weekend());
+    }
+
+    #[test]
+    fn test_add_months() {
+        let date = Date::new(2024, 1, 31).unwrap();
+        assert_eq!(date.add_months(1), Date::new(2024, 2, 29).unwrap());
+        assert_eq!(date.add_months(13), Date::new(2025, 2, 28).unwrap());
+        assert_eq!(date.add_months(-2), Date::new(2023, 11, 30).unwrap());
+        assert_eq!(date.add_months(0), date);
+        assert!(Date::new(2024, 2, 29).unwrap().is_end_of_month());
+        assert_eq!(
+            Date::new(2023, 2, 10).unwrap().end_of_month(),
+            Date::new(2023, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let date = Date::new(2024, 1, 5).unwrap();
+        assert_eq!(format!("{}", date), "2024-01-05");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let date = Date::new(2024, 2, 29).unwrap();
+        let serialized = serde_json::to_string(&date).unwrap();
+        let deserialized: Date = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(date, deserialized);
+
+        let invalid: Result<Date, _> = serde_json::from_str(r#"{"year":2023,"month":2,"day":29}"#);
+        assert!(invalid.is_err());
+    }
+}
@@ -0,0 +1,445 @@
+use crate::dates::civil::{add_months, civil_from_days, days_from_civil, days_in_month, days_in_year};
+use crate::dates::DateLike;
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Day Count Convention
+///
+/// Determines how the time between two dates is measured as a fraction of a year, which is used to
+/// accrue interest and discount cash flows.
+///
+/// Dates may be given as `Date` values or as Excel 1900 date serials (the number of days since 1899-12-30),
+/// or any other `DateLike` type. Conventions that only count actual days (ACT/360 and ACT/365F) only depend
+/// on the difference between two dates, so any epoch may be used with day serials.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::dates::DayCount;
+///
+/// use rust_finprim::dates::Date;
+///
+/// // 2024-01-15 to 2024-07-15
+/// let start = Date::new(2024, 1, 15).unwrap(); let end = Date::new(2024, 7, 15).unwrap();
+/// let yf: f64 = DayCount::Act360.year_fraction(start, end);
+///
+/// // The same dates as Excel date serials
+/// let yf_serial: f64 = DayCount::Act360.year_fraction(45306, 45488);
+/// assert_eq!(yf, yf_serial);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DayCount {
+    /// Actual/360
+    /// Actual days between the dates over a 360-day year, common for money market instruments.
+    Act360,
+    /// Actual/365 Fixed
+    /// Actual days between the dates over a 365-day year regardless of leap years.
+    Act365F,
+    /// Actual/Actual ISDA
+    /// Days falling in a leap year are divided by 366 and days falling in a non-leap year by 365.
+    ActActIsda,
+    /// Actual/Actual ICMA
+    /// Actual days in the period over the actual days in the coupon (reference) period times the
+    /// coupon frequency, common for bonds. Without a reference period, annual reference periods ending on
+    /// the end date are assumed.
+    ActActIcma,
+    /// 30/360 US (Bond Basis)
+    /// Each month is treated as 30 days. A start day of 31 becomes 30, an end day of 31 becomes 30
+    /// only if the start day is 30 or 31.
+    Thirty360,
+    /// 30E/360 (Eurobond Basis)
+    /// Each month is treated as 30 days. A start or end day of 31 becomes 30.
+    ThirtyE360,
+    /// 30E/360 ISDA
+    /// Each month is treated as 30 days. A start or end date on the last day of the month becomes
+    /// the 30th, including the end of February.
+    ThirtyE360Isda,
+}
+
+impl DayCount {
+    /// Day Count
+    ///
+    /// The number of days between two dates under the convention. The actual number of days for the
+    /// actual conventions and the 30/360 day count for the 30/360 conventions.
+    ///
+    /// # Arguments
+    /// * `start` - The start date
+    /// * `end` - The end date
+    ///
+    /// # Returns
+    /// * The number of days from `start` to `end`, negative if `end` is before `start`
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::DayCount;
+    ///
+    /// // 2024-01-31 to 2024-03-31
+    /// let days = DayCount::Thirty360.day_count(45322, 45382);
+    /// assert_eq!(days, 60);
+    /// ```
+    pub fn day_count<D: DateLike>(&self, start: D, end: D) -> i32 {
+        let (start, end) = (start.day_number(), end.day_number());
+        if end < start {
+            return -self.day_count(end, start);
+        }
+        match self {
+            DayCount::Act360 | DayCount::Act365F | DayCount::ActActIsda | DayCount::ActActIcma => end - start,
+            DayCount::Thirty360 | DayCount::ThirtyE360 | DayCount::ThirtyE360Isda => self.thirty_360_days(start, end),
+        }
+    }
+
+    /// Year Fraction
+    ///
+    /// The fraction of a year between two dates under the convention.
+    ///
+    /// # Arguments
+    /// * `start` - The start date
+    /// * `end` - The end date
+    ///
+    /// # Returns
+    /// * The year fraction from `start` to `end`, negative if `end` is before `start`
+    ///
+    /// For `ActActIcma` annual reference periods ending on `end` are assumed, use
+    /// `year_fraction_with_reference` to supply the coupon period.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::dates::DayCount;
+    ///
+    /// // 2023-07-01 to 2024-07-01, half of the days fall in the leap year 2024
+    /// let yf: f64 = DayCount::ActActIsda.year_fraction(45108, 45474);
+    /// ```
+    pub fn year_fraction<T: FloatLike, D: DateLike>(&self, start: D, end: D) -> T {
+        let (start, end) = (start.day_number(), end.day_number());
+        if end < start {
+            return -self.year_fraction::<T, i32>(end, start);
+        }
+        match self {
+            DayCount::Act360 => T::from_i32(end - start) / T::from_u16(360),
+            DayCount::Act365F => T::from_i32(end - start) / T::from_u16(365),
+            DayCount::ActActIsda => act_act_isda(start, end),
+            DayCount::ActActIcma => act_act_icma_annual(start, end),
+            DayCount::Thirty360 | DayCount::ThirtyE360 | DayCount::ThirtyE360Isda => {
+                T::from_i32(self.thirty_360_days(start, end)) / T::from_u16(360)
+            }
+        }
+    }
+
+    /// Year Fraction with a Reference Period
+    ///
+    /// The fraction of a year between two dates that fall within a coupon (reference) period. Only
+    /// `ActActIcma` makes use of the reference period, all other conventions are the same as `year_fraction`.
+    ///
+    /// # Arguments
+    /// * `start` - The start date
+    /// * `end` - The end date
+    /// * `ref_start` - The start of the reference (coupon) period
+    /// * `ref_end` - The end of the reference (coupon) period
+    /// * `frequency` - The number of reference periods per year, e.g. 2 for semi-annual coupons
+    ///
+    /// # Returns
+    /// * The year fraction from `start` to `end`
+    ///
+    /// # Examples
+    /// * Accrual from 2024-01-15 to 2024-03-01 within the semi-annual coupon period 2024-01-15 to 2024-07-15
+    /// ```
+    /// use rust_finprim::dates::DayCount;
+    ///
+    /// let yf: f64 = DayCount::ActActIcma.year_fraction_with_reference(45306, 45352, 45306, 45488, 2);
+    /// ```
+    ///
+    /// # Formula
+    /// For `ActActIcma`:
+    /// $$\frac{D_{end} - D_{start}}{F (R_{end} - R_{start})}$$
+    ///
+    /// Where:
+    /// * \\(D\\) = the accrual dates
+    /// * \\(R\\) = the reference period dates
+    /// * \\(F\\) = the frequency
+    pub fn year_fraction_with_reference<T: FloatLike, D: DateLike>(
+        &self,
+        start: D,
+        end: D,
+        ref_start: D,
+        ref_end: D,
+        frequency: u16,
+    ) -> T {
+        match self {
+            DayCount::ActActIcma => {
+                T::from_i32(end.day_number() - start.day_number())
+                    / (T::from_u16(frequency) * T::from_i32(ref_end.day_number() - ref_start.day_number()))
+            }
+            _ => self.year_fraction(start, end),
+        }
+    }
+
+    /// Days between two ordered dates for the 30/360 family of conventions
+    fn thirty_360_days(&self, start: i32, end: i32) -> i32 {
+        let (y1, m1, d1) = civil_from_days(start);
+        let (y2, m2, d2) = civil_from_days(end);
+        let (mut d1, mut d2) = (d1 as i32, d2 as i32);
+        match self {
+            DayCount::Thirty360 => {
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 == 30 {
+                    d2 = 30;
+                }
+            }
+            DayCount::ThirtyE360 => {
+                d1 = d1.min(30);
+                d2 = d2.min(30);
+            }
+            DayCount::ThirtyE360Isda => {
+                if d1 == days_in_month(y1, m1) as i32 {
+                    d1 = 30;
+                }
+                if d2 == days_in_month(y2, m2) as i32 {
+                    d2 = 30;
+                }
+            }
+            _ => unreachable!(),
+        }
+        360 * (y2 - y1) + 30 * (m2 as i32 - m1 as i32) + (d2 - d1)
+    }
+}
+
+/// ACT/ACT ISDA year fraction between two ordered dates
+fn act_act_isda<T: FloatLike>(start: i32, end: i32) -> T {
+    let (y1, _, _) = civil_from_days(start);
+    let (y2, _, _) = civil_from_days(end);
+    if y1 == y2 {
+        return T::from_i32(end - start) / T::from_i32(days_in_year(y1));
+    }
+    // Stub to the end of the first year, whole years in between, stub from the start of the final year
+    let first = T::from_i32(days_from_civil(y1 + 1, 1, 1) - start) / T::from_i32(days_in_year(y1));
+    let last = T::from_i32(end - days_from_civil(y2, 1, 1)) / T::from_i32(days_in_year(y2));
+    first + T::from_i32(y2 - y1 - 1) + last
+}
+
+/// ACT/ACT ICMA year fraction between two ordered dates, assuming annual reference periods
+/// rolled back from the end date
+fn act_act_icma_annual<T: FloatLike>(start: i32, end: i32) -> T {
+    let (y, m, d) = civil_from_days(end);
+    let mut years = T::zero();
+    let mut period_end = end;
+    let mut k = 1;
+    loop {
+        let (py, pm, pd) = add_months(y, m, d, -12 * k);
+        let period_start = days_from_civil(py, pm, pd);
+        if period_start <= start {
+            // Final (possibly partial) reference period
+            return years + T::from_i32(period_end - start) / T::from_i32(period_end - period_start);
+        }
+        years += T::one();
+        period_end = period_start;
+        k += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    fn date(y: i32, m: u8, d: u8) -> i32 {
+        days_from_civil(y, m, d)
+    }
+
+    #[test]
+    fn test_day_count() {
+        struct TestCase {
+            day_count: DayCount,
+            start: i32,
+            end: i32,
+            expected: i32,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(day_count: DayCount, start: i32, end: i32, expected: i32, description: &'static str) -> TestCase {
+                TestCase {
+                    day_count,
+                    start,
+                    end,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                DayCount::Act360,
+                date(2024, 1, 31),
+                date(2024, 3, 31),
+                60,
+                "Actual days across a leap February",
+            ),
+            TestCase::new(
+                DayCount::Thirty360,
+                date(2024, 1, 31),
+                date(2024, 3, 31),
+                60,
+                "31st start and end days",
+            ),
+            TestCase::new(
+                DayCount::Thirty360,
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                32,
+                "US Bond Basis keeps the 31st when the start is not the 30th or 31st",
+            ),
+            TestCase::new(
+                DayCount::ThirtyE360,
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                31,
+                "Eurobond Basis always moves the 31st to the 30th, leaving the end of February alone",
+            ),
+            TestCase::new(
+                DayCount::ThirtyE360Isda,
+                date(2024, 2, 29),
+                date(2024, 3, 31),
+                30,
+                "30E/360 ISDA moves the end of February to the 30th",
+            ),
+            TestCase::new(
+                DayCount::Thirty360,
+                date(2024, 3, 31),
+                date(2024, 1, 31),
+                -60,
+                "Reversed dates are negative",
+            ),
+        ];
+
+        for case in &cases {
+            let result = case.day_count.day_count(case.start, case.end);
+            assert_eq!(
+                result, case.expected,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.description, case.expected, result
+            );
+        }
+    }
+
+    #[test]
+    fn test_year_fraction() {
+        struct TestCase {
+            day_count: DayCount,
+            start: i32,
+            end: i32,
+            expected: f64,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(day_count: DayCount, start: i32, end: i32, expected: f64, description: &'static str) -> TestCase {
+                TestCase {
+                    day_count,
+                    start,
+                    end,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                DayCount::Act360,
+                date(2024, 1, 15),
+                date(2024, 7, 15),
+                182.0 / 360.0,
+                "ACT/360 over six months",
+            ),
+            TestCase::new(
+                DayCount::Act365F,
+                date(2024, 1, 15),
+                date(2024, 7, 15),
+                182.0 / 365.0,
+                "ACT/365F over six months",
+            ),
+            TestCase::new(
+                DayCount::ActActIsda,
+                date(2023, 7, 1),
+                date(2024, 7, 1),
+                184.0 / 365.0 + 182.0 / 366.0,
+                "ACT/ACT ISDA spanning a leap year",
+            ),
+            TestCase::new(
+                DayCount::ActActIsda,
+                date(2022, 12, 31),
+                date(2025, 1, 1),
+                1.0 / 365.0 + 2.0 + 0.0,
+                "ACT/ACT ISDA spanning whole years",
+            ),
+            TestCase::new(
+                DayCount::ActActIcma,
+                date(2023, 7, 1),
+                date(2024, 7, 1),
+                1.0,
+                "ACT/ACT ICMA over a full annual period",
+            ),
+            TestCase::new(
+                DayCount::ActActIcma,
+                date(2023, 1, 1),
+                date(2024, 7, 1),
+                1.0 + 181.0 / 365.0,
+                "ACT/ACT ICMA with a short front stub",
+            ),
+            TestCase::new(
+                DayCount::Thirty360,
+                date(2024, 1, 31),
+                date(2024, 7, 31),
+                0.5,
+                "30/360 over six months",
+            ),
+        ];
+
+        for case in &cases {
+            let result: f64 = case.day_count.year_fraction(case.start, case.end);
+            assert!(
+                (result - case.expected).abs() < 1e-10,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.description,
+                case.expected,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn test_year_fraction_with_reference() {
+        // Half of a semi-annual coupon period
+        let result: f64 = DayCount::ActActIcma.year_fraction_with_reference(
+            date(2024, 1, 1),
+            date(2024, 4, 1),
+            date(2024, 1, 1),
+            date(2024, 7, 1),
+            2,
+        );
+        let expected = 91.0 / (2.0 * 182.0);
+        assert!(
+            (result - expected).abs() < 1e-10,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "ACT/ACT ICMA within a semi-annual coupon period",
+            expected,
+            result
+        );
+
+        // Other conventions ignore the reference period
+        let result: f64 = DayCount::Act360.year_fraction_with_reference(
+            date(2024, 1, 1),
+            date(2024, 4, 1),
+            date(2024, 1, 1),
+            date(2024, 7, 1),
+            2,
+        );
+        assert_eq!(result, 91.0 / 360.0);
+    }
+}
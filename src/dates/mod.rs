@@ -0,0 +1,28 @@
+//! This module contains date related primitives used by the functions working with dated cash flows.
+//!
+//! For example, day count conventions determine how the time between two dates is measured as a fraction of
+//! a year when accruing interest or discounting irregular cash flows, and holiday calendars determine which
+//! dates payments can actually be made on.
+
+// Calendar arithmetic
+mod civil;
+
+// Calendar Dates
+mod date;
+pub use date::{Date, DateLike, ExcelDateSystem, Weekday};
+
+// Business Day Calendars
+mod calendar;
+pub use calendar::{
+    networkdays, workday, BusinessDayConvention, HolidayCalendar, HolidayList, JointCalendar, WeekendsOnly,
+};
+
+// Payment Schedules
+mod schedule;
+#[cfg(feature = "std")]
+pub use schedule::schedule;
+pub use schedule::{schedule_into, Frequency, StubConvention};
+
+// Day Count Conventions
+mod day_count;
+pub use day_count::DayCount;
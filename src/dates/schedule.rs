@@ -0,0 +1,443 @@
+use crate::dates::Date;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Payment Frequency
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Frequency {
+    Annual,
+    SemiAnnual,
+    Quarterly,
+    Monthly,
+    Weekly,
+}
+
+impl Frequency {
+    /// The number of payment periods per year, 52 for weekly payments
+    pub fn periods_per_year(&self) -> u16 {
+        match self {
+            Frequency::Annual => 1,
+            Frequency::SemiAnnual => 2,
+            Frequency::Quarterly => 4,
+            Frequency::Monthly => 12,
+            Frequency::Weekly => 52,
+        }
+    }
+
+    /// The date `periods` periods away from `anchor`, negative to move backwards
+    pub(crate) fn advance(&self, anchor: Date, periods: i32, end_of_month: bool) -> Date {
+        let months = match self {
+            Frequency::Annual => 12,
+            Frequency::SemiAnnual => 6,
+            Frequency::Quarterly => 3,
+            Frequency::Monthly => 1,
+            Frequency::Weekly => return anchor.add_days(7 * periods),
+        };
+        let date = anchor.add_months(months * periods);
+        if end_of_month {
+            date.end_of_month()
+        } else {
+            date
+        }
+    }
+}
+
+/// Stub Convention
+///
+/// Determines where an irregular (stub) period is placed when the time between the effective date and the
+/// maturity is not a whole number of periods, and with it the direction the schedule is generated in.
+///
+/// Front stubs generate the schedule backward from the maturity, so the irregular period is the first
+/// one. Back stubs generate the schedule forward from the effective date, so the irregular period is the last
+/// one. Short stubs are shorter than a regular period, long stubs combine the short stub with the adjacent
+/// regular period.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum StubConvention {
+    /// Backward generation with a short first period
+    #[default]
+    ShortFront,
+    /// Backward generation with a long first period
+    LongFront,
+    /// Forward generation with a short last period
+    ShortBack,
+    /// Forward generation with a long last period
+    LongBack,
+}
+
+#[cfg(feature = "std")]
+/// Payment Schedule
+///
+/// Generates the unadjusted payment dates of a coupon or loan schedule from an effective date to a maturity.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it uses `std::Vec`. `schedule_into`
+/// can be used in `no_std` environments as any allocation is done by the caller.
+///
+/// # Arguments
+/// * `effective` - The effective (start) date of the schedule, the start of the first period
+/// * `maturity` - The maturity (end) date of the schedule, the last payment date
+/// * `frequency` - The payment frequency
+/// * `stub` (optional) - The placement of an irregular period and the generation direction, default is
+///   `StubConvention::ShortFront`
+/// * `end_of_month` (optional) - The end-of-month rule, default is false. If true and the date the schedule is
+///   generated from (the maturity for front stubs, the effective date for back stubs) is the last day of its
+///   month, all regular dates fall on the last day of their month. Ignored for weekly schedules.
+///
+/// # Returns
+/// * The schedule dates, starting with the effective date and ending with the maturity. Every date after the
+///   effective date is a payment date. Empty if the maturity is not after the effective date.
+///
+/// The dates are not adjusted for business days, use `BusinessDayConvention::adjust` to roll them onto
+/// business days.
+///
+/// # Examples
+/// * Semi-annual coupons from 2024-03-15 to 2026-01-31 with a short first period and the end-of-month rule
+/// ```
+/// use rust_finprim::dates::{schedule, Date, Frequency};
+///
+/// let effective = Date::new(2024, 3, 15).unwrap();
+/// let maturity = Date::new(2026, 1, 31).unwrap();
+/// let dates = schedule(effective, maturity, Frequency::SemiAnnual, None, Some(true));
+/// // 2024-03-15, 2024-07-31, 2025-01-31, 2025-07-31, 2026-01-31
+/// assert_eq!(dates.len(), 5);
+/// assert_eq!(dates[1], Date::new(2024, 7, 31).unwrap());
+/// ```
+///
+/// * Dated cash flows of a coupon bond for `xirr`
+/// ```
+/// use rust_finprim::dates::{schedule, Date, Frequency};
+/// use rust_finprim::rate::xirr;
+///
+/// let dates = schedule(
+///     Date::new(2024, 1, 15).unwrap(),
+///     Date::new(2027, 1, 15).unwrap(),
+///     Frequency::Annual,
+///     None,
+///     None,
+/// );
+/// let mut flow_table: Vec<(f64, Date)> = dates.iter().map(|&date| (5.0, date)).collect();
+/// flow_table[0].0 = -100.0;
+/// flow_table.last_mut().unwrap().0 += 100.0;
+/// xirr(&flow_table, None, None, None);
+/// ```
+pub fn schedule(
+    effective: Date,
+    maturity: Date,
+    frequency: Frequency,
+    stub: Option<StubConvention>,
+    end_of_month: Option<bool>,
+) -> Vec<Date> {
+    let len = schedule_into(&mut [], effective, maturity, frequency, stub, end_of_month);
+    let mut dates = vec![effective; len];
+    schedule_into(dates.as_mut_slice(), effective, maturity, frequency, stub, end_of_month);
+    dates
+}
+
+/// Payment Schedule Into
+///
+/// Generates the unadjusted payment dates of a coupon or loan schedule from an effective date to a maturity,
+/// mutating a slice of `Date`.
+///
+/// # Arguments
+/// * `slice` - A mutable slice of `Date` to be filled with the schedule dates. If the slice is shorter than the
+///   schedule only the first dates are written, call with an empty slice to find the length of the schedule.
+/// * `effective` - The effective (start) date of the schedule, the start of the first period
+/// * `maturity` - The maturity (end) date of the schedule, the last payment date
+/// * `frequency` - The payment frequency
+/// * `stub` (optional) - The placement of an irregular period and the generation direction, default is
+///   `StubConvention::ShortFront`
+/// * `end_of_month` (optional) - The end-of-month rule, default is false. If true and the date the schedule is
+///   generated from (the maturity for front stubs, the effective date for back stubs) is the last day of its
+///   month, all regular dates fall on the last day of their month. Ignored for weekly schedules.
+///
+/// # Returns
+/// * The number of dates in the full schedule, starting with the effective date and ending with the maturity.
+///   Zero if the maturity is not after the effective date.
+///
+/// # Examples
+/// * Quarterly payments with a long last period
+/// ```
+/// use rust_finprim::dates::{schedule_into, Date, Frequency, StubConvention};
+///
+/// let effective = Date::new(2024, 1, 10).unwrap();
+/// let maturity = Date::new(2025, 2, 20).unwrap();
+/// let mut dates = [effective; 8];
+/// let len = schedule_into(
+///     &mut dates,
+///     effective,
+///     maturity,
+///     Frequency::Quarterly,
+///     Some(StubConvention::LongBack),
+///     None,
+/// );
+/// // 2024-01-10, 2024-04-10, 2024-07-10, 2024-10-10, 2025-02-20
+/// assert_eq!(len, 5);
+/// assert_eq!(dates[3], Date::new(2024, 10, 10).unwrap());
+/// assert_eq!(dates[4], maturity);
+/// ```
+pub fn schedule_into(
+    slice: &mut [Date],
+    effective: Date,
+    maturity: Date,
+    frequency: Frequency,
+    stub: Option<StubConvention>,
+    end_of_month: Option<bool>,
+) -> usize {
+    if maturity <= effective {
+        return 0;
+    }
+    let stub = stub.unwrap_or_default();
+    let backward = matches!(stub, StubConvention::ShortFront | StubConvention::LongFront);
+    let (anchor, direction) = if backward { (maturity, -1) } else { (effective, 1) };
+    let end_of_month = end_of_month.unwrap_or(false) && frequency != Frequency::Weekly && anchor.is_end_of_month();
+    let regular_date = |k: i32| frequency.advance(anchor, direction * k, end_of_month);
+    let inside = |date: Date| effective < date && date < maturity;
+
+    // Number of regular dates strictly between the effective date and the maturity
+    let mut regular = 0;
+    while inside(regular_date(regular + 1)) {
+        regular += 1;
+    }
+    // The stub is merged into the adjacent regular period by dropping the regular date closest to it
+    let has_stub = regular_date(regular + 1) != if backward { effective } else { maturity };
+    if has_stub && regular > 0 && matches!(stub, StubConvention::LongFront | StubConvention::LongBack) {
+        regular -= 1;
+    }
+
+    let len = regular as usize + 2;
+    for (i, item) in slice.iter_mut().take(len).enumerate() {
+        *item = if i == 0 {
+            effective
+        } else if i == len - 1 {
+            maturity
+        } else if backward {
+            regular_date(regular + 1 - i as i32)
+        } else {
+            regular_date(i as i32)
+        };
+    }
+    len
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert_eq, vec, vec::Vec};
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_schedule_into() {
+        struct TestCase {
+            effective: Date,
+            maturity: Date,
+            frequency: Frequency,
+            stub: Option<StubConvention>,
+            end_of_month: Option<bool>,
+            expected: Vec<Date>,
+            description: &'static str,
+        }
+        impl TestCase {
+            fn new(
+                effective: Date,
+                maturity: Date,
+                frequency: Frequency,
+                stub: Option<StubConvention>,
+                end_of_month: Option<bool>,
+                expected: Vec<Date>,
+                description: &'static str,
+            ) -> Self {
+                TestCase {
+                    effective,
+                    maturity,
+                    frequency,
+                    stub,
+                    end_of_month,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                date(2024, 1, 15),
+                date(2026, 1, 15),
+                Frequency::SemiAnnual,
+                None,
+                None,
+                vec![
+                    date(2024, 1, 15),
+                    date(2024, 7, 15),
+                    date(2025, 1, 15),
+                    date(2025, 7, 15),
+                    date(2026, 1, 15),
+                ],
+                "Regular semi-annual schedule",
+            ),
+            TestCase::new(
+                date(2024, 3, 1),
+                date(2025, 1, 15),
+                Frequency::SemiAnnual,
+                Some(StubConvention::ShortFront),
+                None,
+                vec![date(2024, 3, 1), date(2024, 7, 15), date(2025, 1, 15)],
+                "Short front stub",
+            ),
+            TestCase::new(
+                date(2024, 3, 1),
+                date(2025, 1, 15),
+                Frequency::SemiAnnual,
+                Some(StubConvention::LongFront),
+                None,
+                vec![date(2024, 3, 1), date(2025, 1, 15)],
+                "Long front stub merges into the only regular period",
+            ),
+            TestCase::new(
+                date(2024, 1, 15),
+                date(2024, 12, 1),
+                Frequency::Quarterly,
+                Some(StubConvention::ShortBack),
+                None,
+                vec![
+                    date(2024, 1, 15),
+                    date(2024, 4, 15),
+                    date(2024, 7, 15),
+                    date(2024, 10, 15),
+                    date(2024, 12, 1),
+                ],
+                "Short back stub",
+            ),
+            TestCase::new(
+                date(2024, 1, 15),
+                date(2024, 12, 1),
+                Frequency::Quarterly,
+                Some(StubConvention::LongBack),
+                None,
+                vec![
+                    date(2024, 1, 15),
+                    date(2024, 4, 15),
+                    date(2024, 7, 15),
+                    date(2024, 12, 1),
+                ],
+                "Long back stub",
+            ),
+            TestCase::new(
+                date(2023, 12, 31),
+                date(2024, 4, 15),
+                Frequency::Monthly,
+                Some(StubConvention::ShortBack),
+                Some(true),
+                vec![
+                    date(2023, 12, 31),
+                    date(2024, 1, 31),
+                    date(2024, 2, 29),
+                    date(2024, 3, 31),
+                    date(2024, 4, 15),
+                ],
+                "End-of-month rule forward",
+            ),
+            TestCase::new(
+                date(2023, 12, 31),
+                date(2024, 4, 15),
+                Frequency::Monthly,
+                Some(StubConvention::ShortBack),
+                Some(false),
+                vec![
+                    date(2023, 12, 31),
+                    date(2024, 1, 31),
+                    date(2024, 2, 29),
+                    date(2024, 3, 31),
+                    date(2024, 4, 15),
+                ],
+                "Without the end-of-month rule dates are generated from the anchor, no day drift",
+            ),
+            TestCase::new(
+                date(2024, 1, 10),
+                date(2024, 6, 30),
+                Frequency::Quarterly,
+                None,
+                Some(true),
+                vec![date(2024, 1, 10), date(2024, 3, 31), date(2024, 6, 30)],
+                "End-of-month rule backward from a June 30 maturity",
+            ),
+            TestCase::new(
+                date(2024, 1, 10),
+                date(2024, 6, 30),
+                Frequency::Quarterly,
+                None,
+                None,
+                vec![date(2024, 1, 10), date(2024, 3, 30), date(2024, 6, 30)],
+                "No end-of-month rule backward from a June 30 maturity",
+            ),
+            TestCase::new(
+                date(2024, 1, 1),
+                date(2024, 1, 20),
+                Frequency::Weekly,
+                Some(StubConvention::ShortBack),
+                None,
+                vec![date(2024, 1, 1), date(2024, 1, 8), date(2024, 1, 15), date(2024, 1, 20)],
+                "Weekly",
+            ),
+            TestCase::new(
+                date(2024, 1, 1),
+                date(2024, 1, 1),
+                Frequency::Annual,
+                None,
+                None,
+                vec![],
+                "Empty schedule",
+            ),
+        ];
+
+        for case in &cases {
+            let mut dates = [case.effective; 8];
+            let len = schedule_into(
+                &mut dates,
+                case.effective,
+                case.maturity,
+                case.frequency,
+                case.stub,
+                case.end_of_month,
+            );
+            assert_eq!(
+                &dates[..len],
+                case.expected.as_slice(),
+                "Failed on case: {}. Expected: {:?}, Result: {:?}",
+                case.description,
+                case.expected,
+                &dates[..len]
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_schedule() {
+        let dates = schedule(date(2024, 1, 15), date(2054, 1, 15), Frequency::Monthly, None, None);
+        assert_eq!(dates.len(), 361);
+        assert_eq!(dates[1], date(2024, 2, 15));
+        assert_eq!(dates[360], date(2054, 1, 15));
+
+        // A short slice only receives the first dates
+        let mut dates = [date(2000, 1, 1); 2];
+        let len = schedule_into(
+            &mut dates,
+            date(2024, 1, 15),
+            date(2026, 1, 15),
+            Frequency::Annual,
+            None,
+            None,
+        );
+        assert_eq!(len, 3);
+        assert_eq!(dates, [date(2024, 1, 15), date(2025, 1, 15)]);
+    }
+}
@@ -0,0 +1,342 @@
+use crate::dates::{DateLike, DayCount};
+use crate::derivatives::{npv_prime2_r, npv_prime_r, pv_prime2_r, pv_prime_r};
+use crate::tvm::{npv, xnpv};
+use crate::FloatLike;
+
+/// Macaulay Duration
+///
+/// The present value weighted average time to the cash flows, in periods.
+///
+/// # Arguments
+/// * `rate` - The discount rate (yield) per period
+/// * `cash_flows` - A slice of cash flows, where each cash flow is at a specific period starting at period 0
+///
+/// # Returns
+/// * The Macaulay duration in periods
+///
+/// # Example
+/// * 3 year 5% annual coupon bond at a 5% yield
+/// ```
+/// use rust_finprim::derivatives::macaulay_duration;
+///
+/// let cash_flows = [0.0, 5.0, 5.0, 105.0];
+/// macaulay_duration(0.05, &cash_flows); // 2.85941
+/// ```
+///
+/// # Formula
+/// $$D_{mac} = \frac{\sum_{t=0}^{n} t \frac{CF_t}{(1+r)^t}}{NPV} = -\frac{NPV'(r)(1+r)}{NPV}$$
+pub fn macaulay_duration<T: FloatLike>(rate: T, cash_flows: &[T]) -> T {
+    -npv_prime_r(rate, cash_flows) * (T::one() + rate) / npv(rate, cash_flows)
+}
+
+/// Modified Duration
+///
+/// The percentage change in the present value for a change in the discount rate, the Macaulay duration
+/// divided by one plus the rate.
+///
+/// # Arguments
+/// * `rate` - The discount rate (yield) per period
+/// * `cash_flows` - A slice of cash flows, where each cash flow is at a specific period starting at period 0
+///
+/// # Returns
+/// * The modified duration in periods
+///
+/// # Example
+/// ```
+/// use rust_finprim::derivatives::modified_duration;
+///
+/// let cash_flows = [0.0, 5.0, 5.0, 105.0];
+/// modified_duration(0.05, &cash_flows); // 2.72325
+/// ```
+///
+/// # Formula
+/// $$D_{mod} = -\frac{NPV'(r)}{NPV} = \frac{D_{mac}}{1+r}$$
+pub fn modified_duration<T: FloatLike>(rate: T, cash_flows: &[T]) -> T {
+    -npv_prime_r(rate, cash_flows) / npv(rate, cash_flows)
+}
+
+/// Convexity
+///
+/// The curvature of the present value with respect to the discount rate, relative to the present value.
+///
+/// # Arguments
+/// * `rate` - The discount rate (yield) per period
+/// * `cash_flows` - A slice of cash flows, where each cash flow is at a specific period starting at period 0
+///
+/// # Returns
+/// * The convexity in periods squared
+///
+/// # Example
+/// ```
+/// use rust_finprim::derivatives::convexity;
+///
+/// let cash_flows = [0.0, 5.0, 5.0, 105.0];
+/// convexity(0.05, &cash_flows); // 10.20562
+/// ```
+///
+/// # Formula
+/// $$C = \frac{NPV''(r)}{NPV}$$
+pub fn convexity<T: FloatLike>(rate: T, cash_flows: &[T]) -> T {
+    npv_prime2_r(rate, cash_flows) / npv(rate, cash_flows)
+}
+
+/// DV01 - Dollar Value of a Basis Point
+///
+/// The decrease in the present value for a one basis point (0.01%) increase in the discount rate, also known
+/// as PV01.
+///
+/// # Arguments
+/// * `rate` - The discount rate (yield) per period
+/// * `cash_flows` - A slice of cash flows, where each cash flow is at a specific period starting at period 0
+///
+/// # Returns
+/// * The DV01, positive for a position that loses value as rates rise
+///
+/// # Example
+/// ```
+/// use rust_finprim::derivatives::dv01;
+///
+/// let cash_flows = [0.0, 5.0, 5.0, 105.0];
+/// dv01(0.05, &cash_flows); // 0.02723
+/// ```
+///
+/// # Formula
+/// $$DV01 = -NPV'(r) \times 0.0001$$
+pub fn dv01<T: FloatLike>(rate: T, cash_flows: &[T]) -> T {
+    -npv_prime_r(rate, cash_flows) * T::from_f32(1e-4)
+}
+
+/// Effective Duration
+///
+/// The duration measured by shifting the rate up and down and repricing, suitable for instruments whose
+/// cash flows change with the rate, such as callable bonds or mortgages with prepayments.
+///
+/// # Arguments
+/// * `price` - A function pricing the instrument at a given rate
+/// * `rate` - The current rate
+/// * `shift` - The size of the rate shift in each direction, e.g. 0.0001 for one basis point
+///
+/// # Returns
+/// * The effective duration
+///
+/// # Example
+/// * Effective duration of cash flows matches the modified duration for small shifts
+/// ```
+/// use rust_finprim::derivatives::effective_duration;
+/// use rust_finprim::tvm::npv;
+///
+/// let cash_flows = [0.0, 5.0, 5.0, 105.0];
+/// effective_duration(|r| npv(r, &cash_flows), 0.05, 0.0001); // 2.72325
+/// ```
+///
+/// # Formula
+/// $$D_{eff} = \frac{P(r - \Delta r) - P(r + \Delta r)}{2 P(r) \Delta r}$$
+pub fn effective_duration<T: FloatLike, F: Fn(T) -> T>(price: F, rate: T, shift: T) -> T {
+    (price(rate - shift) - price(rate + shift)) / (T::two() * price(rate) * shift)
+}
+
+/// Effective Convexity
+///
+/// The convexity measured by shifting the rate up and down and repricing, suitable for instruments whose
+/// cash flows change with the rate.
+///
+/// # Arguments
+/// * `price` - A function pricing the instrument at a given rate
+/// * `rate` - The current rate
+/// * `shift` - The size of the rate shift in each direction, e.g. 0.0001 for one basis point
+///
+/// # Returns
+/// * The effective convexity
+///
+/// # Formula
+/// $$C_{eff} = \frac{P(r - \Delta r) + P(r + \Delta r) - 2P(r)}{P(r) \Delta r^2}$$
+pub fn effective_convexity<T: FloatLike, F: Fn(T) -> T>(price: F, rate: T, shift: T) -> T {
+    let p0 = price(rate);
+    (price(rate - shift) + price(rate + shift) - T::two() * p0) / (p0 * shift * shift)
+}
+
+/// XMacaulay Duration - Macaulay Duration for irregular cash flows
+///
+/// The present value weighted average time to the cash flows, in years.
+///
+/// # Arguments
+/// * `rate` - The annual discount rate (yield)
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash
+///   flow is assumed to be the valuation date, the order of subsequent cash flows does not matter.
+///
+/// Times are measured assuming a 365-day year (ACT/365F), consistent with `xnpv`.
+///
+/// # Returns
+/// * The Macaulay duration in years
+///
+/// # Example
+/// ```
+/// use rust_finprim::derivatives::xmacaulay_duration;
+///
+/// let flow_table = [(0.0, 0), (5.0, 365), (5.0, 730), (105.0, 1095)];
+/// xmacaulay_duration(0.05, &flow_table); // 2.85941
+/// ```
+pub fn xmacaulay_duration<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)]) -> T {
+    -xnpv_prime_r(rate, flow_table) * (T::one() + rate) / xnpv(rate, flow_table)
+}
+
+/// XModified Duration - Modified Duration for irregular cash flows
+///
+/// The percentage change in the present value for a change in the annual discount rate.
+///
+/// # Arguments
+/// * `rate` - The annual discount rate (yield)
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash
+///   flow is assumed to be the valuation date, the order of subsequent cash flows does not matter.
+///
+/// Times are measured assuming a 365-day year (ACT/365F), consistent with `xnpv`.
+///
+/// # Returns
+/// * The modified duration in years
+pub fn xmodified_duration<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)]) -> T {
+    -xnpv_prime_r(rate, flow_table) / xnpv(rate, flow_table)
+}
+
+/// XConvexity - Convexity for irregular cash flows
+///
+/// The curvature of the present value with respect to the annual discount rate, relative to the present value.
+///
+/// # Arguments
+/// * `rate` - The annual discount rate (yield)
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash
+///   flow is assumed to be the valuation date, the order of subsequent cash flows does not matter.
+///
+/// Times are measured assuming a 365-day year (ACT/365F), consistent with `xnpv`.
+///
+/// # Returns
+/// * The convexity in years squared
+pub fn xconvexity<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)]) -> T {
+    let init_date = flow_table.first().unwrap().1;
+    let npv_prime2: T = flow_table
+        .iter()
+        .map(|&(cf, date)| pv_prime2_r(rate, DayCount::Act365F.year_fraction(init_date, date), cf))
+        .sum();
+    npv_prime2 / xnpv(rate, flow_table)
+}
+
+/// XDV01 - Dollar Value of a Basis Point for irregular cash flows
+///
+/// The decrease in the present value for a one basis point (0.01%) increase in the annual discount rate, also
+/// known as PV01.
+///
+/// # Arguments
+/// * `rate` - The annual discount rate (yield)
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash
+///   flow is assumed to be the valuation date, the order of subsequent cash flows does not matter.
+///
+/// Times are measured assuming a 365-day year (ACT/365F), consistent with `xnpv`.
+///
+/// # Returns
+/// * The DV01, positive for a position that loses value as rates rise
+pub fn xdv01<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)]) -> T {
+    -xnpv_prime_r(rate, flow_table) * T::from_f32(1e-4)
+}
+
+/// Derivative of XNPV with respect to the rate
+fn xnpv_prime_r<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)]) -> T {
+    let init_date = flow_table.first().unwrap().1;
+    flow_table
+        .iter()
+        .map(|&(cf, date)| pv_prime_r(rate, DayCount::Act365F.year_fraction(init_date, date), cf))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+
+    #[test]
+    fn test_duration() {
+        struct TestCase {
+            name: &'static str,
+            function: fn(f64, &[f64]) -> f64,
+            expected: f64,
+        }
+        impl TestCase {
+            fn new(name: &'static str, function: fn(f64, &[f64]) -> f64, expected: f64) -> Self {
+                TestCase {
+                    name,
+                    function,
+                    expected,
+                }
+            }
+        }
+
+        // 3 year 5% annual coupon bond at a 5% yield
+        let rate = 0.05;
+        let cash_flows = [0.0, 5.0, 5.0, 105.0];
+        let cases = [
+            TestCase::new("Macaulay duration", macaulay_duration, 2.85941),
+            TestCase::new("Modified duration", modified_duration, 2.72325),
+            TestCase::new("Convexity", convexity, 10.20562),
+            TestCase::new("DV01", dv01, 0.0272325),
+        ];
+
+        for case in &cases {
+            let result = (case.function)(rate, &cash_flows);
+            assert!(
+                (result - case.expected).abs() < 1e-5,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.name,
+                case.expected,
+                result
+            );
+        }
+
+        // Effective measures converge to the analytic measures for small shifts
+        let price = |r: f64| npv(r, &cash_flows);
+        let effective = effective_duration(price, rate, 1e-5);
+        assert!((effective - modified_duration(rate, &cash_flows)).abs() < 1e-6);
+        let effective = effective_convexity(price, rate, 1e-4);
+        assert!((effective - convexity(rate, &cash_flows)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_xduration() {
+        // Annual cash flows in 365 day years match the periodic measures
+        let rate = 0.05;
+        let cash_flows = [0.0, 5.0, 5.0, 105.0];
+        let flow_table = [(0.0, 0), (5.0, 365), (5.0, 730), (105.0, 1095)];
+
+        let pairs = [
+            (
+                xmacaulay_duration(rate, &flow_table),
+                macaulay_duration(rate, &cash_flows),
+            ),
+            (
+                xmodified_duration(rate, &flow_table),
+                modified_duration(rate, &cash_flows),
+            ),
+            (xconvexity(rate, &flow_table), convexity(rate, &cash_flows)),
+            (xdv01(rate, &flow_table), dv01(rate, &cash_flows)),
+        ];
+        for (result, expected) in pairs {
+            assert!(
+                (result - expected).abs() < 1e-10,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                "Annual dated cash flows",
+                expected,
+                result
+            );
+        }
+
+        // A single cash flow 73 days (0.2 years) out
+        let flow_table = [(0.0, 0), (100.0, 73)];
+        let result = xmacaulay_duration(rate, &flow_table);
+        assert!((result - 0.2).abs() < 1e-10);
+    }
+}
@@ -6,3 +6,9 @@ pub use wacc::{wacc_prime2_de, wacc_prime_de};
 
 mod pv;
 pub use pv::{npv_prime2_r, npv_prime_r, pv_prime2_r, pv_prime_r};
+
+mod duration;
+pub use duration::{
+    convexity, dv01, effective_convexity, effective_duration, macaulay_duration, modified_duration, xconvexity, xdv01,
+    xmacaulay_duration, xmodified_duration,
+};
@@ -1,3 +1,4 @@
+use crate::utils::SolverReport;
 use core::fmt::{self, Debug, Display};
 
 /// Root finding general errors
@@ -38,6 +39,44 @@ pub enum FinPrimError<T> {
     DivideByZero,
     /// The method failed to find a root
     RootFindingError(RootFindingError<T>),
+    /// No finite solution exists for the given inputs
+    NoSolution,
+    /// Bootstrapping failed to solve for the pillar of an instrument
+    BootstrapError {
+        /// The index of the failing instrument in the input
+        instrument: usize,
+        /// The root finding error for the instrument
+        error: RootFindingError<T>,
+    },
+    /// A solver run with a `utils::SolverConfig` did not converge, the report tells why
+    SolverFailed(SolverReport<T>),
+    /// The price is outside the no-arbitrage bounds, so no volatility or rate reproduces it
+    ArbitrageViolation {
+        /// The price given
+        price: T,
+        /// The lower bound on the price
+        lower: T,
+        /// The upper bound on the price
+        upper: T,
+    },
+    /// The period, or the range of periods from `start` to `end`, is not within the term of the annuity
+    InvalidPeriod {
+        /// The first period given
+        start: u32,
+        /// The last period given
+        end: u32,
+    },
+    /// An instrument given to bootstrap a curve is invalid, e.g. its maturity is not greater than zero or is the same
+    /// as another instrument's maturity
+    InvalidInstrument {
+        /// The index of the invalid instrument in the input
+        instrument: usize,
+    },
+    /// An argument is outside the values the function accepts, e.g. a rate at or below -100%
+    InvalidArgument {
+        /// The name of the argument
+        name: &'static str,
+    },
 }
 
 impl<T: Display> Display for FinPrimError<T> {
@@ -45,6 +84,29 @@ impl<T: Display> Display for FinPrimError<T> {
         match self {
             FinPrimError::DivideByZero => write!(f, "Division by zero error."),
             FinPrimError::RootFindingError(e) => write!(f, "Root finding error: {}", e),
+            FinPrimError::NoSolution => write!(f, "No finite solution exists for the given inputs."),
+            FinPrimError::BootstrapError { instrument, error } => {
+                write!(f, "Bootstrapping failed on instrument {}: {}", instrument, error)
+            }
+            FinPrimError::SolverFailed(report) => write!(
+                f,
+                "Solver failed ({:?}) after {} iterations of {:?}. Last x: {}, Last f(x): {}",
+                report.reason, report.iterations, report.method, report.root, report.residual
+            ),
+            FinPrimError::ArbitrageViolation { price, lower, upper } => write!(
+                f,
+                "Price {} is outside the no-arbitrage bounds. Lower: {}, Upper: {}",
+                price, lower, upper
+            ),
+            FinPrimError::InvalidPeriod { start, end } => {
+                write!(
+                    f,
+                    "Periods {} to {} are not within the term of the annuity.",
+                    start, end
+                )
+            }
+            FinPrimError::InvalidInstrument { instrument } => write!(f, "Instrument {} is invalid.", instrument),
+            FinPrimError::InvalidArgument { name } => write!(f, "Invalid argument: {}.", name),
         }
     }
 }
@@ -32,6 +32,9 @@ pub trait FloatLike:
     fn zero() -> Self;
     fn one() -> Self;
     fn powf(self, n: Self) -> Self;
+    fn ln(self) -> Self;
+    fn exp(self) -> Self;
+    fn sqrt(self) -> Self;
     fn two() -> Self {
         Self::one() + Self::one()
     }
@@ -45,6 +48,7 @@ pub trait FloatLike:
     fn from_usize(n: usize) -> Self;
     fn from_i32(n: i32) -> Self;
     fn from_f32(n: f32) -> Self;
+    fn from_f64(n: f64) -> Self;
 
     fn round_with_mode(&self, dp: u32, mode: RoundingMode, epsilon: Self) -> Self;
 }
@@ -69,6 +73,24 @@ fn gen_floats(ftype: Vec<String>) {
                     #[cfg(not(feature = "std"))]
                     { libm::pow{{append}}(self, n) }
                 }
+                fn ln(self) -> Self {
+                    #[cfg(feature = "std")]
+                    { {{t}}::ln(self) }
+                    #[cfg(not(feature = "std"))]
+                    { libm::log{{append}}(self) }
+                }
+                fn exp(self) -> Self {
+                    #[cfg(feature = "std")]
+                    { {{t}}::exp(self) }
+                    #[cfg(not(feature = "std"))]
+                    { libm::exp{{append}}(self) }
+                }
+                fn sqrt(self) -> Self {
+                    #[cfg(feature = "std")]
+                    { {{t}}::sqrt(self) }
+                    #[cfg(not(feature = "std"))]
+                    { libm::sqrt{{append}}(self) }
+                }
                 fn abs(&self) -> Self {
                     #[cfg(feature = "std")]
                     { {{t}}::abs(*self) }
@@ -93,6 +115,9 @@ fn gen_floats(ftype: Vec<String>) {
                 fn from_f32(n: f32) -> Self {
                     n as {{t}}
                 }
+                fn from_f64(n: f64) -> Self {
+                    n as {{t}}
+                }
                 fn round_with_mode(&self, dp: u32, mode: RoundingMode, epsilon: Self) -> Self {
                     let factor = FloatLike::powf(10.0{{t}}, dp as {{t}});
                     let shifted = *self * factor;
@@ -198,6 +223,16 @@ impl FloatLike for Decimal {
     fn powf(self, n: Self) -> Self {
         self.powd(n)
     }
+    fn ln(self) -> Self {
+        MathematicalOps::ln(&self)
+    }
+    fn exp(self) -> Self {
+        MathematicalOps::exp(&self)
+    }
+    // This will panic if the Decimal is negative
+    fn sqrt(self) -> Self {
+        MathematicalOps::sqrt(&self).expect("Failed to take the square root of a negative Decimal")
+    }
     fn abs(&self) -> Self {
         Decimal::abs(self)
     }
@@ -232,6 +267,10 @@ impl FloatLike for Decimal {
     fn from_f32(n: f32) -> Self {
         <Decimal as FromPrimitive>::from_f32(n).expect("Failed to convert f32 to Decimal")
     }
+    // This may panic if the f64 cannot be represented as a Decimal
+    fn from_f64(n: f64) -> Self {
+        <Decimal as FromPrimitive>::from_f64(n).expect("Failed to convert f64 to Decimal")
+    }
 }
 
 #[cfg(test)]
@@ -2,8 +2,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod amort_dep_tax;
+pub mod bond;
+pub mod curve;
+pub mod dates;
 pub mod derivatives;
+pub mod options;
 pub mod rate;
+pub mod swap;
 pub mod tvm;
 pub mod utils;
 
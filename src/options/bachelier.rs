@@ -0,0 +1,278 @@
+use crate::options::OptionType;
+use crate::utils::{norm_cdf, norm_pdf};
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bachelier
+///
+/// A European call or put on a forward price or rate, priced under the Bachelier (normal) model where the forward
+/// follows an arithmetic Brownian motion. The forward and the strike may be zero or negative, which makes it the
+/// model of choice for low and negative interest rates. Caplets, floorlets and swaptions are priced with the forward
+/// rate or forward swap rate as the forward, see `cap_floor` and `swaption`.
+///
+/// The volatility is a normal volatility in the units of the forward, e.g. 0.008 for 80 basis points per year on a
+/// rate. As in `Black76`, the discount factor is an input and the Greeks hold it fixed.
+///
+/// # Examples
+/// * 2 year call struck at 2.5% on a forward rate of 2%, 80 basis points normal volatility
+/// ```
+/// use rust_finprim::options::{Bachelier, OptionType};
+///
+/// let discount_factor: f64 = (-0.03 * 2.0f64).exp();
+/// let option = Bachelier::new(OptionType::Call, 0.02, 0.025, 2.0, discount_factor, 0.008);
+/// assert!((option.price() - 0.0023047).abs() < 1e-7);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bachelier<T> {
+    /// Whether the option is a call or a put.
+    pub option_type: OptionType,
+
+    /// The forward price or rate of the underlying at expiry.
+    pub forward: T,
+
+    /// The strike price or rate.
+    pub strike: T,
+
+    /// The time to expiry in years.
+    pub time: T,
+
+    /// The discount factor to the payment date of the option.
+    pub discount_factor: T,
+
+    /// The annualized normal volatility of the forward.
+    pub volatility: T,
+}
+
+/// Terms shared by the price and the Greeks
+struct Terms<T> {
+    /// 1 for a call, -1 for a put
+    sign: T,
+    d: T,
+    /// \\(N(\pm d)\\) with the sign of the option
+    cdf_d: T,
+    /// \\(\phi(d)\\), zero when the volatility or the time to expiry is zero
+    pdf_d: T,
+}
+
+impl<T: FloatLike> Bachelier<T> {
+    /// Creates a new `Bachelier` instance.
+    ///
+    /// # Arguments
+    /// * `option_type` - Whether the option is a call or a put
+    /// * `forward` - The forward price or rate of the underlying
+    /// * `strike` - The strike price or rate
+    /// * `time` - The time to expiry in years
+    /// * `discount_factor` - The discount factor to the payment date of the option
+    /// * `volatility` - The annualized normal volatility of the forward
+    pub fn new(option_type: OptionType, forward: T, strike: T, time: T, discount_factor: T, volatility: T) -> Self {
+        Self {
+            option_type,
+            forward,
+            strike,
+            time,
+            discount_factor,
+            volatility,
+        }
+    }
+
+    fn terms(&self) -> Terms<T> {
+        let sign = match self.option_type {
+            OptionType::Call => T::one(),
+            OptionType::Put => -T::one(),
+        };
+        let std_dev = self.volatility * self.time.sqrt();
+        if std_dev.is_zero() {
+            let in_the_money = if sign * (self.forward - self.strike) > T::zero() {
+                T::one()
+            } else {
+                T::zero()
+            };
+            return Terms {
+                sign,
+                d: T::zero(),
+                cdf_d: in_the_money,
+                pdf_d: T::zero(),
+            };
+        }
+        let d = (self.forward - self.strike) / std_dev;
+        Terms {
+            sign,
+            d,
+            cdf_d: norm_cdf(sign * d),
+            pdf_d: norm_pdf(d),
+        }
+    }
+
+    /// Price
+    ///
+    /// The value of the option.
+    ///
+    /// # Formula
+    /// $$V = P \left( \omega (F - K) N(\omega d) + \sigma \sqrt{T} \phi(d) \right), \quad
+    /// d = \frac{F - K}{\sigma \sqrt{T}}$$
+    ///
+    /// Where:
+    /// * \\(\omega\\) = 1 for a call and -1 for a put
+    /// * \\(P\\) = discount factor, \\(F\\) = forward, \\(K\\) = strike, \\(T\\) = time to expiry
+    /// * \\(\sigma\\) = normal volatility
+    pub fn price(&self) -> T {
+        let terms = self.terms();
+        self.discount_factor
+            * (terms.sign * (self.forward - self.strike) * terms.cdf_d
+                + self.volatility * self.time.sqrt() * terms.pdf_d)
+    }
+
+    /// Delta
+    ///
+    /// The sensitivity of the price to the forward, \\(\frac{\partial V}{\partial F}\\).
+    ///
+    /// # Formula
+    /// $$\Delta = P \omega N(\omega d)$$
+    pub fn delta(&self) -> T {
+        let terms = self.terms();
+        self.discount_factor * terms.sign * terms.cdf_d
+    }
+
+    /// Gamma
+    ///
+    /// The sensitivity of delta to the forward, \\(\frac{\partial^2 V}{\partial F^2}\\).
+    ///
+    /// # Formula
+    /// $$\Gamma = \frac{P \phi(d)}{\sigma \sqrt{T}}$$
+    pub fn gamma(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d.is_zero() {
+            return T::zero();
+        }
+        self.discount_factor * terms.pdf_d / (self.volatility * self.time.sqrt())
+    }
+
+    /// Vega
+    ///
+    /// The sensitivity of the price to the normal volatility, \\(\frac{\partial V}{\partial \sigma}\\).
+    ///
+    /// # Formula
+    /// $$\nu = P \sqrt{T} \phi(d)$$
+    pub fn vega(&self) -> T {
+        let terms = self.terms();
+        self.discount_factor * self.time.sqrt() * terms.pdf_d
+    }
+
+    /// Theta
+    ///
+    /// The sensitivity of the price to the passage of time, \\(-\frac{\partial V}{\partial T}\\), per year, with the
+    /// discount factor held fixed.
+    ///
+    /// # Formula
+    /// $$\Theta = -\frac{P \sigma \phi(d)}{2 \sqrt{T}}$$
+    pub fn theta(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d.is_zero() {
+            return T::zero();
+        }
+        -self.discount_factor * self.volatility * terms.pdf_d / (T::two() * self.time.sqrt())
+    }
+
+    /// Vanna
+    ///
+    /// The sensitivity of delta to the normal volatility, \\(\frac{\partial^2 V}{\partial F \partial \sigma}\\).
+    ///
+    /// # Formula
+    /// $$\text{Vanna} = -P \phi(d) \frac{d}{\sigma}$$
+    pub fn vanna(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d.is_zero() {
+            return T::zero();
+        }
+        -self.discount_factor * terms.pdf_d * terms.d / self.volatility
+    }
+
+    /// Volga
+    ///
+    /// The sensitivity of vega to the normal volatility, \\(\frac{\partial^2 V}{\partial \sigma^2}\\).
+    ///
+    /// # Formula
+    /// $$\text{Volga} = \nu \frac{d^2}{\sigma}$$
+    pub fn volga(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d.is_zero() {
+            return T::zero();
+        }
+        self.vega() * terms.d * terms.d / self.volatility
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_bachelier() {
+        let discount_factor = (-0.06f64).exp();
+        let call = Bachelier::new(OptionType::Call, 0.02, 0.025, 2.0, discount_factor, 0.008);
+        let put = Bachelier {
+            option_type: OptionType::Put,
+            ..call
+        };
+        // Price, delta, gamma, vega, theta, vanna and volga
+        let cases = [
+            (
+                call,
+                [
+                    0.002304736504047458,
+                    0.31009074261048414,
+                    30.118673571092796,
+                    0.4818987771374848,
+                    -0.0009637975542749695,
+                    18.824170981933,
+                    11.765106863708127,
+                ],
+            ),
+            (
+                put,
+                [
+                    0.007013559171968702,
+                    -0.6316737909737645,
+                    30.118673571092796,
+                    0.4818987771374848,
+                    -0.0009637975542749695,
+                    18.824170981933,
+                    11.765106863708127,
+                ],
+            ),
+        ];
+        for (option, expected) in &cases {
+            let result = [
+                option.price(),
+                option.delta(),
+                option.gamma(),
+                option.vega(),
+                option.theta(),
+                option.vanna(),
+                option.volga(),
+            ];
+            for (result, expected) in result.iter().zip(expected) {
+                assert!(
+                    (result - expected).abs() < 1e-9 * expected.abs().max(1.0),
+                    "Failed on case: {:?}. Expected: {}, Result: {}",
+                    option,
+                    expected,
+                    result
+                );
+            }
+        }
+
+        // Negative rates and expiry
+        let put = Bachelier::new(OptionType::Put, -0.005, 0.0, 1.0, 1.0, 0.004);
+        assert!(put.price() > 0.005);
+        let expired = Bachelier { time: 0.0, ..put };
+        assert_eq!(expired.price(), 0.005);
+    }
+}
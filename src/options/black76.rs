@@ -0,0 +1,318 @@
+use crate::options::OptionType;
+use crate::utils::{norm_cdf, norm_pdf};
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Black-76
+///
+/// A European call or put on a forward or futures price, or on a forward interest rate, priced under the Black
+/// (1976) model where the forward is lognormal. Caplets, floorlets and swaptions are priced with the forward rate or
+/// forward swap rate as the forward, see `cap_floor` and `swaption`.
+///
+/// The discount factor is an input rather than a rate, so that the payment can fall after expiry as it does for a
+/// caplet. The Greeks are the partial derivatives of the price in their natural units, with the discount factor
+/// held fixed: delta and gamma are with respect to the forward, vega and volga per unit of volatility and theta per
+/// year of time to expiry.
+///
+/// The forward and the strike should be greater than zero, use `Bachelier` for negative rates. An option with a
+/// forward or strike at or below zero has no lognormal distribution and is valued at its discounted intrinsic value.
+///
+/// # Examples
+/// * 4 month put struck at 20 on a futures price of 20, 25% volatility, discounted at 9%
+/// ```
+/// use rust_finprim::options::{Black76, OptionType};
+///
+/// let discount_factor: f64 = (-0.09 * 4.0 / 12.0f64).exp();
+/// let option = Black76::new(OptionType::Put, 20.0, 20.0, 4.0 / 12.0, discount_factor, 0.25);
+/// assert!((option.price() - 1.1166).abs() < 1e-4);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Black76<T> {
+    /// Whether the option is a call or a put.
+    pub option_type: OptionType,
+
+    /// The forward or futures price, or forward rate, of the underlying at expiry.
+    pub forward: T,
+
+    /// The strike price or rate.
+    pub strike: T,
+
+    /// The time to expiry in years.
+    pub time: T,
+
+    /// The discount factor to the payment date of the option.
+    pub discount_factor: T,
+
+    /// The annualized lognormal volatility of the forward.
+    pub volatility: T,
+}
+
+/// Terms shared by the price and the Greeks
+struct Terms<T> {
+    /// 1 for a call, -1 for a put
+    sign: T,
+    d1: T,
+    d2: T,
+    /// \\(N(\pm d_1)\\) and \\(N(\pm d_2)\\) with the sign of the option
+    cdf_d1: T,
+    cdf_d2: T,
+    /// \\(\phi(d_1)\\), zero when the volatility or the time to expiry is zero, or the forward or strike is not
+    /// positive
+    pdf_d1: T,
+}
+
+impl<T: FloatLike> Black76<T> {
+    /// Creates a new `Black76` instance.
+    ///
+    /// # Arguments
+    /// * `option_type` - Whether the option is a call or a put
+    /// * `forward` - The forward or futures price, or forward rate, of the underlying, should be greater than zero
+    /// * `strike` - The strike price or rate, should be greater than zero
+    /// * `time` - The time to expiry in years
+    /// * `discount_factor` - The discount factor to the payment date of the option
+    /// * `volatility` - The annualized lognormal volatility of the forward
+    pub fn new(option_type: OptionType, forward: T, strike: T, time: T, discount_factor: T, volatility: T) -> Self {
+        Self {
+            option_type,
+            forward,
+            strike,
+            time,
+            discount_factor,
+            volatility,
+        }
+    }
+
+    fn terms(&self) -> Terms<T> {
+        let sign = match self.option_type {
+            OptionType::Call => T::one(),
+            OptionType::Put => -T::one(),
+        };
+        let std_dev = self.volatility * self.time.sqrt();
+        if std_dev.is_zero() || self.forward <= T::zero() || self.strike <= T::zero() {
+            let in_the_money = if sign * (self.forward - self.strike) > T::zero() {
+                T::one()
+            } else {
+                T::zero()
+            };
+            return Terms {
+                sign,
+                d1: T::zero(),
+                d2: T::zero(),
+                cdf_d1: in_the_money,
+                cdf_d2: in_the_money,
+                pdf_d1: T::zero(),
+            };
+        }
+        let d1 = ((self.forward / self.strike).ln() + std_dev * std_dev / T::two()) / std_dev;
+        let d2 = d1 - std_dev;
+        Terms {
+            sign,
+            d1,
+            d2,
+            cdf_d1: norm_cdf(sign * d1),
+            cdf_d2: norm_cdf(sign * d2),
+            pdf_d1: norm_pdf(d1),
+        }
+    }
+
+    /// Price
+    ///
+    /// The value of the option.
+    ///
+    /// # Formula
+    /// $$V = P \omega \left( F N(\omega d_1) - K N(\omega d_2) \right)$$
+    ///
+    /// $$d_1 = \frac{\ln(F/K) + \sigma^2 T / 2}{\sigma \sqrt{T}}, \quad d_2 = d_1 - \sigma \sqrt{T}$$
+    ///
+    /// Where:
+    /// * \\(\omega\\) = 1 for a call and -1 for a put
+    /// * \\(P\\) = discount factor, \\(F\\) = forward, \\(K\\) = strike, \\(T\\) = time to expiry
+    /// * \\(\sigma\\) = volatility
+    pub fn price(&self) -> T {
+        let terms = self.terms();
+        self.discount_factor * terms.sign * (self.forward * terms.cdf_d1 - self.strike * terms.cdf_d2)
+    }
+
+    /// Delta
+    ///
+    /// The sensitivity of the price to the forward, \\(\frac{\partial V}{\partial F}\\).
+    ///
+    /// # Formula
+    /// $$\Delta = P \omega N(\omega d_1)$$
+    pub fn delta(&self) -> T {
+        let terms = self.terms();
+        self.discount_factor * terms.sign * terms.cdf_d1
+    }
+
+    /// Gamma
+    ///
+    /// The sensitivity of delta to the forward, \\(\frac{\partial^2 V}{\partial F^2}\\).
+    ///
+    /// # Formula
+    /// $$\Gamma = \frac{P \phi(d_1)}{F \sigma \sqrt{T}}$$
+    pub fn gamma(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        self.discount_factor * terms.pdf_d1 / (self.forward * self.volatility * self.time.sqrt())
+    }
+
+    /// Vega
+    ///
+    /// The sensitivity of the price to the volatility, \\(\frac{\partial V}{\partial \sigma}\\).
+    ///
+    /// # Formula
+    /// $$\nu = P F \phi(d_1) \sqrt{T}$$
+    pub fn vega(&self) -> T {
+        let terms = self.terms();
+        self.discount_factor * self.forward * terms.pdf_d1 * self.time.sqrt()
+    }
+
+    /// Theta
+    ///
+    /// The sensitivity of the price to the passage of time, \\(-\frac{\partial V}{\partial T}\\), per year, with the
+    /// discount factor held fixed.
+    ///
+    /// # Formula
+    /// $$\Theta = -\frac{P F \phi(d_1) \sigma}{2 \sqrt{T}}$$
+    pub fn theta(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        -self.discount_factor * self.forward * terms.pdf_d1 * self.volatility / (T::two() * self.time.sqrt())
+    }
+
+    /// Vanna
+    ///
+    /// The sensitivity of delta to the volatility, \\(\frac{\partial^2 V}{\partial F \partial \sigma}\\).
+    ///
+    /// # Formula
+    /// $$\text{Vanna} = -P \phi(d_1) \frac{d_2}{\sigma}$$
+    pub fn vanna(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        -self.discount_factor * terms.pdf_d1 * terms.d2 / self.volatility
+    }
+
+    /// Volga
+    ///
+    /// The sensitivity of vega to the volatility, \\(\frac{\partial^2 V}{\partial \sigma^2}\\).
+    ///
+    /// # Formula
+    /// $$\text{Volga} = \nu \frac{d_1 d_2}{\sigma}$$
+    pub fn volga(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        self.vega() * terms.d1 * terms.d2 / self.volatility
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_black76() {
+        let discount_factor = (-0.05f64).exp();
+        let call = Black76::new(OptionType::Call, 105.0, 100.0, 1.0, discount_factor, 0.3);
+        let put = Black76 {
+            option_type: OptionType::Put,
+            ..call
+        };
+        // Price, delta, gamma, vega, theta, vanna and volga
+        let cases = [
+            (
+                call,
+                [
+                    14.155868770211484,
+                    0.592350141535111,
+                    0.011472572977040147,
+                    37.94553512156028,
+                    -5.6918302682340425,
+                    -0.015219027260070173,
+                    0.49958827282736507,
+                ],
+            ),
+            (
+                put,
+                [
+                    9.39972164770792,
+                    -0.35887928296560306,
+                    0.011472572977040147,
+                    37.94553512156028,
+                    -5.6918302682340425,
+                    -0.015219027260070173,
+                    0.49958827282736507,
+                ],
+            ),
+        ];
+        for (option, expected) in &cases {
+            let result = [
+                option.price(),
+                option.delta(),
+                option.gamma(),
+                option.vega(),
+                option.theta(),
+                option.vanna(),
+                option.volga(),
+            ];
+            for (result, expected) in result.iter().zip(expected) {
+                assert!(
+                    (result - expected).abs() < 1e-10,
+                    "Failed on case: {:?}. Expected: {}, Result: {}",
+                    option,
+                    expected,
+                    result
+                );
+            }
+        }
+
+        // Put-call parity on the forward
+        assert!((call.price() - put.price() - discount_factor * 5.0).abs() < 1e-12);
+
+        // A forward or strike at or below zero is worth its discounted intrinsic value
+        let negative_forward = Black76 { forward: -0.5, ..put };
+        assert!((negative_forward.price() - discount_factor * 100.5).abs() < 1e-12);
+        assert_eq!(Black76 { forward: 0.0, ..call }.price(), 0.0);
+        assert_eq!(negative_forward.gamma(), 0.0);
+        let zero_strike = Black76 { strike: 0.0, ..call };
+        assert!((zero_strike.price() - discount_factor * 105.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_black76_non_positive_forward_decimal() {
+        use rust_decimal_macros::dec;
+
+        let put = Black76::new(
+            OptionType::Put,
+            dec!(-0.001),
+            dec!(0.02),
+            dec!(1),
+            dec!(0.95),
+            dec!(0.2),
+        );
+        assert_eq!(put.price(), dec!(0.95) * dec!(0.021));
+        assert_eq!(
+            Black76 {
+                option_type: OptionType::Call,
+                ..put
+            }
+            .price(),
+            dec!(0)
+        );
+    }
+}
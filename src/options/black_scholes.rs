@@ -0,0 +1,590 @@
+use crate::options::OptionType;
+use crate::utils::{norm_cdf, norm_pdf, solve, SolverConfig, SolverMethod};
+use crate::FinPrimError;
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Black-Scholes-Merton
+///
+/// A European call or put on an underlying paying a continuous dividend yield, priced under the Black-Scholes-Merton
+/// model. Set the dividend yield to zero for the original Black-Scholes model, to the foreign interest rate for a
+/// currency option (Garman-Kohlhagen), or to the cost of carry adjustment of a commodity.
+///
+/// The Greeks are the partial derivatives of the price in their natural units:
+/// * vega and volga are per unit of volatility, divide vega by 100 for the change per volatility point
+/// * theta is per year of calendar time, divide by 365 for the change per day
+/// * rho is per unit of interest rate, divide by 100 for the change per percentage point
+///
+/// When the volatility or the time to expiry is zero, the option is worth its discounted intrinsic value and the
+/// Greeks that depend on the volatility (gamma, vega, vanna and volga) are zero.
+///
+/// # Examples
+/// * 6 month call struck at 40 on a stock at 42, 10% interest rate and 20% volatility
+/// ```
+/// use rust_finprim::options::{BlackScholes, OptionType};
+///
+/// let option = BlackScholes::new(OptionType::Call, 42.0, 40.0, 0.5, 0.1, 0.0, 0.2);
+/// let price: f64 = option.price();
+/// assert!((price - 4.7594).abs() < 1e-4);
+///
+/// let put = BlackScholes { option_type: OptionType::Put, ..option };
+/// assert!((put.price() - 0.8086).abs() < 1e-4);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BlackScholes<T> {
+    /// Whether the option is a call or a put.
+    pub option_type: OptionType,
+
+    /// The spot price of the underlying.
+    pub spot: T,
+
+    /// The strike price.
+    pub strike: T,
+
+    /// The time to expiry in years.
+    pub time: T,
+
+    /// The continuously compounded risk-free interest rate.
+    pub rate: T,
+
+    /// The continuously compounded dividend yield of the underlying.
+    pub dividend_yield: T,
+
+    /// The annualized volatility of the underlying's returns.
+    pub volatility: T,
+}
+
+/// Terms shared by the price and the Greeks
+struct Terms<T> {
+    /// 1 for a call, -1 for a put
+    sign: T,
+    d1: T,
+    d2: T,
+    /// \\(N(\pm d_1)\\) and \\(N(\pm d_2)\\) with the sign of the option
+    cdf_d1: T,
+    cdf_d2: T,
+    /// \\(\phi(d_1)\\), zero when the volatility or the time to expiry is zero
+    pdf_d1: T,
+    /// \\(e^{-qT}\\)
+    dividend_discount: T,
+    /// \\(e^{-rT}\\)
+    discount: T,
+}
+
+impl<T: FloatLike> BlackScholes<T> {
+    /// Creates a new `BlackScholes` instance.
+    ///
+    /// # Arguments
+    /// * `option_type` - Whether the option is a call or a put
+    /// * `spot` - The spot price of the underlying, should be greater than zero
+    /// * `strike` - The strike price, should be greater than zero
+    /// * `time` - The time to expiry in years
+    /// * `rate` - The continuously compounded risk-free interest rate
+    /// * `dividend_yield` - The continuously compounded dividend yield of the underlying
+    /// * `volatility` - The annualized volatility of the underlying's returns
+    pub fn new(
+        option_type: OptionType,
+        spot: T,
+        strike: T,
+        time: T,
+        rate: T,
+        dividend_yield: T,
+        volatility: T,
+    ) -> Self {
+        Self {
+            option_type,
+            spot,
+            strike,
+            time,
+            rate,
+            dividend_yield,
+            volatility,
+        }
+    }
+
+    fn terms(&self) -> Terms<T> {
+        let sign = match self.option_type {
+            OptionType::Call => T::one(),
+            OptionType::Put => -T::one(),
+        };
+        let dividend_discount = (-self.dividend_yield * self.time).exp();
+        let discount = (-self.rate * self.time).exp();
+        let std_dev = self.volatility * self.time.sqrt();
+        if std_dev.is_zero() {
+            // The option expires in or out of the money with certainty
+            let forward_moneyness = self.spot * dividend_discount - self.strike * discount;
+            let in_the_money = if sign * forward_moneyness > T::zero() {
+                T::one()
+            } else {
+                T::zero()
+            };
+            return Terms {
+                sign,
+                d1: T::zero(),
+                d2: T::zero(),
+                cdf_d1: in_the_money,
+                cdf_d2: in_the_money,
+                pdf_d1: T::zero(),
+                dividend_discount,
+                discount,
+            };
+        }
+        let d1 = ((self.spot / self.strike).ln()
+            + (self.rate - self.dividend_yield + self.volatility * self.volatility / T::two()) * self.time)
+            / std_dev;
+        let d2 = d1 - std_dev;
+        Terms {
+            sign,
+            d1,
+            d2,
+            cdf_d1: norm_cdf(sign * d1),
+            cdf_d2: norm_cdf(sign * d2),
+            pdf_d1: norm_pdf(d1),
+            dividend_discount,
+            discount,
+        }
+    }
+
+    /// Price
+    ///
+    /// The value of the option.
+    ///
+    /// # Formula
+    /// $$V = \omega \left( S e^{-qT} N(\omega d_1) - K e^{-rT} N(\omega d_2) \right)$$
+    ///
+    /// $$d_1 = \frac{\ln(S/K) + (r - q + \sigma^2/2) T}{\sigma \sqrt{T}}, \quad d_2 = d_1 - \sigma \sqrt{T}$$
+    ///
+    /// Where:
+    /// * \\(\omega\\) = 1 for a call and -1 for a put
+    /// * \\(S\\) = spot price, \\(K\\) = strike price, \\(T\\) = time to expiry
+    /// * \\(r\\) = interest rate, \\(q\\) = dividend yield, \\(\sigma\\) = volatility
+    /// * \\(N\\) = standard normal cumulative distribution function (`utils::norm_cdf`)
+    pub fn price(&self) -> T {
+        let terms = self.terms();
+        terms.sign * (self.spot * terms.dividend_discount * terms.cdf_d1 - self.strike * terms.discount * terms.cdf_d2)
+    }
+
+    /// Delta
+    ///
+    /// The sensitivity of the price to the spot price, \\(\frac{\partial V}{\partial S}\\).
+    ///
+    /// # Formula
+    /// $$\Delta = \omega e^{-qT} N(\omega d_1)$$
+    pub fn delta(&self) -> T {
+        let terms = self.terms();
+        terms.sign * terms.dividend_discount * terms.cdf_d1
+    }
+
+    /// Gamma
+    ///
+    /// The sensitivity of delta to the spot price, \\(\frac{\partial^2 V}{\partial S^2}\\), the same for calls and
+    /// puts.
+    ///
+    /// # Formula
+    /// $$\Gamma = \frac{e^{-qT} \phi(d_1)}{S \sigma \sqrt{T}}$$
+    ///
+    /// Where \\(\phi\\) is the standard normal probability density function (`utils::norm_pdf`)
+    pub fn gamma(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        terms.dividend_discount * terms.pdf_d1 / (self.spot * self.volatility * self.time.sqrt())
+    }
+
+    /// Vega
+    ///
+    /// The sensitivity of the price to the volatility, \\(\frac{\partial V}{\partial \sigma}\\), the same for calls
+    /// and puts.
+    ///
+    /// # Formula
+    /// $$\nu = S e^{-qT} \phi(d_1) \sqrt{T}$$
+    pub fn vega(&self) -> T {
+        let terms = self.terms();
+        self.spot * terms.dividend_discount * terms.pdf_d1 * self.time.sqrt()
+    }
+
+    /// Theta
+    ///
+    /// The sensitivity of the price to the passage of time, \\(-\frac{\partial V}{\partial T}\\), per year.
+    ///
+    /// # Formula
+    /// $$\Theta = -\frac{S e^{-qT} \phi(d_1) \sigma}{2 \sqrt{T}} - \omega r K e^{-rT} N(\omega d_2)
+    /// + \omega q S e^{-qT} N(\omega d_1)$$
+    pub fn theta(&self) -> T {
+        let terms = self.terms();
+        let decay = if terms.pdf_d1.is_zero() {
+            T::zero()
+        } else {
+            self.spot * terms.dividend_discount * terms.pdf_d1 * self.volatility / (T::two() * self.time.sqrt())
+        };
+        -decay
+            + terms.sign
+                * (self.dividend_yield * self.spot * terms.dividend_discount * terms.cdf_d1
+                    - self.rate * self.strike * terms.discount * terms.cdf_d2)
+    }
+
+    /// Rho
+    ///
+    /// The sensitivity of the price to the interest rate, \\(\frac{\partial V}{\partial r}\\).
+    ///
+    /// # Formula
+    /// $$\rho = \omega K T e^{-rT} N(\omega d_2)$$
+    pub fn rho(&self) -> T {
+        let terms = self.terms();
+        terms.sign * self.strike * self.time * terms.discount * terms.cdf_d2
+    }
+
+    /// Vanna
+    ///
+    /// The sensitivity of delta to the volatility, \\(\frac{\partial^2 V}{\partial S \partial \sigma}\\), the same
+    /// for calls and puts.
+    ///
+    /// # Formula
+    /// $$\text{Vanna} = -e^{-qT} \phi(d_1) \frac{d_2}{\sigma}$$
+    pub fn vanna(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        -terms.dividend_discount * terms.pdf_d1 * terms.d2 / self.volatility
+    }
+
+    /// Volga
+    ///
+    /// The sensitivity of vega to the volatility, \\(\frac{\partial^2 V}{\partial \sigma^2}\\), also known as vomma,
+    /// the same for calls and puts.
+    ///
+    /// # Formula
+    /// $$\text{Volga} = \nu \frac{d_1 d_2}{\sigma}$$
+    pub fn volga(&self) -> T {
+        let terms = self.terms();
+        if terms.pdf_d1.is_zero() {
+            return T::zero();
+        }
+        self.vega() * terms.d1 * terms.d2 / self.volatility
+    }
+
+    /// No-Arbitrage Bounds
+    ///
+    /// The `(lower, upper)` bounds on the price of the option, its prices at zero and at infinite volatility. Any
+    /// price from the lower bound up to, but excluding, the upper bound has an implied volatility.
+    ///
+    /// # Formula
+    /// * Call: \\(\max(S e^{-qT} - K e^{-rT}, 0) \le C < S e^{-qT}\\)
+    /// * Put: \\(\max(K e^{-rT} - S e^{-qT}, 0) \le P < K e^{-rT}\\)
+    pub fn price_bounds(&self) -> (T, T) {
+        let forward_spot = self.spot * (-self.dividend_yield * self.time).exp();
+        let forward_strike = self.strike * (-self.rate * self.time).exp();
+        match self.option_type {
+            OptionType::Call => ((forward_spot - forward_strike).max(T::zero()), forward_spot),
+            OptionType::Put => ((forward_strike - forward_spot).max(T::zero()), forward_strike),
+        }
+    }
+
+    /// Implied Volatility
+    ///
+    /// The volatility at which the option is worth a given price. The volatility of the instance is ignored.
+    ///
+    /// # Arguments
+    /// * `price` - The market price of the option
+    /// * `guess` (optional) - A guess for the volatility, defaults to the Corrado-Miller approximation
+    /// * `tolerance` (optional) - The tolerance/maximum error bound for the price at the implied volatility, defaults
+    ///   to 1e-10
+    /// * `max_iter` (optional) - The maximum number of Newton-Raphson iterations to perform, defaults to 100
+    ///
+    /// # Returns
+    /// * Result of the implied volatility, zero for a price on the lower no-arbitrage bound
+    /// * `FinPrimError::ArbitrageViolation` with the bounds if the price is outside the no-arbitrage bounds, see
+    ///   `price_bounds`
+    /// * `FinPrimError::NoSolution` if the option has expired, its price no longer depends on the volatility
+    /// * If the calculation fails, it returns a `FinPrimError::RootFindingError` with the last estimated volatility
+    ///   and the pricing error at that volatility
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_finprim::options::{BlackScholes, OptionType};
+    ///
+    /// let option = BlackScholes::new(OptionType::Call, 42.0, 40.0, 0.5, 0.1, 0.0, 0.0);
+    /// let volatility: f64 = option.implied_volatility(4.759422, None, None, None).unwrap();
+    /// assert!((volatility - 0.2).abs() < 1e-6);
+    /// ```
+    ///
+    /// The search starts from the Corrado-Miller approximation, which reduces to the Brenner-Subrahmanyam
+    /// approximation \\(\sigma \approx \sqrt{2\pi / T} \, C / S\\) at the money, and takes Newton-Raphson steps on
+    /// vega. Far from the money, where vega vanishes and Newton-Raphson overshoots, it falls back to bisection
+    /// between zero and a volatility that prices the option above the market.
+    pub fn implied_volatility(
+        &self,
+        price: T,
+        guess: Option<T>,
+        tolerance: Option<T>,
+        max_iter: Option<u16>,
+    ) -> Result<T, FinPrimError<T>> {
+        let (lower, upper) = self.price_bounds();
+        if price < lower || price >= upper {
+            return Err(FinPrimError::ArbitrageViolation { price, lower, upper });
+        }
+        if self.time <= T::zero() {
+            return Err(FinPrimError::NoSolution);
+        }
+        if price == lower {
+            return Ok(T::zero());
+        }
+
+        let with_volatility = |volatility| BlackScholes { volatility, ..*self };
+        let f = |volatility| with_volatility(volatility).price() - price;
+        let f_prime = |volatility| with_volatility(volatility).vega();
+        let tolerance = tolerance.unwrap_or(T::from_f32(1e-10));
+        let max_iter = max_iter.unwrap_or(100);
+
+        // The price rises with the volatility, double it until the option is worth more than the market price
+        let mut max_volatility = T::one();
+        while f(max_volatility) < T::zero() && max_volatility < T::from_u16(1000) {
+            max_volatility *= T::two();
+        }
+        let guess = guess
+            .unwrap_or_else(|| self.volatility_guess(price))
+            .min(max_volatility);
+
+        let config = SolverConfig {
+            method: SolverMethod::NewtonRaphson,
+            tolerance,
+            max_iter,
+            bounds: Some((T::zero(), max_volatility)),
+            fallback: false,
+            ..Default::default()
+        };
+        let report = solve(f, f_prime, |_| T::zero(), guess, &config);
+        if report.reason.converged() {
+            return Ok(report.root);
+        }
+        let config = SolverConfig {
+            method: SolverMethod::Bisection,
+            step_tolerance: tolerance,
+            max_iter: max_iter.max(100),
+            ..config
+        };
+        solve(f, f_prime, |_| T::zero(), guess, &config).root_or_error()
+    }
+
+    /// Corrado-Miller approximation of the implied volatility, puts are converted to calls by put-call parity
+    fn volatility_guess(&self, price: T) -> T {
+        let forward_spot = self.spot * (-self.dividend_yield * self.time).exp();
+        let forward_strike = self.strike * (-self.rate * self.time).exp();
+        let moneyness = forward_spot - forward_strike;
+        let call = match self.option_type {
+            OptionType::Call => price,
+            OptionType::Put => price + moneyness,
+        };
+        let pi = T::from_f64(core::f64::consts::PI);
+        let excess = call - moneyness / T::two();
+        let radicand = (excess * excess - moneyness * moneyness / pi).max(T::zero());
+        (T::two() * pi).sqrt() / (forward_spot + forward_strike) * (excess + radicand.sqrt()) / self.time.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_black_scholes() {
+        struct TestCase {
+            option: BlackScholes<f64>,
+            // Price, delta, gamma, vega, theta, rho, vanna and volga
+            expected: [f64; 8],
+        }
+        impl TestCase {
+            fn new(option: BlackScholes<f64>, expected: [f64; 8]) -> TestCase {
+                TestCase { option, expected }
+            }
+        }
+        let call = BlackScholes::new(OptionType::Call, 42.0, 40.0, 0.5, 0.1, 0.0, 0.2);
+        let dividend_call = BlackScholes::new(OptionType::Call, 100.0, 95.0, 0.75, 0.05, 0.02, 0.25);
+        let cases = [
+            TestCase::new(
+                call,
+                [
+                    4.759422392871535,
+                    0.779131290942669,
+                    0.04996267040591185,
+                    8.813415059602853,
+                    -4.559092194592626,
+                    13.982045913360281,
+                    -0.9316006786136686,
+                    21.283288061014414,
+                ],
+            ),
+            TestCase::new(
+                BlackScholes {
+                    option_type: OptionType::Put,
+                    ..call
+                },
+                [
+                    0.8085993729000958,
+                    -0.22086870905733103,
+                    0.04996267040591185,
+                    8.813415059602853,
+                    -0.7541744965897705,
+                    -5.042542576653999,
+                    -0.9316006786136686,
+                    21.283288061014414,
+                ],
+            ),
+            TestCase::new(
+                dividend_call,
+                [
+                    12.1630477115284,
+                    0.6632921841683713,
+                    0.016410824240452263,
+                    30.77029545084799,
+                    -6.510106742070026,
+                    40.62462802898155,
+                    -0.3305520364729774,
+                    12.855934837076058,
+                ],
+            ),
+            TestCase::new(
+                BlackScholes {
+                    option_type: OptionType::Put,
+                    ..dividend_call
+                },
+                [
+                    5.1553234347002075,
+                    -0.3218197554346913,
+                    0.016410824240452263,
+                    30.77029545084799,
+                    -3.9051571371022478,
+                    -28.002974233626997,
+                    -0.3305520364729774,
+                    12.855934837076058,
+                ],
+            ),
+        ];
+        for case in &cases {
+            let option = &case.option;
+            let result = [
+                option.price(),
+                option.delta(),
+                option.gamma(),
+                option.vega(),
+                option.theta(),
+                option.rho(),
+                option.vanna(),
+                option.volga(),
+            ];
+            for (result, expected) in result.iter().zip(case.expected) {
+                assert!(
+                    (result - expected).abs() < 1e-10,
+                    "Failed on case: {:?}. Expected: {}, Result: {}",
+                    option,
+                    expected,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_black_scholes_expired() {
+        // At expiry the option is worth its intrinsic value
+        let call = BlackScholes::new(OptionType::Call, 42.0, 40.0, 0.0, 0.1, 0.0, 0.2);
+        assert_eq!(call.price(), 2.0);
+        assert_eq!(call.delta(), 1.0);
+        assert_eq!(
+            (call.gamma(), call.vega(), call.vanna(), call.volga()),
+            (0.0, 0.0, 0.0, 0.0)
+        );
+        let put = BlackScholes {
+            option_type: OptionType::Put,
+            ..call
+        };
+        assert_eq!((put.price(), put.delta()), (0.0, 0.0));
+
+        // Without volatility the call is worth the discounted forward less the discounted strike
+        let call = BlackScholes {
+            time: 1.0,
+            volatility: 0.0,
+            ..call
+        };
+        let expected = 42.0 - 40.0 * (-0.1f64).exp();
+        assert!((call.price() - expected).abs() < 1e-12);
+        assert!((call.theta() + 4.0 * (-0.1f64).exp()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_implied_volatility() {
+        for option_type in [OptionType::Call, OptionType::Put] {
+            for strike in [50.0, 80.0, 100.0, 120.0, 200.0] {
+                for volatility in [0.05, 0.2, 0.8, 2.0] {
+                    let option = BlackScholes::new(option_type, 100.0, strike, 1.0, 0.03, 0.01, volatility);
+                    // Skip prices too insensitive to the volatility to recover it
+                    if option.vega() < 1e-2 {
+                        continue;
+                    }
+                    let result = option.implied_volatility(option.price(), None, None, None).unwrap();
+                    assert!(
+                        (result - volatility).abs() < 1e-8,
+                        "Failed on case: {:?}. Expected: {}, Result: {}",
+                        option,
+                        volatility,
+                        result
+                    );
+                }
+            }
+        }
+
+        // Newton-Raphson cannot converge from a poor guess in a single iteration, bisection takes over
+        let option = BlackScholes::new(OptionType::Call, 100.0, 200.0, 0.25, 0.03, 0.0, 0.5);
+        let result = option
+            .implied_volatility(option.price(), Some(5.0), None, Some(1))
+            .unwrap();
+        assert!((result - 0.5).abs() < 1e-8, "Expected: 0.5, Result: {}", result);
+
+        // On and outside the no-arbitrage bounds
+        let option = BlackScholes::new(OptionType::Call, 42.0, 40.0, 0.5, 0.1, 0.0, 0.2);
+        let (lower, upper) = option.price_bounds();
+        assert!((lower - (42.0 - 40.0 * (-0.05f64).exp())).abs() < 1e-12 && upper == 42.0);
+        assert_eq!(option.implied_volatility(lower, None, None, None), Ok(0.0));
+        for price in [lower - 0.01, upper, 50.0] {
+            assert_eq!(
+                option.implied_volatility(price, None, None, None),
+                Err(FinPrimError::ArbitrageViolation { price, lower, upper })
+            );
+        }
+        let expired = BlackScholes { time: 0.0, ..option };
+        assert_eq!(
+            expired.implied_volatility(2.5, None, None, None),
+            Err(FinPrimError::NoSolution)
+        );
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_black_scholes_decimal() {
+        use rust_decimal_macros::dec;
+
+        let call = BlackScholes::new(
+            OptionType::Call,
+            dec!(42),
+            dec!(40),
+            dec!(0.5),
+            dec!(0.1),
+            dec!(0),
+            dec!(0.2),
+        );
+        assert!((call.price() - dec!(4.759422)).abs() < dec!(1e-6));
+        let volatility = call
+            .implied_volatility(dec!(4.759422), None, Some(dec!(1e-12)), None)
+            .unwrap();
+        assert!((volatility - dec!(0.2)).abs() < dec!(1e-6));
+    }
+}
@@ -0,0 +1,502 @@
+use crate::options::{BlackScholes, OptionType};
+use crate::FinPrimError;
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Exercise Style
+///
+/// When an option can be exercised.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ExerciseStyle {
+    /// Exercisable only at expiry
+    European,
+    /// Exercisable at any time up to expiry
+    American,
+}
+
+/// Present value at time `t` of the dividends paid after `t` and up to expiry
+fn dividend_pv<T: FloatLike>(dividends: &[(T, T)], t: T, expiry: T, rate: T) -> T {
+    dividends
+        .iter()
+        .filter(|(time, _)| *time > t && *time <= expiry)
+        .map(|&(time, amount)| amount * (-rate * (time - t)).exp())
+        .sum()
+}
+
+/// The value of an option whose volatility or time to expiry is zero, the `BlackScholes` discounted intrinsic value
+/// on the spot less its escrowed dividends, or the value of exercising now if an American option is worth more
+fn deterministic_value<T: FloatLike>(option: &BlackScholes<T>, exercise: ExerciseStyle, spot: T, sign: T) -> T {
+    let value = BlackScholes { spot, ..*option }.price();
+    match exercise {
+        ExerciseStyle::European => value,
+        ExerciseStyle::American => value.max(sign * (option.spot - option.strike)),
+    }
+}
+
+/// Whether a branch probability is within 0 and 1
+fn is_probability<T: FloatLike>(p: T) -> bool {
+    p >= T::zero() && p <= T::one()
+}
+
+/// The tree's option parameters: the payoff sign, the spot less the dividends it escrows, and the time step
+fn setup<T: FloatLike>(option: &BlackScholes<T>, dividends: &[(T, T)], steps: u32) -> (T, T, T) {
+    let sign = match option.option_type {
+        OptionType::Call => T::one(),
+        OptionType::Put => -T::one(),
+    };
+    let spot = option.spot - dividend_pv(dividends, T::zero(), option.time, option.rate);
+    (sign, spot, option.time / T::from_usize(steps as usize))
+}
+
+/// Binomial Tree - Cox-Ross-Rubinstein option pricing
+///
+/// Prices a European or American option on a Cox-Ross-Rubinstein binomial tree, with discrete cash dividends.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it uses `std::Vec`. `binomial_tree_into` can be used
+/// in `no_std` environments as any allocation is done by the caller.
+///
+/// # Arguments
+/// * `option` - The option and its market inputs, the tree uses every field of the `BlackScholes` instance
+/// * `exercise` - Whether the option can be exercised early
+/// * `dividends` - A slice of tuples of the discrete cash dividends `(time, amount)`, the time in years. Dividends
+///   after expiry are ignored.
+/// * `steps` - The number of time steps in the tree, at least 1
+///
+/// # Returns
+/// * Result of the value of the option, the discounted intrinsic value if the volatility or the time to expiry is
+///   zero
+/// * `FinPrimError::NoSolution` if a branch probability is outside 0 to 1, when the drift over a step is larger
+///   than the volatility over a step. More steps shrink the drift relative to the volatility.
+///
+/// # Examples
+/// * 5 month American put struck at 50 on a stock at 50, 10% interest rate and 40% volatility
+/// ```
+/// use rust_finprim::options::{binomial_tree, BlackScholes, ExerciseStyle, OptionType};
+///
+/// let option = BlackScholes::new(OptionType::Put, 50.0, 50.0, 5.0 / 12.0, 0.1, 0.0, 0.4);
+/// let price: f64 = binomial_tree(&option, ExerciseStyle::American, &[], 5).unwrap();
+/// assert!((price - 4.49).abs() < 1e-2);
+/// ```
+///
+/// # Formula
+/// $$u = e^{\sigma \sqrt{\Delta t}}, \quad d = \frac{1}{u}, \quad p = \frac{e^{(r - q) \Delta t} - d}{u - d}$$
+///
+/// Discrete dividends are escrowed: the tree is built on the spot less the present value of the dividends up to
+/// expiry, and the present value of the dividends still to be paid is added back to the stock price at each node
+/// when testing early exercise.
+#[cfg(feature = "std")]
+pub fn binomial_tree<T: FloatLike>(
+    option: &BlackScholes<T>,
+    exercise: ExerciseStyle,
+    dividends: &[(T, T)],
+    steps: u32,
+) -> Result<T, FinPrimError<T>> {
+    let steps = steps.max(1);
+    let mut values = vec![T::zero(); steps as usize + 1];
+    binomial_tree_into(&mut values, option, exercise, dividends, steps)
+}
+
+/// Binomial Tree Into - Cox-Ross-Rubinstein option pricing
+///
+/// Prices a European or American option on a Cox-Ross-Rubinstein binomial tree, with discrete cash dividends, using
+/// a slice as the working buffer for the node values. See `binomial_tree` for details of the tree.
+///
+/// # Arguments
+/// * `slice` - A mutable slice to hold the node values, at least `steps + 1` long. On return its first element is
+///   the value of the option.
+/// * `option` - The option and its market inputs, the tree uses every field of the `BlackScholes` instance
+/// * `exercise` - Whether the option can be exercised early
+/// * `dividends` - A slice of tuples of the discrete cash dividends `(time, amount)`, the time in years
+/// * `steps` - The number of time steps in the tree, at least 1
+///
+/// # Returns
+/// * Result of the value of the option, see `binomial_tree`
+///
+/// # Panics
+/// If the slice is shorter than `steps + 1`.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::options::{binomial_tree_into, BlackScholes, ExerciseStyle, OptionType};
+///
+/// let option = BlackScholes::new(OptionType::Put, 50.0, 50.0, 5.0 / 12.0, 0.1, 0.0, 0.4);
+/// let mut buffer = [0.0f64; 101];
+/// let price = binomial_tree_into(&mut buffer, &option, ExerciseStyle::American, &[], 100).unwrap();
+/// assert!((price - 4.28).abs() < 1e-2);
+/// ```
+pub fn binomial_tree_into<T: FloatLike>(
+    slice: &mut [T],
+    option: &BlackScholes<T>,
+    exercise: ExerciseStyle,
+    dividends: &[(T, T)],
+    steps: u32,
+) -> Result<T, FinPrimError<T>> {
+    let steps = steps.max(1) as usize;
+    let values = &mut slice[..=steps];
+    let (sign, spot, dt) = setup(option, dividends, steps as u32);
+    if (option.volatility * dt.sqrt()).is_zero() {
+        values[0] = deterministic_value(option, exercise, spot, sign);
+        return Ok(values[0]);
+    }
+    let up = (option.volatility * dt.sqrt()).exp();
+    let down = T::one() / up;
+    let p = (((option.rate - option.dividend_yield) * dt).exp() - down) / (up - down);
+    if !is_probability(p) {
+        return Err(FinPrimError::NoSolution);
+    }
+    let discount = (-option.rate * dt).exp();
+    let up_squared = up * up;
+
+    let mut node = spot * down.powf(T::from_usize(steps));
+    for value in values.iter_mut() {
+        *value = (sign * (node - option.strike)).max(T::zero());
+        node *= up_squared;
+    }
+    for i in (0..steps).rev() {
+        let dividends_left = dividend_pv(dividends, dt * T::from_usize(i), option.time, option.rate);
+        let mut node = spot * down.powf(T::from_usize(i));
+        for j in 0..=i {
+            let mut value = discount * (p * values[j + 1] + (T::one() - p) * values[j]);
+            if exercise == ExerciseStyle::American {
+                value = value.max(sign * (node + dividends_left - option.strike));
+            }
+            values[j] = value;
+            node *= up_squared;
+        }
+    }
+    Ok(values[0])
+}
+
+/// Trinomial Tree - Boyle trinomial option pricing
+///
+/// Prices a European or American option on a Boyle trinomial tree, with discrete cash dividends. A trinomial tree
+/// converges more smoothly than a binomial tree with the same number of steps.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it uses `std::Vec`. `trinomial_tree_into` can be used
+/// in `no_std` environments as any allocation is done by the caller.
+///
+/// # Arguments
+/// * `option` - The option and its market inputs, the tree uses every field of the `BlackScholes` instance
+/// * `exercise` - Whether the option can be exercised early
+/// * `dividends` - A slice of tuples of the discrete cash dividends `(time, amount)`, the time in years. Dividends
+///   after expiry are ignored.
+/// * `steps` - The number of time steps in the tree, at least 1
+///
+/// # Returns
+/// * Result of the value of the option, the discounted intrinsic value if the volatility or the time to expiry is
+///   zero
+/// * `FinPrimError::NoSolution` if a branch probability is outside 0 to 1, when the drift over a step is larger
+///   than the volatility over a step. More steps shrink the drift relative to the volatility.
+///
+/// # Examples
+/// * 1 year American call struck at 100 on a stock at 100 paying a $5 dividend in 6 months
+/// ```
+/// use rust_finprim::options::{trinomial_tree, BlackScholes, ExerciseStyle, OptionType};
+///
+/// let option = BlackScholes::new(OptionType::Call, 100.0, 100.0, 1.0, 0.05, 0.0, 0.3);
+/// let price: f64 = trinomial_tree(&option, ExerciseStyle::American, &[(0.5, 5.0)], 100).unwrap();
+/// assert!((price - 11.548).abs() < 1e-3);
+/// ```
+///
+/// # Formula
+/// $$u = e^{\sigma \sqrt{2 \Delta t}}, \quad
+/// p_u = \left( \frac{e^{(r - q) \Delta t / 2} - e^{-\sigma \sqrt{\Delta t / 2}}}
+/// {e^{\sigma \sqrt{\Delta t / 2}} - e^{-\sigma \sqrt{\Delta t / 2}}} \right)^2, \quad
+/// p_d = \left( \frac{e^{\sigma \sqrt{\Delta t / 2}} - e^{(r - q) \Delta t / 2}}
+/// {e^{\sigma \sqrt{\Delta t / 2}} - e^{-\sigma \sqrt{\Delta t / 2}}} \right)^2, \quad p_m = 1 - p_u - p_d$$
+///
+/// Discrete dividends are escrowed as in `binomial_tree`.
+#[cfg(feature = "std")]
+pub fn trinomial_tree<T: FloatLike>(
+    option: &BlackScholes<T>,
+    exercise: ExerciseStyle,
+    dividends: &[(T, T)],
+    steps: u32,
+) -> Result<T, FinPrimError<T>> {
+    let steps = steps.max(1);
+    let mut values = vec![T::zero(); 2 * steps as usize + 1];
+    trinomial_tree_into(&mut values, option, exercise, dividends, steps)
+}
+
+/// Trinomial Tree Into - Boyle trinomial option pricing
+///
+/// Prices a European or American option on a Boyle trinomial tree, with discrete cash dividends, using a slice as
+/// the working buffer for the node values. See `trinomial_tree` for details of the tree.
+///
+/// # Arguments
+/// * `slice` - A mutable slice to hold the node values, at least `2 * steps + 1` long. On return its first element
+///   is the value of the option.
+/// * `option` - The option and its market inputs, the tree uses every field of the `BlackScholes` instance
+/// * `exercise` - Whether the option can be exercised early
+/// * `dividends` - A slice of tuples of the discrete cash dividends `(time, amount)`, the time in years
+/// * `steps` - The number of time steps in the tree, at least 1
+///
+/// # Returns
+/// * Result of the value of the option, see `trinomial_tree`
+///
+/// # Panics
+/// If the slice is shorter than `2 * steps + 1`.
+///
+/// # Examples
+/// ```
+/// use rust_finprim::options::{trinomial_tree_into, BlackScholes, ExerciseStyle, OptionType};
+///
+/// let option = BlackScholes::new(OptionType::Put, 50.0, 50.0, 5.0 / 12.0, 0.1, 0.0, 0.4);
+/// let mut buffer = [0.0f64; 201];
+/// let price = trinomial_tree_into(&mut buffer, &option, ExerciseStyle::American, &[], 100).unwrap();
+/// assert!((price - 4.28).abs() < 1e-2);
+/// ```
+pub fn trinomial_tree_into<T: FloatLike>(
+    slice: &mut [T],
+    option: &BlackScholes<T>,
+    exercise: ExerciseStyle,
+    dividends: &[(T, T)],
+    steps: u32,
+) -> Result<T, FinPrimError<T>> {
+    let steps = steps.max(1) as usize;
+    let values = &mut slice[..=2 * steps];
+    let (sign, spot, dt) = setup(option, dividends, steps as u32);
+    if (option.volatility * dt.sqrt()).is_zero() {
+        values[0] = deterministic_value(option, exercise, spot, sign);
+        return Ok(values[0]);
+    }
+    let up = (option.volatility * (T::two() * dt).sqrt()).exp();
+    let down = T::one() / up;
+    let half_up = (option.volatility * (dt / T::two()).sqrt()).exp();
+    let half_down = T::one() / half_up;
+    let growth = ((option.rate - option.dividend_yield) * dt / T::two()).exp();
+    let p_up = ((growth - half_down) / (half_up - half_down)).powf(T::two());
+    let p_down = ((half_up - growth) / (half_up - half_down)).powf(T::two());
+    let p_middle = T::one() - p_up - p_down;
+    if !(is_probability(p_up) && is_probability(p_down) && is_probability(p_middle)) {
+        return Err(FinPrimError::NoSolution);
+    }
+    let discount = (-option.rate * dt).exp();
+
+    let mut node = spot * down.powf(T::from_usize(steps));
+    for value in values.iter_mut() {
+        *value = (sign * (node - option.strike)).max(T::zero());
+        node *= up;
+    }
+    for i in (0..steps).rev() {
+        let dividends_left = dividend_pv(dividends, dt * T::from_usize(i), option.time, option.rate);
+        let mut node = spot * down.powf(T::from_usize(i));
+        for j in 0..=2 * i {
+            let mut value = discount * (p_up * values[j + 2] + p_middle * values[j + 1] + p_down * values[j]);
+            if exercise == ExerciseStyle::American {
+                value = value.max(sign * (node + dividends_left - option.strike));
+            }
+            values[j] = value;
+            node *= up;
+        }
+    }
+    Ok(values[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_trees() {
+        struct TestCase {
+            option: BlackScholes<f64>,
+            exercise: ExerciseStyle,
+            dividends: &'static [(f64, f64)],
+            steps: u32,
+            // Binomial and trinomial values
+            expected: (f64, f64),
+        }
+        impl TestCase {
+            fn new(
+                option: BlackScholes<f64>,
+                exercise: ExerciseStyle,
+                dividends: &'static [(f64, f64)],
+                steps: u32,
+                expected: (f64, f64),
+            ) -> TestCase {
+                TestCase {
+                    option,
+                    exercise,
+                    dividends,
+                    steps,
+                    expected,
+                }
+            }
+        }
+        let put = BlackScholes::new(OptionType::Put, 50.0, 50.0, 5.0 / 12.0, 0.1, 0.0, 0.4);
+        let call = BlackScholes::new(OptionType::Call, 100.0, 100.0, 1.0, 0.05, 0.0, 0.3);
+        let cases = [
+            TestCase::new(
+                put,
+                ExerciseStyle::American,
+                &[],
+                5,
+                (4.488458534725914, 4.15783122052875),
+            ),
+            TestCase::new(
+                put,
+                ExerciseStyle::European,
+                &[],
+                500,
+                (4.073434834660138, 4.074707750032772),
+            ),
+            TestCase::new(
+                put,
+                ExerciseStyle::American,
+                &[],
+                500,
+                (4.283021276450367, 4.2832900682017065),
+            ),
+            // Dividend of $2.06 in 3.5 months
+            TestCase::new(
+                BlackScholes { spot: 52.0, ..put },
+                ExerciseStyle::American,
+                &[(3.5 / 12.0, 2.06)],
+                5,
+                (4.440359507693445, 4.092129552725772),
+            ),
+            TestCase::new(
+                call,
+                ExerciseStyle::American,
+                &[(0.5, 5.0)],
+                100,
+                (11.545461080795514, 11.548104147207638),
+            ),
+            TestCase::new(
+                call,
+                ExerciseStyle::European,
+                &[(0.5, 5.0), (2.0, 5.0)],
+                100,
+                (11.343666770329627, 11.34384539083065),
+            ),
+            // Continuous dividend yield
+            TestCase::new(
+                BlackScholes::new(OptionType::Call, 100.0, 90.0, 1.0, 0.05, 0.08, 0.25),
+                ExerciseStyle::American,
+                &[],
+                50,
+                (13.494946073068434, 13.455479883487126),
+            ),
+        ];
+        for case in &cases {
+            let binomial =
+                binomial_tree_into(&mut [0.0; 501], &case.option, case.exercise, case.dividends, case.steps).unwrap();
+            let trinomial = trinomial_tree_into(
+                &mut [0.0; 1001],
+                &case.option,
+                case.exercise,
+                case.dividends,
+                case.steps,
+            )
+            .unwrap();
+            for (result, expected) in [(binomial, case.expected.0), (trinomial, case.expected.1)] {
+                assert!(
+                    (result - expected).abs() < 1e-9,
+                    "Failed on case: {:?}. Expected: {}, Result: {}",
+                    case.option,
+                    expected,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_trees_converge() {
+        // European values converge to Black-Scholes-Merton, early exercise of a call without dividends is worthless
+        let call = BlackScholes::new(OptionType::Call, 42.0, 40.0, 0.5, 0.1, 0.0, 0.2);
+        let expected = call.price();
+        for exercise in [ExerciseStyle::European, ExerciseStyle::American] {
+            let binomial = binomial_tree_into(&mut [0.0; 1001], &call, exercise, &[], 1000).unwrap();
+            let trinomial = trinomial_tree_into(&mut [0.0; 1001], &call, exercise, &[], 500).unwrap();
+            assert!(
+                (binomial - expected).abs() < 1e-2 && (trinomial - expected).abs() < 1e-2,
+                "Failed on case: {:?}. Expected: {}, Result: {} and {}",
+                exercise,
+                expected,
+                binomial,
+                trinomial
+            );
+        }
+    }
+
+    #[test]
+    fn test_trees_degenerate() {
+        // Zero volatility or time to expiry gives the discounted intrinsic value, or exercising now if more
+        let call = BlackScholes::new(OptionType::Call, 50.0, 50.0, 1.0, 0.1, 0.0, 0.0);
+        let put = BlackScholes {
+            option_type: OptionType::Put,
+            spot: 40.0,
+            ..call
+        };
+        let cases = [
+            (call, ExerciseStyle::European, 50.0 - 50.0 * (-0.1f64).exp()),
+            (put, ExerciseStyle::European, 50.0 * (-0.1f64).exp() - 40.0),
+            (put, ExerciseStyle::American, 10.0),
+            (BlackScholes { time: 0.0, ..put }, ExerciseStyle::European, 10.0),
+        ];
+        for (option, exercise, expected) in cases {
+            let binomial = binomial_tree_into(&mut [0.0; 3], &option, exercise, &[], 2).unwrap();
+            let trinomial = trinomial_tree_into(&mut [0.0; 5], &option, exercise, &[], 2).unwrap();
+            assert!(
+                (binomial - expected).abs() < 1e-12 && (trinomial - expected).abs() < 1e-12,
+                "Failed on case: {:?} {:?}. Expected: {}, Result: {} and {}",
+                option,
+                exercise,
+                expected,
+                binomial,
+                trinomial
+            );
+        }
+
+        // A drift over a step larger than the volatility over a step leaves no valid branch probabilities
+        let put = BlackScholes::new(OptionType::Put, 50.0, 50.0, 1.0, 0.3, 0.0, 0.05);
+        let result = binomial_tree_into(&mut [0.0; 3], &put, ExerciseStyle::European, &[], 2);
+        assert_eq!(result, Err(FinPrimError::NoSolution));
+        let result = trinomial_tree_into(&mut [0.0; 5], &put, ExerciseStyle::European, &[], 2);
+        assert_eq!(result, Err(FinPrimError::NoSolution));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_trees_decimal() {
+        use rust_decimal::Decimal;
+        use rust_decimal_macros::dec;
+
+        let put = BlackScholes::new(
+            OptionType::Put,
+            dec!(50),
+            dec!(50),
+            dec!(1),
+            dec!(0.1),
+            dec!(0),
+            dec!(0),
+        );
+        let binomial = binomial_tree_into(&mut [Decimal::ZERO; 3], &put, ExerciseStyle::American, &[], 2);
+        assert_eq!(binomial, Ok(dec!(0)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_trees_vec() {
+        let put = BlackScholes::new(OptionType::Put, 50.0, 50.0, 5.0 / 12.0, 0.1, 0.0, 0.4);
+        assert!(
+            binomial_tree(&put, ExerciseStyle::American, &[], 5)
+                == binomial_tree_into(&mut [0.0; 6], &put, ExerciseStyle::American, &[], 5)
+        );
+        assert!(
+            trinomial_tree(&put, ExerciseStyle::American, &[], 5)
+                == trinomial_tree_into(&mut [0.0; 11], &put, ExerciseStyle::American, &[], 5)
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! This module contains option pricing models.
+//!
+//! For example, you can price a European call or put under Black-Scholes-Merton and calculate its Greeks, the
+//! sensitivities of its price to the spot, volatility, time and interest rate, price American options on a tree,
+//! or value interest rate caps, floors and swaptions under Black-76 or Bachelier.
+
+// Option Types
+mod option_type;
+pub use option_type::OptionType;
+
+// Black-Scholes-Merton
+mod black_scholes;
+pub use black_scholes::BlackScholes;
+
+// Lattice Models
+mod lattice;
+#[cfg(feature = "std")]
+pub use lattice::{binomial_tree, trinomial_tree};
+pub use lattice::{binomial_tree_into, trinomial_tree_into, ExerciseStyle};
+
+// Options on Forwards
+mod black76;
+pub use black76::Black76;
+mod bachelier;
+pub use bachelier::Bachelier;
+
+// Options on Rates
+mod rates;
+pub use rates::{cap_floor, swaption, VolatilityModel};
@@ -0,0 +1,14 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Option Type
+///
+/// Whether an option gives the right to buy or to sell the underlying at the strike.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OptionType {
+    /// The right to buy the underlying at the strike
+    Call,
+    /// The right to sell the underlying at the strike
+    Put,
+}
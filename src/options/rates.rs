@@ -0,0 +1,370 @@
+use crate::options::{Bachelier, Black76, OptionType};
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Volatility Model
+///
+/// The distribution assumed for a forward rate when pricing options on rates.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VolatilityModel {
+    /// Lognormal forward, priced with `Black76` and quoted as a relative volatility
+    Black,
+    /// Normal forward, priced with `Bachelier` and quoted as an absolute volatility
+    Bachelier,
+}
+
+impl VolatilityModel {
+    /// Price of an option on a forward under the model
+    fn price<T: FloatLike>(
+        &self,
+        option_type: OptionType,
+        forward: T,
+        strike: T,
+        time: T,
+        discount_factor: T,
+        volatility: T,
+    ) -> T {
+        // Periods that have already fixed are worth their intrinsic value
+        let time = time.max(T::zero());
+        match self {
+            VolatilityModel::Black => {
+                Black76::new(option_type, forward, strike, time, discount_factor, volatility).price()
+            }
+            VolatilityModel::Bachelier => {
+                Bachelier::new(option_type, forward, strike, time, discount_factor, volatility).price()
+            }
+        }
+    }
+}
+
+/// Cap Floor - Interest rate cap or floor
+///
+/// Prices an interest rate cap or floor as the sum of its caplets or floorlets. Each period of the schedule is an
+/// option on the simply compounded forward rate over the period, fixing at the start of the period and paid at its
+/// end, with forward rates and discount factors read off a discount factor function.
+///
+/// # Arguments
+/// * `option_type` - `OptionType::Call` for a cap, `OptionType::Put` for a floor
+/// * `model` - The volatility model of the forward rates
+/// * `notional` - The notional amount
+/// * `strike` - The cap or floor rate
+/// * `volatility` - The flat volatility of the forward rates under the model. Under `VolatilityModel::Black` a
+///   caplet with a forward rate at or below zero is worth its intrinsic value.
+/// * `times` - The reset and payment times in years `[t0, t1, ..., tn]`, period `i` runs from `t(i-1)` to `t(i)`.
+///   A period fixing at or before time zero is worth its intrinsic value, start the schedule at the first reset
+///   in the future to leave it out. Periods of zero length, such as from a repeated time, are skipped.
+/// * `discount_factor` - A function returning the discount factor to a time in years, e.g. a closure over
+///   `YieldCurve::discount_factor`
+///
+/// # Returns
+/// * The value of the cap or floor, zero if the schedule has fewer than two times
+///
+/// # Examples
+/// * 2 year quarterly cap at 5% on $1,000,000 with 20% volatility, off a flat 5% continuously compounded curve
+/// ```
+/// use rust_finprim::options::{cap_floor, OptionType, VolatilityModel};
+///
+/// let times = [0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+/// let discount_factor = |t: f64| (-0.05 * t).exp();
+/// let cap = cap_floor(OptionType::Call, VolatilityModel::Black, 1_000_000.0, 0.05, 0.2, &times, discount_factor);
+/// assert!((cap - 6541.29119).abs() < 1e-5);
+/// ```
+///
+/// # Formula
+/// $$V = N \sum_{i=1}^{n} \tau_i \\, \text{Caplet}(F_i, K, t_{i-1}, P(t_i), \sigma), \quad
+/// F_i = \frac{1}{\tau_i} \left( \frac{P(t_{i-1})}{P(t_i)} - 1 \right), \quad \tau_i = t_i - t_{i-1}$$
+///
+/// Where the caplet is the `Black76` or `Bachelier` price of a call (floorlet: put) on the forward rate \\(F_i\\).
+pub fn cap_floor<T: FloatLike, F>(
+    option_type: OptionType,
+    model: VolatilityModel,
+    notional: T,
+    strike: T,
+    volatility: T,
+    times: &[T],
+    discount_factor: F,
+) -> T
+where
+    F: Fn(T) -> T,
+{
+    let value: T = times
+        .windows(2)
+        .filter(|period| period[1] != period[0])
+        .map(|period| {
+            let (start, end) = (period[0], period[1]);
+            let accrual = end - start;
+            let end_discount = discount_factor(end);
+            let forward = (discount_factor(start) / end_discount - T::one()) / accrual;
+            accrual * model.price(option_type, forward, strike, start, end_discount, volatility)
+        })
+        .sum();
+    notional * value
+}
+
+/// Swaption - European swaption
+///
+/// Prices a European option to enter an interest rate swap at expiry, paying or receiving the strike rate against
+/// the floating rate, as an option on the forward swap rate scaled by the annuity of the swap.
+///
+/// # Arguments
+/// * `option_type` - `OptionType::Call` for a payer swaption, `OptionType::Put` for a receiver swaption
+/// * `model` - The volatility model of the forward swap rate
+/// * `notional` - The notional amount of the swap
+/// * `strike` - The fixed rate of the swap
+/// * `volatility` - The volatility of the forward swap rate under the model. Under `VolatilityModel::Black` a
+///   forward swap rate at or below zero gives the intrinsic value.
+/// * `times` - The swap start, which is the expiry of the swaption, followed by the fixed leg payment times in
+///   years `[t0, t1, ..., tn]`
+/// * `discount_factor` - A function returning the discount factor to a time in years, e.g. a closure over
+///   `YieldCurve::discount_factor`
+///
+/// # Returns
+/// * The value of the swaption, zero if the schedule has fewer than two times
+///
+/// # Examples
+/// * 1 year into 3 year annual payer swaption struck at 5% on $1,000,000 with 20% volatility
+/// ```
+/// use rust_finprim::options::{swaption, OptionType, VolatilityModel};
+///
+/// let times = [1.0, 2.0, 3.0, 4.0];
+/// let discount_factor = |t: f64| (-0.05 * t).exp();
+/// let payer = swaption(OptionType::Call, VolatilityModel::Black, 1_000_000.0, 0.05, 0.2, &times, discount_factor);
+/// assert!((payer - 12147.60120).abs() < 1e-5);
+/// ```
+///
+/// # Formula
+/// $$V = N A \\, \text{Option}(S, K, t_0, 1, \sigma), \quad A = \sum_{i=1}^{n} \tau_i P(t_i), \quad
+/// S = \frac{P(t_0) - P(t_n)}{A}$$
+///
+/// Where \\(A\\) is the annuity, \\(S\\) the forward swap rate and the option the `Black76` or `Bachelier` price of a
+/// call (receiver: put) on the forward swap rate.
+pub fn swaption<T: FloatLike, F>(
+    option_type: OptionType,
+    model: VolatilityModel,
+    notional: T,
+    strike: T,
+    volatility: T,
+    times: &[T],
+    discount_factor: F,
+) -> T
+where
+    F: Fn(T) -> T,
+{
+    if times.len() < 2 {
+        return T::zero();
+    }
+    let (start, end) = (times[0], times[times.len() - 1]);
+    let annuity: T = times
+        .windows(2)
+        .map(|period| (period[1] - period[0]) * discount_factor(period[1]))
+        .sum();
+    let swap_rate = (discount_factor(start) - discount_factor(end)) / annuity;
+    notional * annuity * model.price(option_type, swap_rate, strike, start, T::one(), volatility)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_cap_floor() {
+        let times = [0.25, 0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+        let discount_factor = |t: f64| (-0.05 * t).exp();
+        struct TestCase {
+            option_type: OptionType,
+            model: VolatilityModel,
+            volatility: f64,
+            expected: f64,
+        }
+        impl TestCase {
+            fn new(option_type: OptionType, model: VolatilityModel, volatility: f64, expected: f64) -> TestCase {
+                TestCase {
+                    option_type,
+                    model,
+                    volatility,
+                    expected,
+                }
+            }
+        }
+        let cases = [
+            TestCase::new(OptionType::Call, VolatilityModel::Black, 0.2, 6541.291191250785),
+            TestCase::new(OptionType::Put, VolatilityModel::Black, 0.2, 6025.241146813066),
+            TestCase::new(OptionType::Call, VolatilityModel::Bachelier, 0.01, 6533.4431956026465),
+        ];
+        for case in &cases {
+            let result = cap_floor(
+                case.option_type,
+                case.model,
+                1_000_000.0,
+                0.05,
+                case.volatility,
+                &times,
+                discount_factor,
+            );
+            assert!(
+                (result - case.expected).abs() < 1e-6,
+                "Failed on case: {:?} {:?}. Expected: {}, Result: {}",
+                case.option_type,
+                case.model,
+                case.expected,
+                result
+            );
+        }
+
+        // Cap less floor is a payer swap
+        let cap = cap_floor(
+            OptionType::Call,
+            VolatilityModel::Black,
+            1.0,
+            0.05,
+            0.2,
+            &times,
+            discount_factor,
+        );
+        let floor = cap_floor(
+            OptionType::Put,
+            VolatilityModel::Black,
+            1.0,
+            0.05,
+            0.2,
+            &times,
+            discount_factor,
+        );
+        let swap: f64 = discount_factor(0.25)
+            - discount_factor(2.0)
+            - times[1..]
+                .iter()
+                .map(|&t| 0.25 * 0.05 * discount_factor(t))
+                .sum::<f64>();
+        assert!((cap - floor - swap).abs() < 1e-12);
+
+        // A repeated time adds a zero length period, which is skipped rather than dividing by zero
+        let repeated = [0.25, 0.5, 0.75, 1.0, 1.0, 1.25, 1.5, 1.75, 2.0];
+        let result = cap_floor(
+            OptionType::Call,
+            VolatilityModel::Black,
+            1.0,
+            0.05,
+            0.2,
+            &repeated,
+            discount_factor,
+        );
+        assert!((result - cap).abs() < 1e-15, "Expected: {}, Result: {}", cap, result);
+
+        assert_eq!(
+            cap_floor(
+                OptionType::Call,
+                VolatilityModel::Black,
+                1.0,
+                0.05,
+                0.2,
+                &[1.0],
+                discount_factor
+            ),
+            0.0
+        );
+
+        // Negative forward rates under the Black model are worth their intrinsic value
+        let negative = |t: f64| (0.01 * t).exp();
+        let floor = cap_floor(
+            OptionType::Put,
+            VolatilityModel::Black,
+            1.0,
+            0.01,
+            0.2,
+            &times,
+            negative,
+        );
+        let intrinsic: f64 = times
+            .windows(2)
+            .map(|p| 0.25 * negative(p[1]) * (0.01 - (negative(p[0]) / negative(p[1]) - 1.0) / 0.25))
+            .sum();
+        assert!((floor - intrinsic).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_swaption() {
+        let times = [1.0, 2.0, 3.0, 4.0];
+        let discount_factor = |t: f64| (-0.05 * t).exp();
+        let payer = swaption(
+            OptionType::Call,
+            VolatilityModel::Black,
+            1_000_000.0,
+            0.05,
+            0.2,
+            &times,
+            discount_factor,
+        );
+        let receiver = swaption(
+            OptionType::Put,
+            VolatilityModel::Black,
+            1_000_000.0,
+            0.05,
+            0.2,
+            &times,
+            discount_factor,
+        );
+        assert!(
+            (payer - 12147.601198609815).abs() < 1e-6,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "1y into 3y payer",
+            12147.601198609815,
+            payer
+        );
+
+        // Payer less receiver is a forward starting payer swap
+        let swap = 1_000_000.0
+            * (discount_factor(1.0)
+                - discount_factor(4.0)
+                - times[1..].iter().map(|&t| 0.05 * discount_factor(t)).sum::<f64>());
+        assert!((payer - receiver - swap).abs() < 1e-6);
+
+        let bachelier = swaption(
+            OptionType::Call,
+            VolatilityModel::Bachelier,
+            1_000_000.0,
+            0.05,
+            0.01,
+            &times,
+            discount_factor,
+        );
+        assert!((bachelier - 12035.377017654258).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_negative_rates_decimal() {
+        use rust_decimal_macros::dec;
+
+        // Discount factors above one give negative forward rates
+        let times = [dec!(1), dec!(2), dec!(3)];
+        let discount_factor = |t| dec!(1) + dec!(0.01) * t;
+        let cap = cap_floor(
+            OptionType::Call,
+            VolatilityModel::Black,
+            dec!(1),
+            dec!(0.01),
+            dec!(0.2),
+            &times,
+            discount_factor,
+        );
+        assert_eq!(cap, dec!(0));
+        let payer = swaption(
+            OptionType::Call,
+            VolatilityModel::Black,
+            dec!(1),
+            dec!(0.01),
+            dec!(0.2),
+            &times,
+            discount_factor,
+        );
+        assert_eq!(payer, dec!(0));
+    }
+}
@@ -0,0 +1,331 @@
+use crate::utils::{solve, SolverConfig, SolverReport};
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// RATE - Interest Rate per Period of an Annuity
+///
+/// The interest rate per period that equates the present value, the payments and the future value of an
+/// annuity, similar to the Excel `RATE` function.
+/// The `due` parameter expresses whether the annuity type is an ordinary annuity (false and the default) or an annuity due (true),
+/// Excel provides this parameter as `type` with 0 for ordinary annuity and 1 for annuity due.
+///
+/// Uses the same sign conventions as `tvm::pv`, `tvm::fv` and `tvm::pmt`, cash outflows are negative and
+/// cash inflows are positive.
+///
+/// # Arguments
+/// * `nper` - The number of compounding periods
+/// * `pmt` - The payment amount per period
+/// * `pv` - The present value of a series of cash flows or principal amount
+/// * `fv` (optional) - The future value, default is 0
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+/// * `guess` (optional) - A guess for the rate, defaults to 0.1. Providing a guess can help the function converge faster
+/// * `tolerance` (optional) - The tolerance/maximum error bound for the rate calculation, defaults to 1e-5 i.e. 0.00001
+/// * `max_iter` (optional) - The maximum number of iterations to perform, defaults to 20.
+///
+/// # Returns
+/// * Result of the rate calculation
+/// * If the calculation fails, it returns a `FinPrimError::RootFindingError` with the last estimated rate and the
+///   value of the annuity equation at that rate
+///
+/// # Example
+/// * 48 monthly payments of $200 on an $8000 loan
+/// ```
+/// use rust_finprim::rate::rate;
+///
+/// let nper = 48.0; let pmt = -200.0; let pv = 8000.0;
+/// rate(nper, pmt, pv, None, None, None, None, None);
+/// ```
+///
+/// # Formula
+/// The rate is the root of the annuity equation:
+/// $$PV(1+r)^n + PMT(1+rt)\frac{(1+r)^n - 1}{r} + FV = 0$$
+///
+/// Where:
+/// * \\(r\\) = interest rate per period
+/// * \\(n\\) = number of compounding periods
+/// * \\(PMT\\) = payment per period
+/// * \\(PV\\) = present value
+/// * \\(FV\\) = future value
+/// * \\(t\\) = 1 if payments are at the beginning of the period (annuity due), 0 otherwise
+///
+/// This function uses the Halley method with the analytic first and second derivatives of the
+/// annuity equation, maxing out at 20 iterations by default.
+#[allow(clippy::too_many_arguments)]
+pub fn rate<T: FloatLike>(
+    nper: T,
+    pmt: T,
+    pv: T,
+    fv: Option<T>,
+    due: Option<bool>,
+    guess: Option<T>,
+    tolerance: Option<T>,
+    max_iter: Option<u16>,
+) -> Result<T, FinPrimError<T>> {
+    let config = SolverConfig {
+        tolerance: tolerance.unwrap_or(T::from_f32(1e-5)),
+        max_iter: max_iter.unwrap_or(20),
+        fallback: false,
+        ..Default::default()
+    };
+    rate_report(nper, pmt, pv, fv, due, guess, &config).root_or_error()
+}
+
+/// RATE With Config - Interest Rate per Period of an Annuity with solver diagnostics
+///
+/// Calculates the interest rate per period of an annuity like `rate`, with the root finding method and stopping
+/// criteria set by a solver configuration, and reports how the search went.
+///
+/// # Arguments
+/// * `nper` - The number of compounding periods
+/// * `pmt` - The payment amount per period
+/// * `pv` - The present value of a series of cash flows or principal amount
+/// * `fv` (optional) - The future value, default is 0
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+/// * `guess` (optional) - A guess for the rate, defaults to 0.1
+/// * `config` - The solver configuration
+///
+/// # Returns
+/// * Result of the solver report, with the rate as its `root`
+/// * If the solver does not converge, it returns a `FinPrimError::SolverFailed` with the report
+///
+/// # Example
+/// * 48 monthly payments of $200 on an $8000 loan
+/// ```
+/// use rust_finprim::rate::rate_with_config;
+/// use rust_finprim::utils::{SolverConfig, Termination};
+///
+/// let config: SolverConfig<f64> = SolverConfig { tolerance: 1e-9, ..Default::default() };
+/// let report = rate_with_config(48.0, -200.0, 8000.0, None, None, None, &config).unwrap();
+/// assert_eq!(report.reason, Termination::Residual);
+/// assert!((report.root - 0.007701472).abs() < 1e-8);
+/// ```
+pub fn rate_with_config<T: FloatLike>(
+    nper: T,
+    pmt: T,
+    pv: T,
+    fv: Option<T>,
+    due: Option<bool>,
+    guess: Option<T>,
+    config: &SolverConfig<T>,
+) -> Result<SolverReport<T>, FinPrimError<T>> {
+    rate_report(nper, pmt, pv, fv, due, guess, config).check()
+}
+
+fn rate_report<T: FloatLike>(
+    nper: T,
+    pmt: T,
+    pv: T,
+    fv: Option<T>,
+    due: Option<bool>,
+    guess: Option<T>,
+    config: &SolverConfig<T>,
+) -> SolverReport<T> {
+    let fv = fv.unwrap_or(T::zero());
+    let due = if due.unwrap_or(false) { T::one() } else { T::zero() };
+    let guess = guess.unwrap_or(T::from_f32(0.1));
+
+    // f(r) = PV * P(r) + PMT * (1 + r * t) * A(r) + FV
+    // Where P(r) = (1 + r)^n and A(r) = (P(r) - 1) / r is the annuity factor
+    let f = |r: T| {
+        let (p, _, _) = compound_factor(r, nper);
+        let (a, _, _) = annuity_factor(r, nper);
+        pv * p + pmt * (T::one() + r * due) * a + fv
+    };
+    let f_prime = |r: T| {
+        let (_, p1, _) = compound_factor(r, nper);
+        let (a, a1, _) = annuity_factor(r, nper);
+        pv * p1 + pmt * (due * a + (T::one() + r * due) * a1)
+    };
+    let f_prime2 = |r: T| {
+        let (_, _, p2) = compound_factor(r, nper);
+        let (_, a1, a2) = annuity_factor(r, nper);
+        pv * p2 + pmt * (T::two() * due * a1 + (T::one() + r * due) * a2)
+    };
+    solve(f, f_prime, f_prime2, guess, config)
+}
+
+/// Returns \\((1+r)^n\\) and its first and second derivatives with respect to the rate
+#[inline(always)]
+fn compound_factor<T: FloatLike>(rate: T, nper: T) -> (T, T, T) {
+    let one_plus_r = T::one() + rate;
+    let p = one_plus_r.powf(nper);
+    let p1 = nper * p / one_plus_r;
+    let p2 = nper * (nper - T::one()) * p / (one_plus_r * one_plus_r);
+    (p, p1, p2)
+}
+
+/// Returns the annuity factor \\(\frac{(1+r)^n - 1}{r}\\) and its first and second derivatives with respect
+/// to the rate
+///
+/// Close to a zero rate the closed form suffers from catastrophic cancellation, so the binomial expansion
+/// \\(\sum_{k \ge 1} \binom{n}{k} r^{k-1}\\) is used instead.
+#[inline(always)]
+fn annuity_factor<T: FloatLike>(rate: T, nper: T) -> (T, T, T) {
+    let n = nper;
+    if (rate * n).abs() < T::from_f32(1e-3) {
+        let c2 = n * (n - T::one()) / T::two();
+        let c3 = c2 * (n - T::two()) / T::from_u16(3);
+        let c4 = c3 * (n - T::from_u16(3)) / T::from_u16(4);
+        let c5 = c4 * (n - T::from_u16(4)) / T::from_u16(5);
+        let r = rate;
+        let a = n + r * (c2 + r * (c3 + r * (c4 + r * c5)));
+        let a1 = c2 + r * (T::two() * c3 + r * (T::from_u16(3) * c4 + r * T::from_u16(4) * c5));
+        let a2 = T::two() * c3 + r * (T::from_u16(6) * c4 + r * T::from_u16(12) * c5);
+        return (a, a1, a2);
+    }
+    let (p, p1, p2) = compound_factor(rate, nper);
+    let a = (p - T::one()) / rate;
+    let a1 = (p1 - a) / rate;
+    let a2 = (p2 - T::two() * a1) / rate;
+    (a, a1, a2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+
+    #[test]
+    fn test_rate() {
+        struct TestCase {
+            nper: f64,
+            pmt: f64,
+            pv: f64,
+            fv: Option<f64>,
+            due: Option<bool>,
+            expected: f64,
+            description: &'static str,
+        }
+
+        impl TestCase {
+            fn new(
+                nper: f64,
+                pmt: f64,
+                pv: f64,
+                fv: Option<f64>,
+                due: Option<bool>,
+                expected: f64,
+                description: &'static str,
+            ) -> TestCase {
+                TestCase {
+                    nper,
+                    pmt,
+                    pv,
+                    fv,
+                    due,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                48.0,
+                -200.0,
+                8000.0,
+                None,
+                None,
+                0.0077014,
+                "4 year $8000 loan, $200 monthly",
+            ),
+            TestCase::new(
+                360.0,
+                -1073.64324,
+                200_000.0,
+                None,
+                None,
+                0.05 / 12.0,
+                "30 year mortgage at 5%",
+            ),
+            TestCase::new(
+                10.0,
+                -50.0,
+                -1000.0,
+                Some(1000.0),
+                None,
+                -0.05,
+                "Paying $50 a period just to get the principal back is a negative rate",
+            ),
+            TestCase::new(
+                10.0,
+                -100.0,
+                1000.0,
+                None,
+                Some(true),
+                0.0,
+                "Zero interest rate annuity due",
+            ),
+            TestCase::new(
+                10.0,
+                50.0,
+                -1100.0,
+                Some(1000.0),
+                None,
+                0.03781,
+                "10yr 5% coupon bond trading at a premium, what's my YTM?",
+            ),
+        ];
+
+        for case in &cases {
+            let calculated_rate = rate(
+                case.nper,
+                case.pmt,
+                case.pv,
+                case.fv,
+                case.due,
+                None,
+                Some(1e-7),
+                Some(50),
+            )
+            .unwrap_or_else(|e| panic!("Failed on case: {}. {:?}", case.description, e));
+            assert!(
+                (calculated_rate - case.expected).abs() < 1e-5,
+                "Failed on case: {}. Expected {}, got {}",
+                case.description,
+                case.expected,
+                calculated_rate
+            );
+        }
+    }
+
+    #[test]
+    fn test_rate_failed_to_converge() {
+        // Payments and principal with the same sign, no rate can balance the equation
+        let result = rate(10.0, 100.0, 1000.0, None, None, None, None, None);
+        assert!(matches!(result, Err(FinPrimError::RootFindingError(_))));
+    }
+
+    #[test]
+    fn test_rate_with_config() {
+        use crate::utils::SolverMethod;
+
+        let config = SolverConfig {
+            method: SolverMethod::Brent,
+            bounds: Some((0.0, 0.1)),
+            ..Default::default()
+        };
+        let report = rate_with_config(48.0, -200.0, 8000.0, None, None, None, &config).unwrap();
+        let expected = rate(48.0, -200.0, 8000.0, None, None, None, Some(1e-10), None).unwrap();
+        assert!(
+            (report.root - expected).abs() < 1e-9,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "48 payments of 200 on 8000 with Brent",
+            expected,
+            report.root
+        );
+
+        // No rate exists, the report shows where the search stopped
+        let result = rate_with_config(10.0, 100.0, 1000.0, None, None, None, &SolverConfig::default());
+        match result {
+            Err(FinPrimError::SolverFailed(report)) => assert!(!report.reason.converged()),
+            _ => panic!("Expected a solver failure, got {:?}", result),
+        }
+    }
+}
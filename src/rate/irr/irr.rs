@@ -1,9 +1,80 @@
+use crate::dates::{DateLike, DayCount};
 use crate::derivatives::{pv_prime2_r, pv_prime_r};
-use crate::tvm::{npv, xnpv};
-use crate::utils::halley;
+use crate::tvm::{npv, xnpv_with_day_count};
+use crate::utils::{solve, SolverConfig, SolverMethod, SolverReport, Termination};
 use crate::FinPrimError;
 use crate::FloatLike;
 
+/// Solves for an IRR under a solver configuration. Bracketing methods, and the fallback of open methods, search
+/// \\(y = \ln(1 + r)\\), which keeps the rate above -100%. An open method landing on a rate at or below -100%
+/// stops as out of bounds.
+fn solve_irr<T: FloatLike, F, D, D2>(
+    f: F,
+    f_prime: D,
+    f_prime2: D2,
+    guess: T,
+    config: &SolverConfig<T>,
+) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+    D: Fn(T) -> T,
+    D2: Fn(T) -> T,
+{
+    if let Some((lower, upper)) = config.bounds {
+        if lower <= -T::one() || upper <= -T::one() {
+            // The bounds cannot be mapped to ln(1 + IRR), report the guess without searching
+            let root = if guess > -T::one() { guess } else { T::zero() };
+            return SolverReport::new(root, f(root), 0, config.method, Termination::InvalidBracket);
+        }
+    }
+    let to_y = |rate: T| (T::one() + rate).ln();
+    let g = |y: T| f(y.exp() - T::one());
+    let bracketed = |config: &SolverConfig<T>| {
+        let y_config = SolverConfig {
+            bounds: config.bounds.map(|(lower, upper)| (to_y(lower), to_y(upper))),
+            ..*config
+        };
+        let y = if guess > -T::one() { to_y(guess) } else { T::zero() };
+        let report = solve(g, |_| T::zero(), |_| T::zero(), y, &y_config);
+        SolverReport {
+            root: report.root.exp() - T::one(),
+            ..report
+        }
+    };
+    if config.method.is_bracketing() {
+        return bracketed(config);
+    }
+
+    let mut report = solve(
+        &f,
+        f_prime,
+        f_prime2,
+        guess,
+        &SolverConfig {
+            fallback: false,
+            ..*config
+        },
+    );
+    if report.reason.converged() && report.root <= -T::one() {
+        report.reason = Termination::OutOfBounds;
+    }
+    if report.reason.converged() || !config.fallback {
+        return report;
+    }
+    let fallback = bracketed(&SolverConfig {
+        method: SolverMethod::Brent,
+        max_iter: config.max_iter.max(100),
+        ..*config
+    });
+    let iterations = report.iterations.saturating_add(fallback.iterations);
+    if fallback.reason.converged() {
+        SolverReport { iterations, ..fallback }
+    } else {
+        // Report the open method, it carries the last estimate near the guess
+        SolverReport { iterations, ..report }
+    }
+}
+
 /// IRR - Internal Rate of Return
 ///
 /// The internal rate of return (IRR) is a metric used in capital budgeting to estimate the profitability of potential investments.
@@ -42,19 +113,64 @@ use crate::FloatLike;
 /// * \\(IRR\\) = internal rate of return
 ///
 /// This function uses the Halley method to find the root of the NPV formula, maxing out
-/// at 20 iterations.
+/// at 20 iterations. If it fails, or lands on a rate at or below -100%, a bracket is expanded around the guess
+/// and searched with Brent's method, and the Halley error is returned if that fails too.
+///
+/// Cash flows with several sign changes may have several IRRs, this returns one of them. See `irr_all` to find
+/// every IRR in a range.
 pub fn irr<T: FloatLike>(
     cash_flows: &[T],
     guess: Option<T>,
     tolerance: Option<T>,
     max_iter: Option<u16>,
 ) -> Result<T, FinPrimError<T>> {
-    let max_iter = max_iter.unwrap_or(20);
-    let tolerance = tolerance.unwrap_or(T::from_f32(1e-5));
-    let rate = guess.unwrap_or(T::from_f32(0.1));
+    let config = SolverConfig {
+        tolerance: tolerance.unwrap_or(T::from_f32(1e-5)),
+        max_iter: max_iter.unwrap_or(20),
+        ..Default::default()
+    };
+    irr_report(cash_flows, guess, &config).root_or_error()
+}
 
-    // Halley's Method
-    let f = |x: T| npv(x, &cash_flows);
+/// IRR With Config - Internal Rate of Return with solver diagnostics
+///
+/// Calculates the internal rate of return like `irr`, with the root finding method and stopping criteria set by a
+/// solver configuration, and reports how the search went.
+///
+/// Bracketing methods, and the fallback of open methods, search over \\(\ln(1 + IRR)\\) to keep the rate above
+/// -100%. An open method that lands on a rate at or below -100% stops with `Termination::OutOfBounds`.
+///
+/// # Arguments
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+/// * `guess` (optional) - A guess for the IRR, defaults to 0.1
+/// * `config` - The solver configuration. Bounds, if any, must be above -100%.
+///
+/// # Returns
+/// * Result of the solver report, with the IRR as its `root`
+/// * If the solver does not converge, it returns a `FinPrimError::SolverFailed` with the report
+/// * If a bound is at or below -100%, it returns a `FinPrimError::SolverFailed` with a report of
+///   `Termination::InvalidBracket` after no iterations
+///
+/// # Example
+/// ```
+/// use rust_finprim::rate::irr_with_config;
+/// use rust_finprim::utils::{SolverConfig, SolverMethod, SolverReport};
+///
+/// let config = SolverConfig { method: SolverMethod::Brent, bounds: Some((0.0, 1.0)), ..Default::default() };
+/// let report: SolverReport<f64> = irr_with_config(&[-100.0, 50.0, 40.0, 30.0, 20.0], None, &config).unwrap();
+/// assert!((report.root - 0.178047).abs() < 1e-6);
+/// ```
+pub fn irr_with_config<T: FloatLike>(
+    cash_flows: &[T],
+    guess: Option<T>,
+    config: &SolverConfig<T>,
+) -> Result<SolverReport<T>, FinPrimError<T>> {
+    irr_report(cash_flows, guess, config).check()
+}
+
+fn irr_report<T: FloatLike>(cash_flows: &[T], guess: Option<T>, config: &SolverConfig<T>) -> SolverReport<T> {
+    let rate = guess.unwrap_or(T::from_f32(0.1));
+    let f = |x: T| npv(x, cash_flows);
     let f_prime = |x: T| {
         cash_flows
             .iter()
@@ -69,7 +185,7 @@ pub fn irr<T: FloatLike>(
             .map(|(i, &cf)| pv_prime2_r(x, T::from_usize(i), cf))
             .sum()
     };
-    halley(rate, f, f_prime, f_prime2, tolerance, max_iter)
+    solve_irr(f, f_prime, f_prime2, rate, config)
 }
 
 /// XIRR - Internal Rate of Return for Irregular Cash Flows
@@ -78,19 +194,20 @@ pub fn irr<T: FloatLike>(
 ///
 /// # Arguments
 /// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
-/// where `date` represents the number of days from an arbitrary epoch. The first cash flow
+/// where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash flow
 /// is assumed to be the initial investment date, the order of subsequent cash flows does
 /// not matter.
 /// * `guess` (optional) - A guess for the IRR, defaults to 0.1. Providing a guess can help the function converge faster
 /// * `tolerance` (optional) - The tolerance/maximum error bound for the IRR calculation, defaults to 1e-5 i.e. 0.00001
 /// * `max_iter` (optional) - The maximum number of iterations to perform, defaults to 20.
 ///
-/// Most time libraries will provide a method for the number of days from an epoch. For example, in the `chrono` library
-/// you can use the `num_days_from_ce` method to get the number of days from the Common Era (CE) epoch, simply convert
-/// your date types to an integer representing the number of days from any epoch. Alternatively, you can calculate the
-/// time delta in days from an arbitrary epoch, such as the initial investment date.
+/// Dates may be `dates::Date` values or integers representing the number of days from any epoch. Most time libraries
+/// will provide a method for the number of days from an epoch. For example, in the `chrono` library you can use the
+/// `num_days_from_ce` method to get the number of days from the Common Era (CE) epoch. Alternatively, implement the
+/// `dates::DateLike` trait for your date type.
 ///
-/// Cash flows are discounted assuming a 365-day year.
+/// Cash flows are discounted assuming a 365-day year (ACT/365F), see the `_with_day_count` variant for other
+/// day count conventions.
 ///
 /// # Returns
 /// * Result of the IRR calculation
@@ -114,46 +231,158 @@ pub fn irr<T: FloatLike>(
 /// ```
 ///
 /// This function uses the Halley method to find the root of the NPV formula, maxing out
-/// at 20 iterations.
-pub fn xirr<T: FloatLike>(
-    flow_table: &[(T, i32)],
+/// at 20 iterations. If it fails, or lands on a rate at or below -100%, a bracket is expanded around the guess
+/// and searched with Brent's method, and the Halley error is returned if that fails too.
+///
+/// Cash flows with several sign changes may have several IRRs, this returns one of them.
+pub fn xirr<T: FloatLike, D: DateLike>(
+    flow_table: &[(T, D)],
+    guess: Option<T>,
+    tolerance: Option<T>,
+    max_iter: Option<u16>,
+) -> Result<T, FinPrimError<T>> {
+    xirr_with_day_count(flow_table, DayCount::Act365F, guess, tolerance, max_iter)
+}
+
+/// XIRR With Day Count - Internal Rate of Return for Irregular Cash Flows under a day count convention
+///
+/// The XIRR function calculates the internal rate of return for a schedule of cash flows that is not necessarily periodic,
+/// measuring the time between cash flows with the given day count convention.
+///
+/// # Arguments
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or an Excel 1900 date serial (days since 1899-12-30). The first cash flow is
+///   assumed to be the initial investment date, the order of subsequent cash flows does not matter.
+/// * `day_count` - The day count convention used to convert the days between cash flows to years
+/// * `guess` (optional) - A guess for the IRR, defaults to 0.1. Providing a guess can help the function converge faster
+/// * `tolerance` (optional) - The tolerance/maximum error bound for the IRR calculation, defaults to 1e-5 i.e. 0.00001
+/// * `max_iter` (optional) - The maximum number of iterations to perform, defaults to 20.
+///
+/// `DayCount::Act360` and `DayCount::Act365F` only depend on the number of days between the dates, so any epoch
+/// may be used with them.
+///
+/// # Returns
+/// * Result of the IRR calculation
+/// * If the calculation fails, it returns a tuple of the error type with a tuple of the last estimated rate and the NPV at that rate
+///
+/// # Example
+/// * Cash flows of $-100, $50, $60 on 2024-01-01, 2024-07-01, 2025-01-01
+/// ```
+/// use rust_finprim::dates::DayCount;
+/// use rust_finprim::rate::xirr_with_day_count;
+///
+/// let flow_table = vec![
+///    (-100.0, 45292),
+///    (50.0, 45474),
+///    (60.0, 45658),
+/// ];
+/// xirr_with_day_count(&flow_table, DayCount::ActActIsda, None, None, None);
+/// ```
+///
+/// This function uses the Halley method to find the root of the NPV formula, maxing out
+/// at 20 iterations. If it fails, or lands on a rate at or below -100%, a bracket is expanded around the guess
+/// and searched with Brent's method, and the Halley error is returned if that fails too.
+///
+/// Cash flows with several sign changes may have several IRRs, this returns one of them.
+pub fn xirr_with_day_count<T: FloatLike, D: DateLike>(
+    flow_table: &[(T, D)],
+    day_count: DayCount,
     guess: Option<T>,
     tolerance: Option<T>,
     max_iter: Option<u16>,
 ) -> Result<T, FinPrimError<T>> {
-    let max_iter = max_iter.unwrap_or(20);
-    let tolerance = tolerance.unwrap_or(T::from_f32(1e-5));
+    let config = SolverConfig {
+        tolerance: tolerance.unwrap_or(T::from_f32(1e-5)),
+        max_iter: max_iter.unwrap_or(20),
+        ..Default::default()
+    };
+    xirr_report(flow_table, day_count, guess, &config).root_or_error()
+}
+
+/// XIRR With Config - Internal Rate of Return for Irregular Cash Flows with solver diagnostics
+///
+/// Calculates the internal rate of return of irregular cash flows like `xirr_with_day_count`, with the root
+/// finding method and stopping criteria set by a solver configuration, and reports how the search went. The search
+/// keeps the rate above -100% as in `irr_with_config`.
+///
+/// # Arguments
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`,
+///   see `xirr_with_day_count`
+/// * `day_count` - The day count convention used to convert the days between cash flows to years, `xirr` uses
+///   `DayCount::Act365F`
+/// * `guess` (optional) - A guess for the IRR, defaults to 0.1
+/// * `config` - The solver configuration. Bounds, if any, must be above -100%.
+///
+/// # Returns
+/// * Result of the solver report, with the IRR as its `root`
+/// * If the solver does not converge, it returns a `FinPrimError::SolverFailed` with the report
+/// * If a bound is at or below -100%, it returns a `FinPrimError::SolverFailed` with a report of
+///   `Termination::InvalidBracket` after no iterations
+///
+/// # Example
+/// ```
+/// use rust_finprim::dates::DayCount;
+/// use rust_finprim::rate::xirr_with_config;
+/// use rust_finprim::utils::{SolverConfig, SolverMethod, Termination};
+/// use rust_finprim::FinPrimError;
+///
+/// let flow_table: [(f64, i32); 5] = [(-100.0, 0), (50.0, 359), (40.0, 400), (30.0, 1000), (20.0, 2000)];
+/// let config = SolverConfig { method: SolverMethod::Brent, bounds: Some((0.0, 1.0)), ..Default::default() };
+/// let report = xirr_with_config(&flow_table, DayCount::Act365F, None, &config).unwrap();
+/// assert!((report.root - 0.20084).abs() < 1e-5);
+///
+/// // Too few iterations without a fallback
+/// let config = SolverConfig { max_iter: 1, fallback: false, ..Default::default() };
+/// match xirr_with_config(&flow_table, DayCount::Act365F, None, &config) {
+///     Err(FinPrimError::SolverFailed(report)) => assert_eq!(report.reason, Termination::MaxIterations),
+///     result => panic!("Expected a solver failure, got {:?}", result),
+/// }
+/// ```
+pub fn xirr_with_config<T: FloatLike, D: DateLike>(
+    flow_table: &[(T, D)],
+    day_count: DayCount,
+    guess: Option<T>,
+    config: &SolverConfig<T>,
+) -> Result<SolverReport<T>, FinPrimError<T>> {
+    xirr_report(flow_table, day_count, guess, config).check()
+}
+
+fn xirr_report<T: FloatLike, D: DateLike>(
+    flow_table: &[(T, D)],
+    day_count: DayCount,
+    guess: Option<T>,
+    config: &SolverConfig<T>,
+) -> SolverReport<T> {
     // First date should be 0 (initial investment) and the rest should be difference from the initial date
     let init_date = flow_table.first().unwrap().1;
     let rate = guess.unwrap_or(T::from_f32(0.1));
 
-    // Halley's Method
-    let f = |x: T| xnpv(x, &flow_table);
+    let f = |x: T| xnpv_with_day_count(x, flow_table, day_count);
     let f_prime = |x: T| {
         flow_table
             .iter()
-            .map(|&(cf, date)| pv_prime_r(x, T::from_i32(date - init_date) / T::from_u16(365), cf))
+            .map(|&(cf, date)| pv_prime_r(x, day_count.year_fraction(init_date, date), cf))
             .sum::<T>()
     };
     let f_prime2 = |x: T| {
         flow_table
             .iter()
-            .map(|&(cf, date)| pv_prime2_r(x, T::from_i32(date - init_date) / T::from_u16(365), cf))
+            .map(|&(cf, date)| pv_prime2_r(x, day_count.year_fraction(init_date, date), cf))
             .sum::<T>()
     };
 
-    // Halley's method
-    halley(rate, f, f_prime, f_prime2, tolerance, max_iter)
+    solve_irr(f, f_prime, f_prime2, rate, config)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::{SolverMethod, Termination};
 
     #[cfg(not(feature = "std"))]
     extern crate std;
     #[cfg(not(feature = "std"))]
-    use std::{assert, vec};
+    use std::{assert, assert_eq, vec};
 
     #[test]
     fn test_irr() {
@@ -168,6 +397,139 @@ mod tests {
             expected,
             result.unwrap()
         );
+
+        // Halley's method diverges from a guess near -100%, the bracketed fallback does not
+        let mut cash_flows = vec![-100.0];
+        cash_flows.extend([12.0; 30]);
+        let result = irr(&cash_flows, Some(-0.9), Some(1e-9), None).unwrap();
+        let expected = 0.1154777581;
+        assert!(
+            (result - expected).abs() < 1e-9,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Cash flows of -100 and 30 x 12 from a guess of -0.9",
+            expected,
+            result
+        );
+
+        // Halley's method converges to a root at -236.5% from a guess of -300%
+        let result = irr(&[-100.0, 10.0, 200.0], Some(-3.0), Some(1e-9), None).unwrap();
+        let expected = 0.4650972;
+        assert!(
+            (result - expected).abs() < 1e-6,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Cash flows of -100, 10, 200 from a guess of -3",
+            expected,
+            result
+        );
+    }
+
+    #[test]
+    fn test_irr_with_config() {
+        let cash_flows = [-100.0, 50.0, 40.0, 30.0, 1000.0];
+        let expected = 1.008240536;
+        let methods = [
+            SolverMethod::NewtonRaphson,
+            SolverMethod::Halley,
+            SolverMethod::Bisection,
+            SolverMethod::Brent,
+            SolverMethod::Illinois,
+        ];
+        for method in methods {
+            let config = SolverConfig {
+                method,
+                ..Default::default()
+            };
+            let report = irr_with_config(&cash_flows, None, &config).unwrap();
+            assert!(
+                (report.root - expected).abs() < 1e-8,
+                "Failed on case: {:?}. Expected: {}, Result: {}",
+                method,
+                expected,
+                report.root
+            );
+        }
+
+        // Too few iterations without a fallback fails with the report, the legacy function with a root finding error
+        let config = SolverConfig {
+            max_iter: 2,
+            fallback: false,
+            ..Default::default()
+        };
+        let result = irr_with_config(&cash_flows, None, &config);
+        assert!(matches!(
+            result,
+            Err(FinPrimError::SolverFailed(SolverReport {
+                iterations: 2,
+                reason: Termination::MaxIterations,
+                ..
+            }))
+        ));
+
+        // The fallback takes over and counts its iterations on top
+        let report = irr_with_config(
+            &cash_flows,
+            None,
+            &SolverConfig {
+                fallback: true,
+                ..config
+            },
+        )
+        .unwrap();
+        assert_eq!(report.method, SolverMethod::Brent);
+        assert!(report.iterations > 2 && (report.root - expected).abs() < 1e-8);
+
+        // Bounds that exclude the IRR
+        let config = SolverConfig {
+            method: SolverMethod::Brent,
+            bounds: Some((0.0, 0.5)),
+            ..Default::default()
+        };
+        let result = irr_with_config(&cash_flows, None, &config);
+        assert!(matches!(
+            result,
+            Err(FinPrimError::SolverFailed(SolverReport {
+                reason: Termination::InvalidBracket,
+                ..
+            }))
+        ));
+
+        // Bounds at or below -100% cannot be searched
+        for method in [SolverMethod::Brent, SolverMethod::Halley] {
+            let config = SolverConfig {
+                method,
+                bounds: Some((-1.5, 1.0)),
+                ..Default::default()
+            };
+            let result = irr_with_config(&cash_flows, None, &config);
+            assert!(matches!(
+                result,
+                Err(FinPrimError::SolverFailed(SolverReport {
+                    iterations: 0,
+                    reason: Termination::InvalidBracket,
+                    ..
+                }))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_xirr_with_config() {
+        let flow_table = vec![(-100.0, 0), (50.0, 359), (40.0, 400), (30.0, 1000), (20.0, 2000)];
+        let config = SolverConfig {
+            method: SolverMethod::Illinois,
+            bounds: Some((0.0, 1.0)),
+            ..Default::default()
+        };
+        let report = xirr_with_config(&flow_table, DayCount::Act365F, None, &config).unwrap();
+        let expected = xirr(&flow_table, None, Some(1e-10), None).unwrap();
+        assert!(
+            (report.root - expected).abs() < 1e-8,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Cash flows of -100, 50, 40, 30, 20 with Illinois",
+            expected,
+            report.root
+        );
+        assert_eq!(report.reason, Termination::Residual);
     }
 
     #[test]
@@ -183,4 +545,40 @@ mod tests {
             xirr.unwrap()
         );
     }
+
+    #[test]
+    fn test_xirr_with_day_count() {
+        let flow_table = vec![(-100.0, 0), (50.0, 359), (40.0, 400), (30.0, 1000), (20.0, 2000)];
+        let result = xirr_with_day_count(&flow_table, DayCount::Act365F, None, Some(1e-5), None);
+        assert_eq!(result, xirr(&flow_table, None, Some(1e-5), None));
+
+        // A year and a half later on 2025-07-01 under 30/360
+        let flow_table = vec![(-100.0, 45292), (121.0, 45839)];
+        let result = xirr_with_day_count(&flow_table, DayCount::Thirty360, None, Some(1e-9), None).unwrap();
+        let expected: f64 = 1.21f64.powf(1.0 / 1.5) - 1.0;
+        assert!(
+            (result - expected).abs() < 1e-5,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Single cash flow after exactly 1.5 years under 30/360",
+            expected,
+            result
+        );
+    }
+
+    #[test]
+    fn test_xirr_dates() {
+        use crate::dates::Date;
+
+        let start = Date::new(2024, 1, 1).unwrap();
+        let flow_table = vec![
+            (-100.0, start),
+            (50.0, start.add_months(6)),
+            (60.0, start.add_months(12)),
+        ];
+        let serial_table = vec![(-100.0, 45292), (50.0, 45474), (60.0, 45658)];
+        assert_eq!(
+            xirr(&flow_table, None, Some(1e-9), None),
+            xirr(&serial_table, None, Some(1e-9), None)
+        );
+    }
 }
@@ -0,0 +1,443 @@
+use crate::utils::{brent_report, SolverConfig, SolverMethod, SolverReport};
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// Counts the sign changes of a sequence, skipping zeros
+fn count_sign_changes<T: FloatLike, I: Iterator<Item = T>>(values: I) -> usize {
+    let mut changes = 0;
+    let mut last_negative = None;
+    for value in values.filter(|v| !v.is_zero()) {
+        let negative = value < T::zero();
+        if last_negative.is_some_and(|last| last != negative) {
+            changes += 1;
+        }
+        last_negative = Some(negative);
+    }
+    changes
+}
+
+/// Sign Changes - Descartes' rule of signs
+///
+/// Counts the sign changes in a series of cash flows, ignoring zero cash flows. By Descartes' rule of signs, the
+/// number of IRRs above -100% is at most the number of sign changes, and differs from it by an even number.
+/// Conventional cash flows, an investment followed by returns, have one sign change and exactly one IRR.
+///
+/// # Arguments
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+///
+/// # Returns
+/// * The number of sign changes
+///
+/// # Example
+/// ```
+/// use rust_finprim::rate::sign_changes;
+///
+/// assert_eq!(sign_changes(&[-100.0, 230.0, 0.0, -132.0]), 2);
+/// ```
+pub fn sign_changes<T: FloatLike>(cash_flows: &[T]) -> usize {
+    count_sign_changes(cash_flows.iter().copied())
+}
+
+/// Cumulative Sign Changes - Norstrom's criterion
+///
+/// Counts the sign changes in the running total of a series of cash flows, ignoring zero totals. By Norstrom's
+/// criterion, if the running total starts negative, changes sign exactly once and ends non-zero, there is exactly one
+/// positive IRR, even when the cash flows themselves change sign several times.
+///
+/// # Arguments
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+///
+/// # Returns
+/// * The number of sign changes of the cumulative cash flows
+///
+/// # Example
+/// * Three sign changes in the cash flows, but one in the cumulative cash flows
+/// ```
+/// use rust_finprim::rate::{cumulative_sign_changes, sign_changes};
+///
+/// let cash_flows = [-100.0, 150.0, -20.0, 10.0];
+/// assert_eq!(sign_changes(&cash_flows), 3);
+/// assert_eq!(cumulative_sign_changes(&cash_flows), 1);
+/// ```
+pub fn cumulative_sign_changes<T: FloatLike>(cash_flows: &[T]) -> usize {
+    let mut total = T::zero();
+    count_sign_changes(cash_flows.iter().map(|&cf| {
+        total += cf;
+        total
+    }))
+}
+
+/// The NPV at rates above 0%, and the NPV compounded to the last cash flow \\(\sum_t CF_t (1+r)^{n-t}\\) at or below
+/// 0%. Both have the sign and the roots of the NPV and are bounded by the sum of the absolute cash flows, where the NPV
+/// itself grows without bound near -100%.
+fn scaled_npv<T: FloatLike>(rate: T, cash_flows: &[T]) -> T {
+    let growth = T::one() + rate;
+    if growth > T::one() {
+        let discount = T::one() / growth;
+        cash_flows.iter().rev().fold(T::zero(), |acc, &cf| acc * discount + cf)
+    } else {
+        cash_flows.iter().fold(T::zero(), |acc, &cf| acc * growth + cf)
+    }
+}
+
+/// Records a root in the slice if there is room for it
+fn record<T: FloatLike>(slice: &mut [T], found: &mut usize, root: T) {
+    if let Some(slot) = slice.get_mut(*found) {
+        *slot = root;
+    }
+    *found += 1;
+}
+
+/// IRR All - Every Internal Rate of Return in a range
+///
+/// Finds every IRR of a series of cash flows within a range of rates. The range is scanned on an even grid for
+/// sign changes of the NPV, and each bracket found is solved with Brent's method. The scan stops early once as many
+/// IRRs as cash flow sign changes have been found, as Descartes' rule of signs allows no more.
+///
+/// At or below 0% the NPV compounded to the last cash flow, \\(\sum_t CF_t (1+r)^{n-t}\\), is used in place of the
+/// NPV. It has the same roots and stays finite near -100%, where the NPV overflows.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it returns a `Vec`. See `irr_all_into` for a version
+/// that writes to a slice.
+///
+/// # Arguments
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+/// * `range` (optional) - The `(lower, upper)` range of rates to scan, defaults to (-0.99, 1.0). The lower rate must
+///   be above -100%.
+/// * `steps` (optional) - The number of grid intervals the range is split into, defaults to 1000
+/// * `tolerance` (optional) - The tolerance/maximum error bound for the NPV at each IRR, or the compounded NPV at or
+///   below 0%, defaults to 1e-10
+///
+/// # Returns
+/// * Result of the IRRs found in increasing order, empty if there is none in the range
+/// * `FinPrimError::InvalidArgument` if the lower rate of the range is at or below -100%
+/// * If solving a bracket fails, it returns the root finding error of `utils::brent`
+///
+/// Two IRRs closer together than a grid interval, and IRRs where the NPV touches zero without changing sign, are not
+/// found. Increase `steps` to resolve close IRRs.
+///
+/// # Example
+/// * Cash flows of $-100, $230, $-132 have IRRs of 10% and 20%
+/// ```
+/// use rust_finprim::rate::irr_all;
+///
+/// let rates: Vec<f64> = irr_all(&[-100.0, 230.0, -132.0], None, None, None).unwrap();
+/// assert_eq!(rates.len(), 2);
+/// assert!((rates[0] - 0.1).abs() < 1e-9 && (rates[1] - 0.2).abs() < 1e-9);
+/// ```
+#[cfg(feature = "std")]
+pub fn irr_all<T: FloatLike>(
+    cash_flows: &[T],
+    range: Option<(T, T)>,
+    steps: Option<u16>,
+    tolerance: Option<T>,
+) -> Result<Vec<T>, FinPrimError<T>> {
+    let mut rates = vec![T::zero(); sign_changes(cash_flows)];
+    let found = irr_all_into(&mut rates, cash_flows, range, steps, tolerance)?;
+    rates.truncate(found);
+    Ok(rates)
+}
+
+/// IRR All Into - Every Internal Rate of Return in a range
+///
+/// Finds every IRR of a series of cash flows within a range of rates, writing them to a slice. See `irr_all` for
+/// details of the search.
+///
+/// # Arguments
+/// * `slice` - A mutable slice to write the IRRs to in increasing order, at most `sign_changes(cash_flows)` are found
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+/// * `range` (optional) - The `(lower, upper)` range of rates to scan, defaults to (-0.99, 1.0). The lower rate must
+///   be above -100%.
+/// * `steps` (optional) - The number of grid intervals the range is split into, defaults to 1000
+/// * `tolerance` (optional) - The tolerance/maximum error bound for the NPV at each IRR, or the compounded NPV at or
+///   below 0%, defaults to 1e-10
+///
+/// # Returns
+/// * Result of the number of IRRs found. If it is larger than the slice, only the first IRRs are written.
+/// * `FinPrimError::InvalidArgument` if the lower rate of the range is at or below -100%
+/// * If solving a bracket fails, it returns the root finding error of `utils::brent`
+///
+/// # Example
+/// ```
+/// use rust_finprim::rate::irr_all_into;
+///
+/// let mut rates = [0.0; 2];
+/// let found = irr_all_into(&mut rates, &[-100.0, 230.0, -132.0], None, None, None).unwrap();
+/// assert_eq!(found, 2);
+/// ```
+pub fn irr_all_into<T: FloatLike>(
+    slice: &mut [T],
+    cash_flows: &[T],
+    range: Option<(T, T)>,
+    steps: Option<u16>,
+    tolerance: Option<T>,
+) -> Result<usize, FinPrimError<T>> {
+    let range = range.unwrap_or((T::from_f32(-0.99), T::one()));
+    if range.0 <= -T::one() {
+        return Err(FinPrimError::InvalidArgument { name: "range" });
+    }
+    let tolerance = tolerance.unwrap_or(T::from_f32(1e-10));
+    let config = SolverConfig {
+        method: SolverMethod::Brent,
+        tolerance,
+        step_tolerance: tolerance,
+        max_iter: 100,
+        ..Default::default()
+    };
+    scan(slice, cash_flows, range, steps, &config, SolverReport::root_or_error)
+}
+
+/// IRR All With Config - Every Internal Rate of Return in a range with a solver configuration
+///
+/// Finds every IRR of a series of cash flows like `irr_all`, solving each bracket with Brent's method under the
+/// tolerances and maximum iterations of a solver configuration.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it returns a `Vec`. See `irr_all_into_with_config`
+/// for a version that writes to a slice.
+///
+/// # Arguments
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+/// * `steps` (optional) - The number of grid intervals the range is split into, defaults to 1000
+/// * `config` - The solver configuration. Its bounds are the range of rates to scan, defaults to (-0.99, 1.0), and
+///   the lower bound must be above -100%. The method and fallback are ignored.
+///
+/// # Returns
+/// * Result of the IRRs found in increasing order, empty if there is none in the range
+/// * `FinPrimError::InvalidArgument` if the lower bound is at or below -100%
+/// * If solving a bracket fails, it returns a `FinPrimError::SolverFailed` with the report of that bracket
+///
+/// # Example
+/// ```
+/// use rust_finprim::rate::irr_all_with_config;
+/// use rust_finprim::utils::SolverConfig;
+///
+/// let config = SolverConfig { tolerance: 1e-12, max_iter: 50, ..Default::default() };
+/// let rates: Vec<f64> = irr_all_with_config(&[-100.0, 230.0, -132.0], None, &config).unwrap();
+/// assert!((rates[0] - 0.1).abs() < 1e-12 && (rates[1] - 0.2).abs() < 1e-12);
+/// ```
+#[cfg(feature = "std")]
+pub fn irr_all_with_config<T: FloatLike>(
+    cash_flows: &[T],
+    steps: Option<u16>,
+    config: &SolverConfig<T>,
+) -> Result<Vec<T>, FinPrimError<T>> {
+    let mut rates = vec![T::zero(); sign_changes(cash_flows)];
+    let found = irr_all_into_with_config(&mut rates, cash_flows, steps, config)?;
+    rates.truncate(found);
+    Ok(rates)
+}
+
+/// IRR All Into With Config - Every Internal Rate of Return in a range with a solver configuration
+///
+/// Finds every IRR of a series of cash flows like `irr_all_with_config`, writing them to a slice.
+///
+/// # Arguments
+/// * `slice` - A mutable slice to write the IRRs to in increasing order, at most `sign_changes(cash_flows)` are found
+/// * `cash_flows` - A slice of values representing the cash flows of the investment
+/// * `steps` (optional) - The number of grid intervals the range is split into, defaults to 1000
+/// * `config` - The solver configuration. Its bounds are the range of rates to scan, defaults to (-0.99, 1.0), and
+///   the lower bound must be above -100%. The method and fallback are ignored.
+///
+/// # Returns
+/// * Result of the number of IRRs found. If it is larger than the slice, only the first IRRs are written.
+/// * `FinPrimError::InvalidArgument` if the lower bound is at or below -100%
+/// * If solving a bracket fails, it returns a `FinPrimError::SolverFailed` with the report of that bracket
+pub fn irr_all_into_with_config<T: FloatLike>(
+    slice: &mut [T],
+    cash_flows: &[T],
+    steps: Option<u16>,
+    config: &SolverConfig<T>,
+) -> Result<usize, FinPrimError<T>> {
+    let range = config.bounds.unwrap_or((T::from_f32(-0.99), T::one()));
+    if range.0 <= -T::one() {
+        return Err(FinPrimError::InvalidArgument { name: "bounds" });
+    }
+    scan(slice, cash_flows, range, steps, config, |report| {
+        report.check().map(|report| report.root)
+    })
+}
+
+/// Scans the range for sign changes of the NPV and solves each bracket with Brent's method, turning the report of
+/// each bracket into its root or an error with `finish`
+fn scan<T: FloatLike>(
+    slice: &mut [T],
+    cash_flows: &[T],
+    (lower, upper): (T, T),
+    steps: Option<u16>,
+    config: &SolverConfig<T>,
+    finish: fn(SolverReport<T>) -> Result<T, FinPrimError<T>>,
+) -> Result<usize, FinPrimError<T>> {
+    let steps = steps.unwrap_or(1000).max(1);
+    let max_roots = sign_changes(cash_flows);
+    let f = |rate: T| scaled_npv(rate, cash_flows);
+    let width = (upper - lower) / T::from_u16(steps);
+
+    let mut found = 0;
+    let mut a = lower;
+    let mut fa = f(a);
+    for i in 1..=steps {
+        if found >= max_roots {
+            return Ok(found);
+        }
+        if fa.is_zero() {
+            record(slice, &mut found, a);
+        }
+        let b = if i == steps {
+            upper
+        } else {
+            lower + width * T::from_u16(i)
+        };
+        let fb = f(b);
+        if fa * fb < T::zero() {
+            record(slice, &mut found, finish(brent_report(a, b, f, config))?);
+        }
+        a = b;
+        fa = fb;
+    }
+    if fa.is_zero() && found < max_roots {
+        record(slice, &mut found, a);
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Termination;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_sign_changes() {
+        struct TestCase {
+            cash_flows: &'static [f64],
+            changes: usize,
+            cumulative: usize,
+        }
+        impl TestCase {
+            fn new(cash_flows: &'static [f64], changes: usize, cumulative: usize) -> TestCase {
+                TestCase {
+                    cash_flows,
+                    changes,
+                    cumulative,
+                }
+            }
+        }
+        let cases = [
+            TestCase::new(&[-100.0, 50.0, 40.0, 30.0, 20.0], 1, 1),
+            TestCase::new(&[-100.0, 230.0, -132.0], 2, 2),
+            TestCase::new(&[-100.0, 0.0, 150.0, -20.0, 10.0], 3, 1),
+            TestCase::new(&[0.0, 0.0], 0, 0),
+        ];
+        for case in &cases {
+            assert_eq!(
+                (sign_changes(case.cash_flows), cumulative_sign_changes(case.cash_flows)),
+                (case.changes, case.cumulative),
+                "Failed on case: {:?}",
+                case.cash_flows
+            );
+        }
+    }
+
+    #[test]
+    fn test_irr_all_into() {
+        struct TestCase {
+            cash_flows: &'static [f64],
+            expected: &'static [f64],
+        }
+        impl TestCase {
+            fn new(cash_flows: &'static [f64], expected: &'static [f64]) -> TestCase {
+                TestCase { cash_flows, expected }
+            }
+        }
+        let cases = [
+            TestCase::new(&[-100.0, 230.0, -132.0], &[0.1, 0.2]),
+            TestCase::new(&[-1000.0, 3050.0, -3050.0, 1000.0], &[-0.2, 0.0, 0.25]),
+            // Two sign changes and no IRR
+            TestCase::new(&[100.0, -300.0, 250.0], &[]),
+            // Conventional cash flows
+            TestCase::new(&[-100.0, 110.0], &[0.1]),
+        ];
+        for case in &cases {
+            let mut rates = [0.0; 3];
+            let found = irr_all_into(&mut rates, case.cash_flows, None, None, None).unwrap();
+            assert_eq!(found, case.expected.len(), "Failed on case: {:?}", case.cash_flows);
+            for (result, expected) in rates.iter().zip(case.expected) {
+                assert!(
+                    (result - expected).abs() < 1e-9,
+                    "Failed on case: {:?}. Expected: {}, Result: {}",
+                    case.cash_flows,
+                    expected,
+                    result
+                );
+            }
+        }
+
+        // Out of range
+        let found = irr_all_into(&mut [0.0; 2], &[-100.0, 230.0, -132.0], Some((0.15, 1.0)), None, None).unwrap();
+        assert_eq!(found, 1);
+
+        // A range reaching -100%
+        let result = irr_all_into(&mut [0.0; 2], &[-100.0, 230.0, -132.0], Some((-1.0, 1.0)), None, None);
+        assert_eq!(result, Err(FinPrimError::InvalidArgument { name: "range" }));
+    }
+
+    #[test]
+    fn test_irr_all_into_with_config() {
+        let cash_flows = [-100.0, 230.0, -132.0];
+        let mut rates = [0.0; 2];
+        let config = SolverConfig {
+            tolerance: 1e-12,
+            bounds: Some((0.15, 1.0)),
+            ..Default::default()
+        };
+        let found = irr_all_into_with_config(&mut rates, &cash_flows, None, &config).unwrap();
+        assert_eq!(found, 1);
+        assert!((rates[0] - 0.2).abs() < 1e-12, "Result: {}", rates[0]);
+
+        // Too few iterations to solve a bracket
+        let config = SolverConfig {
+            tolerance: 1e-15,
+            max_iter: 1,
+            ..Default::default()
+        };
+        let result = irr_all_into_with_config(&mut rates, &cash_flows, None, &config);
+        assert!(
+            matches!(result, Err(FinPrimError::SolverFailed(report)) if report.reason == Termination::MaxIterations),
+            "Result: {:?}",
+            result
+        );
+
+        // Bounds reaching -100%
+        let config = SolverConfig {
+            bounds: Some((-1.0, 1.0)),
+            ..Default::default()
+        };
+        let result = irr_all_into_with_config(&mut rates, &cash_flows, None, &config);
+        assert_eq!(result, Err(FinPrimError::InvalidArgument { name: "bounds" }));
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_irr_all_into_decimal() {
+        use rust_decimal::Decimal;
+        use rust_decimal_macros::dec;
+
+        // The NPV of 31 cash flows overflows a Decimal near -100%
+        let mut cash_flows = [dec!(12); 31];
+        cash_flows[0] = dec!(-100);
+        let mut rates = [Decimal::ZERO; 1];
+        let found = irr_all_into(&mut rates, &cash_flows, None, None, None).unwrap();
+        assert_eq!(found, 1);
+        assert!(
+            (rates[0] - dec!(0.1154777581)).abs() < dec!(1e-9),
+            "Result: {}",
+            rates[0]
+        );
+    }
+}
@@ -1,4 +1,9 @@
 // Private module for irr
 
 mod irr;
-pub use irr::{irr, xirr};
+pub use irr::{irr, irr_with_config, xirr, xirr_with_config, xirr_with_day_count};
+
+mod irr_all;
+pub use irr_all::{cumulative_sign_changes, irr_all_into, irr_all_into_with_config, sign_changes};
+#[cfg(feature = "std")]
+pub use irr_all::{irr_all, irr_all_with_config};
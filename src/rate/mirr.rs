@@ -1,3 +1,4 @@
+use crate::dates::{DateLike, DayCount};
 use crate::rate::cagr;
 use crate::tvm::{fv, pv};
 use crate::FloatLike;
@@ -59,17 +60,18 @@ pub fn mirr<T: FloatLike>(cash_flows: &[T], finance_rate: T, reinvest_rate: T) -
 ///
 /// # Arguments
 /// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
-/// where `date` represents the number of days from an arbitrary epoch. The first cash flow is assumed to be the initial investment date
+/// where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash flow is assumed to be the initial investment date
 /// at time 0, the order of subsequent cash flows does not matter.
 /// * `finance_rate` - The cost of capital (interest rate) for financing
 /// * `reinvest_rate` - The reinvestment rate for positive cash flows
 ///
-/// Most time libraries will provide a method for the number of days from an epoch. For example, in the `chrono` library
-/// you can use the `num_days_from_ce` method to get the number of days from the Common Era (CE) epoch, simply convert
-/// your date types to an integer representing the number of days from any epoch. Alternatively, you can calculate the
-/// time delta in days from an arbitrary epoch, such as the initial investment date.
+/// Dates may be `dates::Date` values or integers representing the number of days from any epoch. Most time libraries
+/// will provide a method for the number of days from an epoch. For example, in the `chrono` library you can use the
+/// `num_days_from_ce` method to get the number of days from the Common Era (CE) epoch. Alternatively, implement the
+/// `dates::DateLike` trait for your date type.
 ///
-/// Cash flows are discounted assuming a 365-day year.
+/// Cash flows are discounted assuming a 365-day year (ACT/365F), see the `_with_day_count` variant for other
+/// day count conventions.
 ///
 /// # Returns
 /// * The modified internal rate of return (MIRR)
@@ -89,10 +91,54 @@ pub fn mirr<T: FloatLike>(cash_flows: &[T], finance_rate: T, reinvest_rate: T) -
 /// let finance_rate = 0.1;
 /// let reinvest_rate = 0.05;
 /// xmirr(&flow_table, finance_rate, reinvest_rate);
-pub fn xmirr<T: FloatLike>(flow_table: &[(T, i32)], finance_rate: T, reinvest_rate: T) -> T {
+pub fn xmirr<T: FloatLike, D: DateLike>(flow_table: &[(T, D)], finance_rate: T, reinvest_rate: T) -> T {
+    xmirr_with_day_count(flow_table, finance_rate, reinvest_rate, DayCount::Act365F)
+}
+
+/// XMIRR With Day Count - Modified Internal Rate of Return for Irregular Cash Flows under a day count convention
+///
+/// The XMIRR function calculates the modified internal rate of return for a schedule of cash flows that is not necessarily periodic,
+/// measuring the time between cash flows with the given day count convention.
+///
+/// # Arguments
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or an Excel 1900 date serial (days since 1899-12-30). The first cash flow is
+///   assumed to be the initial investment date at time 0 and the last cash flow the terminal date, the order of the
+///   cash flows in between does not matter.
+/// * `finance_rate` - The cost of capital (interest rate) for financing
+/// * `reinvest_rate` - The reinvestment rate for positive cash flows
+/// * `day_count` - The day count convention used to convert the days between cash flows to years
+///
+/// `DayCount::Act360` and `DayCount::Act365F` only depend on the number of days between the dates, so any epoch
+/// may be used with them.
+///
+/// # Returns
+/// * The modified internal rate of return (MIRR)
+///
+/// # Example
+/// * Cash flows of $-100, $-20, $70, $70 on 2024-01-01, 2024-07-01, 2025-01-01, 2025-07-01
+/// * Finance rate of 0.1, reinvestment rate of 0.05
+/// ```
+/// use rust_finprim::dates::DayCount;
+/// use rust_finprim::rate::xmirr_with_day_count;
+///
+/// let flow_table = vec![
+///   (-100.0, 45292),
+///   (-20.0, 45474),
+///   (70.0, 45658),
+///   (70.0, 45839),
+/// ];
+/// xmirr_with_day_count(&flow_table, 0.1, 0.05, DayCount::Thirty360);
+/// ```
+pub fn xmirr_with_day_count<T: FloatLike, D: DateLike>(
+    flow_table: &[(T, D)],
+    finance_rate: T,
+    reinvest_rate: T,
+    day_count: DayCount,
+) -> T {
     let init_date = flow_table.first().unwrap().1;
+    let final_date = flow_table.last().unwrap().1;
 
-    let n = T::from_i32(flow_table.last().unwrap().1);
     let mut npv_neg = T::zero();
     let mut fv_pos = T::zero();
     // Calculate the NPV of negative cash flows and the FV of positive cash Flows
@@ -102,7 +148,7 @@ pub fn xmirr<T: FloatLike>(flow_table: &[(T, i32)], finance_rate: T, reinvest_ra
         if cf < T::zero() {
             npv_neg += pv(
                 finance_rate,
-                T::from_i32(date - init_date) / T::from_u16(365),
+                day_count.year_fraction(init_date, date),
                 T::zero(),
                 Some(cf),
                 None,
@@ -110,19 +156,19 @@ pub fn xmirr<T: FloatLike>(flow_table: &[(T, i32)], finance_rate: T, reinvest_ra
         } else {
             fv_pos -= fv(
                 reinvest_rate,
-                (n - T::from_i32(date)) / T::from_u16(365),
+                day_count.year_fraction(date, final_date),
                 T::zero(),
                 Some(cf),
                 None,
             );
         }
     }
-    npv_neg.abs(); // Ensure npv_neg is positive for the calculation
+    npv_neg = npv_neg.abs(); // Ensure npv_neg is positive for the calculation
     cagr(
         // Calculate the CAGR using the future value of positive cash flows and the present value of negative cash flows
         npv_neg,
         fv_pos,
-        n / T::from_u16(365), // Convert to years by dividing by 365
+        day_count.year_fraction(init_date, final_date),
     )
 }
 
@@ -133,7 +179,7 @@ mod tests {
     #[cfg(not(feature = "std"))]
     extern crate std;
     #[cfg(not(feature = "std"))]
-    use std::{assert, vec};
+    use std::{assert, assert_eq, vec};
 
     #[test]
     fn test_mirr() {
@@ -181,4 +227,38 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_xmirr_with_day_count() {
+        use crate::dates::Date;
+
+        let finance_rate = 0.1;
+        let reinvest_rate = 0.05;
+
+        let flow_table = vec![(-100.0, 0), (-20.0, 359), (20.0, 400), (20.0, 1000), (20.0, 2000)];
+        let result = xmirr_with_day_count(&flow_table, finance_rate, reinvest_rate, DayCount::Act365F);
+        assert_eq!(result, xmirr(&flow_table, finance_rate, reinvest_rate));
+
+        // Semi-annual cash flows from 2024-01-01 to 2025-01-01 are exact half years under 30/360
+        let flow_table = vec![(-100.0, 45292), (-20.0, 45474), (70.0, 45658)];
+        let result = xmirr_with_day_count(&flow_table, finance_rate, reinvest_rate, DayCount::Thirty360);
+        let expected: f64 = 70.0 / (100.0 + 20.0 / 1.1f64.powf(0.5)) - 1.0;
+        assert!(
+            (result - expected).abs() < 1e-10,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Cash flows of -100, -20, 70 semi-annually under 30/360",
+            expected,
+            result
+        );
+
+        // Keyed by calendar dates instead of serials
+        let start = Date::new(2024, 1, 1).unwrap();
+        let date_table = vec![
+            (-100.0, start),
+            (-20.0, start.add_months(6)),
+            (70.0, start.add_months(12)),
+        ];
+        let result = xmirr_with_day_count(&date_table, finance_rate, reinvest_rate, DayCount::Thirty360);
+        assert_eq!(result, expected);
+    }
 }
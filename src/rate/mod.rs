@@ -7,13 +7,22 @@
 mod apr_ear;
 pub use apr_ear::{apr, ear};
 
+// RATE
+mod annuity_rate;
+pub use annuity_rate::{rate, rate_with_config};
+
 // IRR and MIRR
 mod irr;
-pub use irr::{irr, xirr};
+pub use irr::{
+    cumulative_sign_changes, irr, irr_all_into, irr_all_into_with_config, irr_with_config, sign_changes, xirr,
+    xirr_with_config, xirr_with_day_count,
+};
+#[cfg(feature = "std")]
+pub use irr::{irr_all, irr_all_with_config};
 
 // XIRR and XMIRR
 mod mirr;
-pub use mirr::{mirr, xmirr};
+pub use mirr::{mirr, xmirr, xmirr_with_day_count};
 
 // CAGR
 mod cagr;
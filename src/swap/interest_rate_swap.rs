@@ -0,0 +1,393 @@
+use crate::curve::YieldCurve;
+use crate::dates::{schedule, Date, DayCount, Frequency};
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Swap Leg
+///
+/// The payment frequency and day count convention of one leg of a swap.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SwapLeg {
+    /// The payment frequency.
+    pub frequency: Frequency,
+
+    /// The day count convention used to accrue interest.
+    pub day_count: DayCount,
+}
+
+impl SwapLeg {
+    /// Creates a new `SwapLeg` instance.
+    ///
+    /// # Arguments
+    /// * `frequency` - The payment frequency
+    /// * `day_count` - The day count convention used to accrue interest
+    pub fn new(frequency: Frequency, day_count: DayCount) -> Self {
+        Self { frequency, day_count }
+    }
+}
+
+/// Interest Rate Swap
+///
+/// A vanilla fixed-for-floating interest rate swap exchanging a fixed rate for a floating rate plus a spread on
+/// the same notional. The floating rate of each period is projected off the discount curve (single curve
+/// valuation), so the floating leg is worth the notional paid at the start less the notional paid at the end, plus
+/// the spread.
+///
+/// The schedules of both legs are generated backward from the maturity with a short first period, without business
+/// day adjustment. Curve times are year fractions from the valuation date under `curve_day_count`, ACT/365F unless
+/// set otherwise. Periods paid on or before the valuation date are left out, and a period in progress is valued
+/// with its full coupon on both legs, the floating coupon at `current_fixing`.
+///
+/// # Feature
+/// This struct requires the `std` feature to be enabled as it generates its schedules in a `std::Vec` and values
+/// off a `curve::YieldCurve`.
+///
+/// # Examples
+/// * 5 year swap paying 4% annually on 30/360 against a quarterly ACT/360 floating rate
+/// ```
+/// use rust_finprim::curve::{Compounding, Interpolation, YieldCurve};
+/// use rust_finprim::dates::{Date, DayCount, Frequency};
+/// use rust_finprim::swap::{InterestRateSwap, SwapLeg};
+///
+/// let curve = YieldCurve::from_zero_rates(
+///     &[1.0, 2.0, 5.0],
+///     &[0.045, 0.048, 0.05],
+///     Compounding::Continuous,
+///     Interpolation::LogLinear,
+/// );
+/// let effective = Date::new(2024, 1, 15).unwrap();
+/// let swap = InterestRateSwap::new(
+///     1_000_000.0,
+///     0.04,
+///     effective,
+///     Date::new(2029, 1, 15).unwrap(),
+///     SwapLeg::new(Frequency::Annual, DayCount::Thirty360),
+///     SwapLeg::new(Frequency::Quarterly, DayCount::Act360),
+/// );
+/// let par_rate = swap.par_rate(&curve, effective).unwrap();
+/// let npv = swap.npv(&curve, effective); // To the payer of the fixed rate
+/// let pv01 = swap.pv01(&curve, effective);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InterestRateSwap<T> {
+    /// The notional amount.
+    pub notional: T,
+
+    /// The annual fixed rate.
+    pub fixed_rate: T,
+
+    /// The spread over the floating rate.
+    pub spread: T,
+
+    /// The effective date, the start of the first period of both legs.
+    pub effective: Date,
+
+    /// The maturity date, the end of the last period of both legs.
+    pub maturity: Date,
+
+    /// The fixed leg conventions.
+    pub fixed_leg: SwapLeg,
+
+    /// The floating leg conventions.
+    pub floating_leg: SwapLeg,
+
+    /// The day count convention used to convert dates to curve times.
+    pub curve_day_count: DayCount,
+
+    /// The floating rate fixed for the period in progress at the valuation date, before the spread. If not set it
+    /// is projected off the curve from the valuation date to the end of the period.
+    pub current_fixing: Option<T>,
+}
+
+impl<T: FloatLike> InterestRateSwap<T> {
+    /// Creates a new `InterestRateSwap` instance without a floating spread.
+    ///
+    /// # Arguments
+    /// * `notional` - The notional amount
+    /// * `fixed_rate` - The annual fixed rate
+    /// * `effective` - The effective date, should be before the maturity
+    /// * `maturity` - The maturity date
+    /// * `fixed_leg` - The fixed leg conventions
+    /// * `floating_leg` - The floating leg conventions
+    pub fn new(
+        notional: T,
+        fixed_rate: T,
+        effective: Date,
+        maturity: Date,
+        fixed_leg: SwapLeg,
+        floating_leg: SwapLeg,
+    ) -> Self {
+        Self {
+            notional,
+            fixed_rate,
+            spread: T::zero(),
+            effective,
+            maturity,
+            fixed_leg,
+            floating_leg,
+            curve_day_count: DayCount::Act365F,
+            current_fixing: None,
+        }
+    }
+
+    /// The schedule dates of a leg, starting with the effective date and ending with the maturity
+    pub fn schedule(&self, leg: &SwapLeg) -> Vec<Date> {
+        schedule(self.effective, self.maturity, leg.frequency, None, None)
+    }
+
+    /// The discount factor of the curve at a date
+    fn discount_factor(&self, curve: &YieldCurve<T>, valuation: Date, date: Date) -> T {
+        curve.discount_factor(self.curve_day_count.year_fraction(valuation, date))
+    }
+
+    /// The periods of a leg not yet paid at the valuation date, as `(start, end)` dates
+    fn periods(&self, leg: &SwapLeg, valuation: Date) -> impl Iterator<Item = (Date, Date)> {
+        let dates = self.schedule(leg);
+        (1..dates.len())
+            .map(move |i| (dates[i - 1], dates[i]))
+            .filter(move |&(_, end)| end > valuation)
+    }
+
+    /// Annuity
+    ///
+    /// The present value of the fixed leg per unit of fixed rate, the value of receiving 100% a year on the
+    /// notional over the fixed leg schedule.
+    ///
+    /// # Arguments
+    /// * `curve` - The discount curve
+    /// * `valuation` - The valuation date, time zero of the curve
+    ///
+    /// # Formula
+    /// $$A = N \sum_{i} \tau_i P(t_i)$$
+    ///
+    /// Where:
+    /// * \\(N\\) = notional
+    /// * \\(\tau_i\\) = year fraction of fixed period \\(i\\) under the fixed leg day count
+    /// * \\(P(t_i)\\) = discount factor to the payment date of period \\(i\\)
+    pub fn annuity(&self, curve: &YieldCurve<T>, valuation: Date) -> T {
+        let day_count = self.fixed_leg.day_count;
+        let annuity: T = self
+            .periods(&self.fixed_leg, valuation)
+            .map(|(start, end)| {
+                day_count.year_fraction::<T, Date>(start, end) * self.discount_factor(curve, valuation, end)
+            })
+            .sum();
+        self.notional * annuity
+    }
+
+    /// Fixed Leg Value
+    ///
+    /// The present value of the fixed leg payments.
+    ///
+    /// # Arguments
+    /// * `curve` - The discount curve
+    /// * `valuation` - The valuation date, time zero of the curve
+    pub fn fixed_leg_value(&self, curve: &YieldCurve<T>, valuation: Date) -> T {
+        self.fixed_rate * self.annuity(curve, valuation)
+    }
+
+    /// Floating Leg Value
+    ///
+    /// The present value of the floating leg payments, including the spread. A period in progress at the valuation
+    /// date pays its full coupon at the current fixing, like the fixed leg.
+    ///
+    /// # Arguments
+    /// * `curve` - The discount curve
+    /// * `valuation` - The valuation date, time zero of the curve
+    ///
+    /// # Formula
+    /// $$V_{float} = N \left( (L + s) \tau_0 P(e_0) + \sum_{j \geq 1} \left( P(s_j) - P(e_j) + \tau_j s P(e_j) \right)
+    /// \right)$$
+    ///
+    /// Where:
+    /// * \\(s_j\\), \\(e_j\\) = start and end of floating period \\(j\\), period 0 being in progress
+    /// * \\(\tau_j\\) = year fraction of floating period \\(j\\) under the floating leg day count
+    /// * \\(s\\) = spread
+    /// * \\(L\\) = current fixing, or if not set the forward rate \\(\frac{1}{\tau_v} \left( \frac{1}{P(e_0)} - 1
+    ///   \right)\\) where \\(\tau_v\\) is the year fraction from the valuation date to \\(e_0\\)
+    ///
+    /// Without a current fixing the forward over the rest of the period in progress is applied over its full
+    /// accrual \\(\tau_0\\), an approximation of the rate fixed at \\(s_0\\), which the curve does not know. Set the
+    /// current fixing for an exact value.
+    pub fn floating_leg_value(&self, curve: &YieldCurve<T>, valuation: Date) -> T {
+        let day_count = self.floating_leg.day_count;
+        let value: T = self
+            .periods(&self.floating_leg, valuation)
+            .map(|(start, end)| {
+                let end_discount = self.discount_factor(curve, valuation, end);
+                let accrual = day_count.year_fraction::<T, Date>(start, end);
+                if start < valuation {
+                    let fixing = self.current_fixing.unwrap_or_else(|| {
+                        (T::one() / end_discount - T::one()) / day_count.year_fraction::<T, Date>(valuation, end)
+                    });
+                    (fixing + self.spread) * accrual * end_discount
+                } else {
+                    self.discount_factor(curve, valuation, start) - end_discount + accrual * self.spread * end_discount
+                }
+            })
+            .sum();
+        self.notional * value
+    }
+
+    /// Par Rate
+    ///
+    /// The fixed rate at which the swap is worth zero, the floating leg value over the annuity.
+    ///
+    /// # Arguments
+    /// * `curve` - The discount curve
+    /// * `valuation` - The valuation date, time zero of the curve
+    ///
+    /// # Returns
+    /// * The par rate, or `None` if the annuity is zero, e.g. on or after the last fixed payment date
+    ///
+    /// # Formula
+    /// $$K_{par} = \frac{V_{float}}{A}$$
+    pub fn par_rate(&self, curve: &YieldCurve<T>, valuation: Date) -> Option<T> {
+        let annuity = self.annuity(curve, valuation);
+        if annuity.is_zero() {
+            return None;
+        }
+        Some(self.floating_leg_value(curve, valuation) / annuity)
+    }
+
+    /// Net Present Value
+    ///
+    /// The mark-to-market value of the swap to the payer of the fixed rate, negate it for the receiver.
+    ///
+    /// # Arguments
+    /// * `curve` - The discount curve
+    /// * `valuation` - The valuation date, time zero of the curve
+    ///
+    /// # Formula
+    /// $$NPV = V_{float} - K A = (K_{par} - K) A$$
+    pub fn npv(&self, curve: &YieldCurve<T>, valuation: Date) -> T {
+        self.floating_leg_value(curve, valuation) - self.fixed_leg_value(curve, valuation)
+    }
+
+    /// PV01
+    ///
+    /// The present value of one basis point a year on the fixed leg, the change in the value of the swap for a one
+    /// basis point change in the fixed rate. Divide a change in value by the PV01 to express it in basis points
+    /// of fixed rate.
+    ///
+    /// # Arguments
+    /// * `curve` - The discount curve
+    /// * `valuation` - The valuation date, time zero of the curve
+    ///
+    /// # Formula
+    /// $$PV01 = 0.0001 A$$
+    pub fn pv01(&self, curve: &YieldCurve<T>, valuation: Date) -> T {
+        self.annuity(curve, valuation) / T::from_u16(10000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::{Compounding, Interpolation};
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_interest_rate_swap() {
+        let curve = YieldCurve::from_zero_rates(
+            &[1.0, 10.0],
+            &[0.05, 0.05],
+            Compounding::Continuous,
+            Interpolation::Linear,
+        );
+        let effective = date(2024, 1, 15);
+        let swap = InterestRateSwap::new(
+            1_000_000.0,
+            0.04,
+            effective,
+            date(2029, 1, 15),
+            SwapLeg::new(Frequency::Annual, DayCount::Thirty360),
+            SwapLeg::new(Frequency::Quarterly, DayCount::Act360),
+        );
+        assert_eq!(swap.schedule(&swap.floating_leg).len(), 21);
+
+        struct TestCase {
+            swap: InterestRateSwap<f64>,
+            valuation: Date,
+            // Annuity, par rate and NPV
+            expected: (f64, f64, f64),
+        }
+        impl TestCase {
+            fn new(swap: InterestRateSwap<f64>, valuation: Date, expected: (f64, f64, f64)) -> TestCase {
+                TestCase {
+                    swap,
+                    valuation,
+                    expected,
+                }
+            }
+        }
+        let cases = [
+            TestCase::new(
+                swap,
+                effective,
+                (4313608.731598689, 0.051328845882130214, 48868.20851609274),
+            ),
+            TestCase::new(
+                InterestRateSwap { spread: 0.001, ..swap },
+                effective,
+                (4313608.731598689, 0.052363182263192726, 53329.930960854195),
+            ),
+            // Mid-life, two fixed periods paid, the floating period in progress projected or fixed
+            TestCase::new(
+                swap,
+                date(2026, 3, 1),
+                (2733455.1593019897, 0.051302817577422354, 30895.74502165435),
+            ),
+            TestCase::new(
+                InterestRateSwap {
+                    current_fixing: Some(0.045),
+                    ..swap
+                },
+                date(2026, 3, 1),
+                (2733455.1593019897, 0.05089674489619876, 29785.763556112128),
+            ),
+        ];
+        for case in &cases {
+            let swap = &case.swap;
+            let result = (
+                swap.annuity(&curve, case.valuation),
+                swap.par_rate(&curve, case.valuation).unwrap(),
+                swap.npv(&curve, case.valuation),
+            );
+            assert!(
+                (result.0 - case.expected.0).abs() < 1e-6
+                    && (result.1 - case.expected.1).abs() < 1e-12
+                    && (result.2 - case.expected.2).abs() < 1e-6,
+                "Failed on case: {:?}. Expected: {:?}, Result: {:?}",
+                swap,
+                case.expected,
+                result
+            );
+        }
+
+        // At the par rate the swap is worth zero, a basis point off it is worth one PV01
+        let par_rate = swap.par_rate(&curve, effective).unwrap();
+        let par_swap = InterestRateSwap {
+            fixed_rate: par_rate,
+            ..swap
+        };
+        assert!(par_swap.npv(&curve, effective).abs() < 1e-6);
+        let off_par = InterestRateSwap {
+            fixed_rate: par_rate - 0.0001,
+            ..swap
+        };
+        assert!((off_par.npv(&curve, effective) - swap.pv01(&curve, effective)).abs() < 1e-6);
+
+        // A matured swap has no par rate rather than dividing by a zero annuity
+        let matured = date(2029, 1, 15);
+        assert_eq!(swap.annuity(&curve, matured), 0.0);
+        assert_eq!(swap.par_rate(&curve, matured), None);
+        assert_eq!(swap.npv(&curve, matured), 0.0);
+    }
+}
@@ -0,0 +1,10 @@
+//! This module contains interest rate swap valuation.
+//!
+//! For example, you can value a vanilla fixed-for-floating swap off a yield curve, or calculate its par rate,
+//! the annuity of its fixed leg and its PV01.
+
+// Interest Rate Swaps
+#[cfg(feature = "std")]
+mod interest_rate_swap;
+#[cfg(feature = "std")]
+pub use interest_rate_swap::{InterestRateSwap, SwapLeg};
@@ -0,0 +1,469 @@
+use crate::tvm;
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// IPMT - Interest Payment
+///
+/// The interest portion of the payment for a given period of an annuity, similar to the Excel `IPMT` function.
+/// The `due` parameter expresses whether the annuity type is an ordinary annuity (false and the default) or an annuity due (true),
+/// Excel provides this parameter as `type` with 0 for ordinary annuity and 1 for annuity due.
+///
+/// For an annuity due no interest has accrued at the first payment, so the interest portion of period 1 is zero.
+///
+/// # Arguments
+/// * `rate` - The interest rate per period
+/// * `per` - The period to find the interest portion for, from 1 to `nper`
+/// * `nper` - The number of compounding periods
+/// * `pv` - The present value of a series of cash flows or principal amount
+/// * `fv` (optional) - The future value
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+///
+/// # Returns
+/// * The interest portion of the payment for period `per`
+/// * `FinPrimError::InvalidPeriod` if `per` is not from 1 to `nper`
+///
+/// # Example
+/// * 10% annual interest rate paid monthly
+/// * First month of a 3 year $8000 loan
+/// ```
+/// use rust_finprim::tvm::ipmt;
+///
+/// let rate: f64 = 0.1 / 12.0; let per = 1; let nper = 36.0; let pv = 8000.0;
+/// let interest = ipmt(rate, per, nper, pv, None, None).unwrap();
+/// assert!((interest + 66.66667).abs() < 1e-5);
+/// ```
+///
+/// # Formula
+/// The interest portion is the interest accrued on the balance at the start of the period:
+/// $$IPMT_k = r \cdot FV(r, k - 1, PMT, PV)$$
+///
+/// For an annuity due the balance already reflects the payment made at the start of the period, so the
+/// result is discounted by one period, \\(\frac{IPMT_k}{1 + r}\\), and is zero for the first period.
+///
+/// Where:
+/// * \\(r\\) = interest rate per period
+/// * \\(k\\) = the period
+/// * \\(PMT\\) = the level payment, see `pmt`
+/// * \\(PV\\) = present value
+pub fn ipmt<T: FloatLike>(
+    rate: T,
+    per: u32,
+    nper: T,
+    pv: T,
+    fv: Option<T>,
+    due: Option<bool>,
+) -> Result<T, FinPrimError<T>> {
+    check_periods(per, per, nper)?;
+    let due = due.unwrap_or(false);
+    if due && per == 1 {
+        // Payment made at the start of the first period, no interest has accrued yet
+        return Ok(T::zero());
+    }
+
+    let pmt = tvm::pmt(rate, nper, pv, fv, Some(due));
+    let interest = tvm::fv(rate, T::from_usize(per as usize - 1), pmt, Some(pv), Some(due)) * rate;
+    if due {
+        Ok(interest / (T::one() + rate))
+    } else {
+        Ok(interest)
+    }
+}
+
+/// Checks that the periods from `start` to `end` are within the term of an annuity of `nper` periods
+fn check_periods<T: FloatLike>(start: u32, end: u32, nper: T) -> Result<(), FinPrimError<T>> {
+    if start == 0 || end < start || T::from_usize(end as usize) > nper {
+        return Err(FinPrimError::InvalidPeriod { start, end });
+    }
+    Ok(())
+}
+
+/// PPMT - Principal Payment
+///
+/// The principal portion of the payment for a given period of an annuity, similar to the Excel `PPMT` function.
+/// The `due` parameter expresses whether the annuity type is an ordinary annuity (false and the default) or an annuity due (true),
+/// Excel provides this parameter as `type` with 0 for ordinary annuity and 1 for annuity due.
+///
+/// # Arguments
+/// * `rate` - The interest rate per period
+/// * `per` - The period to find the principal portion for, from 1 to `nper`
+/// * `nper` - The number of compounding periods
+/// * `pv` - The present value of a series of cash flows or principal amount
+/// * `fv` (optional) - The future value
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+///
+/// # Returns
+/// * The principal portion of the payment for period `per`
+/// * `FinPrimError::InvalidPeriod` if `per` is not from 1 to `nper`
+///
+/// # Example
+/// * 8% annual interest rate
+/// * Final year of a 10 year $200,000 loan
+/// ```
+/// use rust_finprim::tvm::ppmt;
+///
+/// let rate: f64 = 0.08; let per = 10; let nper = 10.0; let pv = 200_000.0;
+/// let principal = ppmt(rate, per, nper, pv, None, None).unwrap();
+/// assert!((principal + 27598.05346).abs() < 1e-5);
+/// ```
+///
+/// # Formula
+/// $$PPMT_k = PMT - IPMT_k$$
+pub fn ppmt<T: FloatLike>(
+    rate: T,
+    per: u32,
+    nper: T,
+    pv: T,
+    fv: Option<T>,
+    due: Option<bool>,
+) -> Result<T, FinPrimError<T>> {
+    Ok(tvm::pmt(rate, nper, pv, fv, due) - ipmt(rate, per, nper, pv, fv, due)?)
+}
+
+/// CUMIPMT - Cumulative Interest Payment
+///
+/// The cumulative interest paid on a loan between two periods (inclusive), similar to the Excel `CUMIPMT` function.
+/// The `due` parameter expresses whether the annuity type is an ordinary annuity (false and the default) or an annuity due (true),
+/// Excel provides this parameter as `type` with 0 for ordinary annuity and 1 for annuity due.
+///
+/// Calculated in closed form, no schedule is built.
+///
+/// # Arguments
+/// * `rate` - The interest rate per period
+/// * `nper` - The number of compounding periods
+/// * `pv` - The present value or principal amount of the loan
+/// * `start` - The first period in the calculation, periods are numbered from 1
+/// * `end` - The last period in the calculation, should be greater than or equal to `start`
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+///
+/// # Returns
+/// * The total interest paid from period `start` to period `end`
+/// * `FinPrimError::InvalidPeriod` unless `1 <= start <= end <= nper`
+///
+/// # Example
+/// * 9% annual interest rate paid monthly
+/// * Interest paid in the second year of a 30 year $125,000 mortgage
+/// ```
+/// use rust_finprim::tvm::cumipmt;
+///
+/// let rate: f64 = 0.09 / 12.0; let nper = 360.0; let pv = 125_000.0;
+/// let interest = cumipmt(rate, nper, pv, 13, 24, None).unwrap();
+/// assert!((interest + 11135.23213).abs() < 1e-5);
+/// ```
+///
+/// # Formula
+/// $$CUMIPMT = (e - s + 1) \cdot PMT - CUMPRINC$$
+///
+/// Where:
+/// * \\(s\\) = the start period
+/// * \\(e\\) = the end period
+/// * \\(PMT\\) = the level payment, see `pmt`
+pub fn cumipmt<T: FloatLike>(
+    rate: T,
+    nper: T,
+    pv: T,
+    start: u32,
+    end: u32,
+    due: Option<bool>,
+) -> Result<T, FinPrimError<T>> {
+    let principal = cumprinc(rate, nper, pv, start, end, due)?;
+    let pmt = tvm::pmt(rate, nper, pv, None, due);
+    Ok(T::from_usize((end - start + 1) as usize) * pmt - principal)
+}
+
+/// CUMPRINC - Cumulative Principal Payment
+///
+/// The cumulative principal paid on a loan between two periods (inclusive), similar to the Excel `CUMPRINC` function.
+/// The `due` parameter expresses whether the annuity type is an ordinary annuity (false and the default) or an annuity due (true),
+/// Excel provides this parameter as `type` with 0 for ordinary annuity and 1 for annuity due.
+///
+/// Calculated in closed form, no schedule is built.
+///
+/// # Arguments
+/// * `rate` - The interest rate per period
+/// * `nper` - The number of compounding periods
+/// * `pv` - The present value or principal amount of the loan
+/// * `start` - The first period in the calculation, periods are numbered from 1
+/// * `end` - The last period in the calculation, should be greater than or equal to `start`
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+///
+/// # Returns
+/// * The total principal paid from period `start` to period `end`
+/// * `FinPrimError::InvalidPeriod` unless `1 <= start <= end <= nper`
+///
+/// # Example
+/// * 9% annual interest rate paid monthly
+/// * Principal paid in the second year of a 30 year $125,000 mortgage
+/// ```
+/// use rust_finprim::tvm::cumprinc;
+///
+/// let rate: f64 = 0.09 / 12.0; let nper = 360.0; let pv = 125_000.0;
+/// let principal = cumprinc(rate, nper, pv, 13, 24, None).unwrap();
+/// assert!((principal + 934.10712).abs() < 1e-5);
+/// ```
+///
+/// # Formula
+/// The principal paid is the change in the balance between the two periods:
+/// $$CUMPRINC = FV(r, s - 1, PMT, PV) - FV(r, e, PMT, PV)$$
+///
+/// For an annuity due the difference is discounted by one period, \\(\frac{CUMPRINC}{1 + r}\\), and
+/// when the first period is included it is adjusted by \\(\frac{PV r}{1 + r}\\) as no interest is paid at
+/// the first payment.
+///
+/// Where:
+/// * \\(r\\) = interest rate per period
+/// * \\(s\\) = the start period
+/// * \\(e\\) = the end period
+/// * \\(PMT\\) = the level payment, see `pmt`
+/// * \\(PV\\) = present value
+pub fn cumprinc<T: FloatLike>(
+    rate: T,
+    nper: T,
+    pv: T,
+    start: u32,
+    end: u32,
+    due: Option<bool>,
+) -> Result<T, FinPrimError<T>> {
+    check_periods(start, end, nper)?;
+    let due = due.unwrap_or(false);
+    let pmt = tvm::pmt(rate, nper, pv, None, Some(due));
+    let fv_start = tvm::fv(rate, T::from_usize(start as usize - 1), pmt, Some(pv), Some(due));
+    let fv_end = tvm::fv(rate, T::from_usize(end as usize), pmt, Some(pv), Some(due));
+
+    if !due {
+        return Ok(fv_start - fv_end);
+    }
+
+    let one_plus_r = T::one() + rate;
+    let principal = (fv_start - fv_end) / one_plus_r;
+    if start == 1 {
+        // The entire first payment goes to principal
+        Ok(principal - pv * rate / one_plus_r)
+    } else {
+        Ok(principal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_ipmt_ppmt() {
+        struct TestCase {
+            rate: f64,
+            per: u32,
+            nper: f64,
+            pv: f64,
+            fv: Option<f64>,
+            due: Option<bool>,
+            expected_ipmt: f64,
+            expected_ppmt: f64,
+            description: &'static str,
+        }
+
+        impl TestCase {
+            #[allow(clippy::too_many_arguments)]
+            fn new(
+                rate: f64,
+                per: u32,
+                nper: f64,
+                pv: f64,
+                fv: Option<f64>,
+                due: Option<bool>,
+                expected_ipmt: f64,
+                expected_ppmt: f64,
+                description: &'static str,
+            ) -> TestCase {
+                TestCase {
+                    rate,
+                    per,
+                    nper,
+                    pv,
+                    fv,
+                    due,
+                    expected_ipmt,
+                    expected_ppmt,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                0.1 / 12.0,
+                1,
+                36.0,
+                8000.0,
+                None,
+                None,
+                -66.66667,
+                -191.47083,
+                "First month of a 3 year $8000 loan",
+            ),
+            TestCase::new(
+                0.1,
+                3,
+                3.0,
+                8000.0,
+                None,
+                None,
+                -292.44713,
+                -2924.47130,
+                "Last year of a 3 year $8000 loan",
+            ),
+            TestCase::new(
+                0.08,
+                10,
+                10.0,
+                200_000.0,
+                None,
+                None,
+                -2207.84428,
+                -27598.05346,
+                "Last year of a 10 year $200,000 loan",
+            ),
+            TestCase::new(
+                0.1 / 12.0,
+                1,
+                36.0,
+                8000.0,
+                None,
+                Some(true),
+                0.0,
+                -256.00413,
+                "No interest accrued at the first payment of an annuity due",
+            ),
+            TestCase::new(
+                0.1 / 12.0,
+                5,
+                36.0,
+                8000.0,
+                None,
+                Some(true),
+                -59.70653,
+                -196.29760,
+                "Fifth month of an annuity due",
+            ),
+            TestCase::new(
+                0.1 / 12.0,
+                5,
+                36.0,
+                8000.0,
+                Some(1000.0),
+                None,
+                -59.39626,
+                -222.67509,
+                "Fifth month with a future value",
+            ),
+            TestCase::new(0.0, 5, 10.0, 1000.0, None, None, 0.0, -100.0, "Zero interest rate"),
+        ];
+
+        for case in &cases {
+            let calculated_ipmt = ipmt(case.rate, case.per, case.nper, case.pv, case.fv, case.due).unwrap();
+            let calculated_ppmt = ppmt(case.rate, case.per, case.nper, case.pv, case.fv, case.due).unwrap();
+            assert!(
+                (calculated_ipmt - case.expected_ipmt).abs() < 1e-5,
+                "Failed on case: {}. Expected IPMT {}, got {}",
+                case.description,
+                case.expected_ipmt,
+                calculated_ipmt
+            );
+            assert!(
+                (calculated_ppmt - case.expected_ppmt).abs() < 1e-5,
+                "Failed on case: {}. Expected PPMT {}, got {}",
+                case.description,
+                case.expected_ppmt,
+                calculated_ppmt
+            );
+        }
+    }
+
+    #[test]
+    fn test_cumipmt_cumprinc() {
+        let rate = 0.09 / 12.0;
+        let nper = 360.0;
+        let pv = 125_000.0;
+
+        let result = cumipmt(rate, nper, pv, 13, 24, None).unwrap();
+        let expected = -11135.23213;
+        assert!(
+            (result - expected).abs() < 1e-5,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Interest paid in the second year of a 30 year mortgage",
+            expected,
+            result
+        );
+
+        let result = cumprinc(rate, nper, pv, 13, 24, None).unwrap();
+        let expected = -934.10712;
+        assert!(
+            (result - expected).abs() < 1e-5,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Principal paid in the second year of a 30 year mortgage",
+            expected,
+            result
+        );
+
+        let result = cumipmt(rate, nper, pv, 1, 1, None).unwrap();
+        let expected = -937.5;
+        assert!(
+            (result - expected).abs() < 1e-5,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Interest paid in the first month of a 30 year mortgage",
+            expected,
+            result
+        );
+
+        // The closed forms should agree with summing the individual periods
+        for due in [None, Some(true)] {
+            for (start, end) in [(1, 12), (13, 24), (1, 360), (359, 360)] {
+                let summed_ipmt: f64 = (start..=end).map(|k| ipmt(rate, k, nper, pv, None, due).unwrap()).sum();
+                let summed_ppmt: f64 = (start..=end).map(|k| ppmt(rate, k, nper, pv, None, due).unwrap()).sum();
+                let result_ipmt = cumipmt(rate, nper, pv, start, end, due).unwrap();
+                let result_ppmt = cumprinc(rate, nper, pv, start, end, due).unwrap();
+                assert!(
+                    (result_ipmt - summed_ipmt).abs() < 1e-5,
+                    "Failed on case: periods {} to {}, due {:?}. Expected: {}, Result: {}",
+                    start,
+                    end,
+                    due,
+                    summed_ipmt,
+                    result_ipmt
+                );
+                assert!(
+                    (result_ppmt - summed_ppmt).abs() < 1e-5,
+                    "Failed on case: periods {} to {}, due {:?}. Expected: {}, Result: {}",
+                    start,
+                    end,
+                    due,
+                    summed_ppmt,
+                    result_ppmt
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        let rate = 0.09 / 12.0;
+        let nper = 360.0;
+        let pv = 125_000.0;
+        let invalid = |start, end| Err(FinPrimError::InvalidPeriod { start, end });
+
+        assert_eq!(ipmt(rate, 0, nper, pv, None, None), invalid(0, 0));
+        assert_eq!(ppmt(rate, 361, nper, pv, None, Some(true)), invalid(361, 361));
+        assert_eq!(cumipmt(rate, nper, pv, 0, 12, None), invalid(0, 12));
+        assert_eq!(cumprinc(rate, nper, pv, 24, 13, None), invalid(24, 13));
+        assert_eq!(cumprinc(rate, nper, pv, 1, 361, None), invalid(1, 361));
+        assert!(ipmt(rate, 360, nper, pv, None, None).is_ok());
+    }
+}
@@ -11,8 +11,18 @@ pub use fv::fv;
 
 // PV - Present Value
 mod pv;
-pub use pv::{npv, npv_differing_rates, pv, xnpv};
+pub use pv::{npv, npv_differing_rates, pv, xnpv, xnpv_with_day_count};
+#[cfg(feature = "std")]
+pub use pv::{npv_with_curve, xnpv_with_curve};
 
 // PMT - Payment
 mod pmt;
 pub use pmt::pmt;
+
+// IPMT, PPMT, CUMIPMT, CUMPRINC - Interest and Principal Portions of a Payment
+mod ipmt;
+pub use ipmt::{cumipmt, cumprinc, ipmt, ppmt};
+
+// NPER - Number of Periods
+mod nper;
+pub use nper::nper;
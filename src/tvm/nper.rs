@@ -0,0 +1,179 @@
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// NPER - Number of Periods
+///
+/// The number of periods required for an investment or loan to reach a future value given a
+/// fixed interest rate and payment, similar to the Excel `NPER` function.
+/// The `due` parameter expresses whether the annuity type is an ordinary annuity (false and the default) or an annuity due (true),
+/// Excel provides this parameter as `type` with 0 for ordinary annuity and 1 for annuity due.
+///
+/// Uses the same sign conventions as `pmt`, cash outflows are negative and cash inflows are positive.
+///
+/// # Arguments
+/// * `rate` - The interest rate per period
+/// * `pmt` - The payment amount per period
+/// * `pv` - The present value of a series of cash flows or principal amount
+/// * `fv` (optional) - The future value, default is 0
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///   (ordinary annuity)
+///
+/// # Returns
+/// * Result of the number of periods (NPER), which may be fractional
+/// * `FinPrimError::NoSolution` if no finite number of periods exists, e.g. the payment never covers
+///   the interest accruing on the balance
+/// * `FinPrimError::DivideByZero` if both the rate and the payment are zero
+///
+/// # Example
+/// * 1% interest rate
+/// * $100 payment per period
+/// * $1000 present value (loan)
+/// ```
+/// use rust_finprim::tvm::nper;
+///
+/// let rate = 0.01; let pmt = -100.0; let pv = 1000.0;
+/// nper(rate, pmt, pv, None, None);
+/// ```
+///
+/// # Formula
+/// The number of periods is found by solving the annuity formula for \\(n\\):
+/// $$n = \frac{\ln\left(\frac{PMT(1+rt) - FV r}{PMT(1+rt) + PV r}\right)}{\ln(1+r)}$$
+///
+/// Where:
+/// * \\(r\\) = interest rate per period
+/// * \\(PMT\\) = payment per period
+/// * \\(PV\\) = present value
+/// * \\(FV\\) = future value
+/// * \\(t\\) = 1 if payments are at the beginning of the period (annuity due), 0 otherwise
+pub fn nper<T: FloatLike>(rate: T, pmt: T, pv: T, fv: Option<T>, due: Option<bool>) -> Result<T, FinPrimError<T>> {
+    let fv: T = fv.unwrap_or(T::zero());
+    let due = due.unwrap_or(false);
+
+    if rate.is_zero() {
+        // With no interest the balance changes linearly by the payment amount
+        if pmt.is_zero() {
+            return Err(FinPrimError::DivideByZero);
+        }
+        return Ok(-(pv + fv) / pmt);
+    }
+
+    let one_plus_r = T::one() + rate;
+    if one_plus_r <= T::zero() {
+        // A rate of -100% or below has no meaningful compounding
+        return Err(FinPrimError::NoSolution);
+    }
+
+    let pmt_adj = if due { pmt * one_plus_r } else { pmt };
+    let numerator = pmt_adj - fv * rate;
+    let denominator = pmt_adj + pv * rate;
+
+    if denominator.is_zero() {
+        // The payment exactly covers the interest, the balance never moves
+        return Err(FinPrimError::NoSolution);
+    }
+
+    let ratio = numerator / denominator;
+    if ratio <= T::zero() {
+        // The payment can never bring the balance to the future value
+        return Err(FinPrimError::NoSolution);
+    }
+    Ok(ratio.ln() / one_plus_r.ln())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_nper() {
+        struct TestCase {
+            rate: f64,
+            pmt: f64,
+            pv: f64,
+            fv: Option<f64>,
+            due: Option<bool>,
+            expected: f64,
+            description: &'static str,
+        }
+
+        impl TestCase {
+            fn new(
+                rate: f64,
+                pmt: f64,
+                pv: f64,
+                fv: Option<f64>,
+                due: Option<bool>,
+                expected: f64,
+                description: &'static str,
+            ) -> TestCase {
+                TestCase {
+                    rate,
+                    pmt,
+                    pv,
+                    fv,
+                    due,
+                    expected,
+                    description,
+                }
+            }
+        }
+
+        let cases = [
+            TestCase::new(
+                0.01,
+                -100.0,
+                -1000.0,
+                Some(10000.0),
+                None,
+                60.08212,
+                "Savings plan reaching $10,000 with a $1000 initial deposit",
+            ),
+            TestCase::new(
+                0.01,
+                -100.0,
+                -1000.0,
+                Some(10000.0),
+                Some(true),
+                59.67387,
+                "Payment at the beg of period should reach the target sooner",
+            ),
+            TestCase::new(0.01, -100.0, 1000.0, None, None, 10.58864, "Paying off a $1000 loan"),
+            TestCase::new(0.0, -100.0, 1000.0, None, None, 10.0, "Zero interest rate no growth"),
+            TestCase::new(
+                0.05 / 12.0,
+                -1073.64324,
+                200_000.0,
+                None,
+                None,
+                360.0,
+                "30 year mortgage at 5%",
+            ),
+        ];
+
+        for case in &cases {
+            let calculated_nper = nper(case.rate, case.pmt, case.pv, case.fv, case.due).unwrap();
+            assert!(
+                (calculated_nper - case.expected).abs() < 1e-5,
+                "Failed on case: {}. Expected {}, got {}",
+                case.description,
+                case.expected,
+                calculated_nper
+            );
+        }
+    }
+
+    #[test]
+    fn test_nper_no_solution() {
+        // $50 a period never covers the $100 of interest on a $1000 loan at 10%
+        assert_eq!(nper(0.1, -50.0, 1000.0, None, None), Err(FinPrimError::NoSolution));
+        // Interest exactly equal to the payment, the balance never moves
+        assert_eq!(nper(0.1, -100.0, 1000.0, None, None), Err(FinPrimError::NoSolution));
+        // No rate and no payment
+        assert_eq!(nper(0.0, 0.0, 1000.0, None, None), Err(FinPrimError::DivideByZero));
+    }
+}
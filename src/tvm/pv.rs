@@ -1,3 +1,6 @@
+#[cfg(feature = "std")]
+use crate::curve::YieldCurve;
+use crate::dates::{DateLike, DayCount};
 use crate::FloatLike;
 
 /// PV - Present Value
@@ -154,16 +157,17 @@ pub fn npv_differing_rates<T: FloatLike>(flow_table: &[(T, T)]) -> T {
 /// # Arguments
 /// * `rate` - The discount rate
 /// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
-/// where `date` represents the number of days from an arbitrary epoch. The first cash flow
+/// where `date` is a `dates::Date` or represents the number of days from an arbitrary epoch. The first cash flow
 /// is assumed to be the initial investment date, the order of subsequent cash flows does
 /// not matter.
 ///
-/// Most time libraries will provide a method yielding the number of days from an epoch. For example, in the `chrono` library
-/// you can use the `num_days_from_ce` method to get the number of days from the Common Era (CE) epoch, simply convert
-/// your date types to an integer representing the number of days from any epoch. Alternatively, you can calculate the
-/// time delta in days from an arbitrary epoch, such as the initial investment date.
+/// Dates may be `dates::Date` values or integers representing the number of days from any epoch. Most time libraries
+/// will provide a method yielding the number of days from an epoch. For example, in the `chrono` library you can use the
+/// `num_days_from_ce` method to get the number of days from the Common Era (CE) epoch. Alternatively, implement the
+/// `dates::DateLike` trait for your date type.
 ///
-/// Cash flows are discounted assuming a 365-day year.
+/// Cash flows are discounted assuming a 365-day year (ACT/365F), see the `_with_day_count` variant for other
+/// day count conventions.
 ///
 /// # Returns
 /// * The net present value (NPV)
@@ -185,21 +189,170 @@ pub fn npv_differing_rates<T: FloatLike>(flow_table: &[(T, T)]) -> T {
 ///    (20.0, 1460),
 /// ];
 /// xnpv(rate, &flows_table);
-pub fn xnpv<T: FloatLike>(rate: T, flow_table: &[(T, i32)]) -> T {
+pub fn xnpv<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)]) -> T {
+    xnpv_with_day_count(rate, flow_table, DayCount::Act365F)
+}
+
+/// XNPV With Day Count - Net Present Value for irregular cash flows under a day count convention
+///
+/// The XNPV function calculates the net present value of a series of cash flows that are not necessarily periodic,
+/// measuring the time between cash flows with the given day count convention.
+///
+/// # Arguments
+/// * `rate` - The discount rate
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or an Excel 1900 date serial (days since 1899-12-30). The first cash flow is
+///   assumed to be the initial investment date, the order of subsequent cash flows does not matter.
+/// * `day_count` - The day count convention used to convert the days between cash flows to years
+///
+/// `DayCount::Act360` and `DayCount::Act365F` only depend on the number of days between the dates, so any epoch
+/// may be used with them.
+///
+/// # Returns
+/// * The net present value (NPV)
+///
+/// # Example
+/// * 5% discount rate
+/// * Cash flows of $-100, $50, $40
+/// * Dates of 2024-01-01, 2024-07-01, 2025-01-01
+/// * ACT/360 day count
+///
+/// ```
+/// use rust_finprim::dates::DayCount;
+/// use rust_finprim::tvm::xnpv_with_day_count;
+///
+/// let rate = 0.05;
+/// let flows_table = vec![
+///    (-100.0, 45292),
+///    (50.0, 45474),
+///    (40.0, 45658),
+/// ];
+/// xnpv_with_day_count(rate, &flows_table, DayCount::Act360);
+/// ```
+///
+/// # Formula
+/// $$XNPV = \sum_{i=0}^{n} \frac{CF_i}{(1+r)^{YF(d_0, d_i)}}$$
+/// Where:
+/// * \\(CF_i\\) = cash flow \\(i\\)
+/// * \\(r\\) = discount rate
+/// * \\(YF(d_0, d_i)\\) = the year fraction between the initial date and the date of cash flow \\(i\\)
+pub fn xnpv_with_day_count<T: FloatLike, D: DateLike>(rate: T, flow_table: &[(T, D)], day_count: DayCount) -> T {
     // First date should be 0 (initial investment) and the rest should be difference from the initial date
     let init_date = flow_table.first().unwrap().1;
 
     let one_plus_r = T::one() + rate;
-    let yr_length: T = T::from_u16(365);
     flow_table
         .iter()
         .map(|&(cf, date)| {
-            let years = T::from_i32(date - init_date) / yr_length;
+            let years: T = day_count.year_fraction(init_date, date);
             cf / (one_plus_r).powf(years)
         })
         .sum()
 }
 
+#[cfg(feature = "std")]
+/// NPV With Curve - Net Present Value discounting off a yield curve
+///
+/// The net present value of a series of periodic cash flows, discounting each cash flow with the discount factor of
+/// a yield curve at its time rather than a single flat rate.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it uses `curve::YieldCurve`.
+///
+/// # Arguments
+/// * `curve` - The yield curve to discount off
+/// * `cash_flows` - A slice of cash flows, where each cash flow is at a specific period starting at period 0
+/// * `periods_per_year` (optional) - The number of periods per year used to convert periods to curve times in
+///   years, default is 1 (annual cash flows)
+///
+/// # Returns
+/// * The net present value (NPV)
+///
+/// # Example
+/// * Semi-annual cash flows discounted off an upward sloping curve
+/// ```
+/// use rust_finprim::curve::{Compounding, Interpolation, YieldCurve};
+/// use rust_finprim::tvm::npv_with_curve;
+///
+/// let curve = YieldCurve::from_zero_rates(
+///     &[0.5, 1.0, 2.0],
+///     &[0.03, 0.035, 0.04],
+///     Compounding::Periodic(2),
+///     Interpolation::LogLinear,
+/// );
+/// let cash_flows = [-100.0, 2.0, 2.0, 2.0, 102.0];
+/// npv_with_curve(&curve, &cash_flows, Some(2));
+/// ```
+///
+/// # Formula
+/// $$NPV = \sum_{t=0}^{n} CF_t \cdot DF(\frac{t}{m})$$
+/// Where:
+/// * \\(CF_t\\) = cash flow at period \\(t\\)
+/// * \\(DF\\) = discount factor of the curve
+/// * \\(m\\) = periods per year
+pub fn npv_with_curve<T: FloatLike>(curve: &YieldCurve<T>, cash_flows: &[T], periods_per_year: Option<u16>) -> T {
+    let periods_per_year = T::from_u16(periods_per_year.unwrap_or(1));
+    cash_flows
+        .iter()
+        .enumerate()
+        .map(|(t, &cf)| cf * curve.discount_factor(T::from_usize(t) / periods_per_year))
+        .sum()
+}
+
+#[cfg(feature = "std")]
+/// XNPV With Curve - Net Present Value for irregular cash flows discounting off a yield curve
+///
+/// The net present value of a series of cash flows that are not necessarily periodic, discounting each cash flow with
+/// the discount factor of a yield curve at its time rather than a single flat rate.
+///
+/// # Feature
+/// This function requires the `std` feature to be enabled as it uses `curve::YieldCurve`.
+///
+/// # Arguments
+/// * `curve` - The yield curve to discount off, with times measured from the first cash flow date
+/// * `flow_table` - A slice of tuples representing the cash flows and dates for each period `(cash_flow, date)`
+///   where `date` is a `dates::Date` or an Excel 1900 date serial (days since 1899-12-30). The first cash flow is
+///   assumed to be the valuation date, the order of subsequent cash flows does not matter.
+/// * `day_count` - The day count convention used to convert the dates to curve times in years
+///
+/// # Returns
+/// * The net present value (NPV)
+///
+/// # Example
+/// ```
+/// use rust_finprim::curve::{Compounding, Interpolation, YieldCurve};
+/// use rust_finprim::dates::{Date, DayCount};
+/// use rust_finprim::tvm::xnpv_with_curve;
+///
+/// let curve = YieldCurve::from_zero_rates(
+///     &[0.5, 1.0, 2.0],
+///     &[0.03, 0.035, 0.04],
+///     Compounding::Continuous,
+///     Interpolation::Linear,
+/// );
+/// let start = Date::new(2024, 1, 1).unwrap();
+/// let flow_table = vec![(-100.0, start), (50.0, start.add_months(9)), (55.0, start.add_months(18))];
+/// xnpv_with_curve(&curve, &flow_table, DayCount::Act365F);
+/// ```
+///
+/// # Formula
+/// $$XNPV = \sum_{i=0}^{n} CF_i \cdot DF(YF(d_0, d_i))$$
+/// Where:
+/// * \\(CF_i\\) = cash flow \\(i\\)
+/// * \\(DF\\) = discount factor of the curve
+/// * \\(YF(d_0, d_i)\\) = the year fraction between the initial date and the date of cash flow \\(i\\)
+pub fn xnpv_with_curve<T: FloatLike, D: DateLike>(
+    curve: &YieldCurve<T>,
+    flow_table: &[(T, D)],
+    day_count: DayCount,
+) -> T {
+    let init_date = flow_table.first().unwrap().1;
+    flow_table
+        .iter()
+        .map(|&(cf, date)| cf * curve.discount_factor(day_count.year_fraction(init_date, date)))
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,7 +360,7 @@ mod tests {
     #[cfg(not(feature = "std"))]
     extern crate std;
     #[cfg(not(feature = "std"))]
-    use std::{assert, vec, vec::Vec};
+    use std::{assert, assert_eq, vec, vec::Vec};
 
     #[test]
     fn test_xnpv() {
@@ -225,6 +378,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_xnpv_with_day_count() {
+        let rate = 0.05;
+        // 2024-01-01, 2024-07-01, 2025-01-01
+        let flows_table = vec![(-100.0, 45292), (50.0, 45474), (40.0, 45658)];
+
+        let result = xnpv_with_day_count(rate, &flows_table, DayCount::Act365F);
+        assert_eq!(result, xnpv(rate, &flows_table));
+
+        let result = xnpv_with_day_count(rate, &flows_table, DayCount::Thirty360);
+        let expected: f64 = -100.0 + 50.0 / 1.05f64.powf(0.5) + 40.0 / 1.05;
+        assert!(
+            (result - expected).abs() < 1e-10,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "30/360 discounts exact half and whole years",
+            expected,
+            result
+        );
+
+        let result = xnpv_with_day_count(rate, &flows_table, DayCount::Act360);
+        let expected: f64 = -100.0 + 50.0 / 1.05f64.powf(182.0 / 360.0) + 40.0 / 1.05f64.powf(366.0 / 360.0);
+        assert!(
+            (result - expected).abs() < 1e-10,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "ACT/360 counts the leap day",
+            expected,
+            result
+        );
+    }
+
+    #[test]
+    fn test_xnpv_dates() {
+        use crate::dates::Date;
+
+        let rate = 0.05;
+        let dates = [(2024, 1, 1), (2024, 7, 1), (2025, 1, 1)].map(|(y, m, d)| Date::new(y, m, d).unwrap());
+        let date_table = vec![(-100.0, dates[0]), (50.0, dates[1]), (40.0, dates[2])];
+        let serial_table = vec![(-100.0, 45292), (50.0, 45474), (40.0, 45658)];
+        assert_eq!(xnpv(rate, &date_table), xnpv(rate, &serial_table));
+        assert_eq!(
+            xnpv_with_day_count(rate, &date_table, DayCount::Thirty360),
+            xnpv_with_day_count(rate, &serial_table, DayCount::Thirty360)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_npv_with_curve() {
+        use crate::curve::{Compounding, Interpolation, YieldCurve};
+
+        // A flat curve discounts like a flat rate
+        let curve = YieldCurve::from_zero_rates(
+            &[1.0, 10.0],
+            &[0.05, 0.05],
+            Compounding::Periodic(1),
+            Interpolation::LogLinear,
+        );
+        let cash_flows = vec![-100.0, 50.0, 40.0, 30.0, 20.0];
+        let result = npv_with_curve(&curve, &cash_flows, None);
+        let expected = npv(0.05, &cash_flows);
+        assert!(
+            (result - expected).abs() < 1e-10,
+            "Failed on case: {}. Expected: {}, Result: {}",
+            "Flat curve",
+            expected,
+            result
+        );
+
+        let flows_table = vec![(-100.0, 0), (50.0, 365), (40.0, 730), (30.0, 1095), (20.0, 1460)];
+        let result = xnpv_with_curve(&curve, &flows_table, DayCount::Act365F);
+        assert!((result - xnpv(0.05, &flows_table)).abs() < 1e-10);
+
+        // Semi-annual periods read the curve at half years
+        let curve = YieldCurve::from_discount_factors(&[0.5, 1.0], &[0.98, 0.95], Interpolation::Linear);
+        let result = npv_with_curve(&curve, &[-1.0, 1.0, 1.0], Some(2));
+        assert!((result - (-1.0 + 0.98 + 0.95)).abs() < 1e-12);
+    }
+
     #[test]
     fn test_pv() {
         struct TestCase {
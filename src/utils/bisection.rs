@@ -0,0 +1,83 @@
+use crate::utils::bracket::opposite_signs;
+use crate::utils::solver::{SolverConfig, SolverMethod, SolverReport, Termination};
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// Bisection Method
+///
+/// Finds a root inside a bracket by repeatedly halving it. Slow, but it converges whenever the function changes
+/// sign over the bracket.
+///
+/// # Arguments
+/// * `lower` - The lower end of the bracket
+/// * `upper` - The upper end of the bracket
+/// * `f` - The function to find a root of, with opposite signs at `lower` and `upper`
+/// * `tolerance` - The search stops once \\(|f(x)|\\) or the width of the bracket is below the tolerance
+/// * `max_iter` - The maximum number of iterations to perform
+///
+/// # Returns
+/// * Result of the root
+/// * If `f` has the same sign at both ends, it returns a `RootFindingError::InvalidBracket`
+/// * If the root is not found within `max_iter` iterations, it returns a `RootFindingError::FailedToConverge`
+///
+/// # Example
+/// ```
+/// use rust_finprim::utils::bisection;
+///
+/// let root = bisection(0.0, 2.0, |x: f64| x * x - 2.0, 1e-10, 100).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-9);
+/// ```
+pub fn bisection<T: FloatLike, F>(lower: T, upper: T, f: F, tolerance: T, max_iter: u16) -> Result<T, FinPrimError<T>>
+where
+    F: Fn(T) -> T,
+{
+    let config = SolverConfig {
+        method: SolverMethod::Bisection,
+        tolerance,
+        step_tolerance: tolerance,
+        max_iter,
+        ..Default::default()
+    };
+    bisection_report(lower, upper, f, &config).root_or_error()
+}
+
+/// Bisection iterations over a bracket under a solver configuration, ignoring its method, bounds and fallback
+pub(crate) fn bisection_report<T: FloatLike, F>(lower: T, upper: T, f: F, config: &SolverConfig<T>) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+{
+    let report = |x, fx, iterations, reason| SolverReport::new(x, fx, iterations, SolverMethod::Bisection, reason);
+    let tolerance = config.tolerance;
+    let (mut a, mut b) = (lower, upper);
+    let mut fa = f(a);
+    let fb = f(b);
+    if fa.abs() < tolerance {
+        return report(a, fa, 0, Termination::Residual);
+    }
+    if fb.abs() < tolerance {
+        return report(b, fb, 0, Termination::Residual);
+    }
+    if !opposite_signs(fa, fb) {
+        return report(a, fa, 0, Termination::InvalidBracket);
+    }
+
+    let mut x = a;
+    let mut fx = fa;
+    for iter in 1..=config.max_iter {
+        x = (a + b) / T::two();
+        fx = f(x);
+        if fx.abs() < tolerance {
+            return report(x, fx, iter, Termination::Residual);
+        }
+        if (b - a).abs() < config.step_tolerance {
+            return report(x, fx, iter, Termination::StepSize);
+        }
+        if opposite_signs(fa, fx) {
+            b = x;
+        } else {
+            a = x;
+            fa = fx;
+        }
+    }
+    report(x, fx, config.max_iter, Termination::MaxIterations)
+}
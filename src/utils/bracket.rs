@@ -0,0 +1,111 @@
+use crate::error::RootFindingError;
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// Whether two function values have opposite signs, i.e. bracket a root
+#[inline(always)]
+pub(crate) fn opposite_signs<T: FloatLike>(a: T, b: T) -> bool {
+    (a < T::zero() && b > T::zero()) || (a > T::zero() && b < T::zero())
+}
+
+/// Expand Bracket - Find an interval containing a root
+///
+/// Grows an interval geometrically until the function has opposite signs at its ends, moving the end where the
+/// function is closer to zero. The result can be passed to `bisection`, `brent` or `illinois`.
+///
+/// # Arguments
+/// * `lower` - The initial lower end of the interval
+/// * `upper` - The initial upper end of the interval, must differ from `lower`
+/// * `f` - The function to bracket a root of
+/// * `factor` (optional) - The factor the interval grows by at each step, defaults to 1.6
+/// * `max_iter` - The maximum number of expansions
+///
+/// # Returns
+/// * Result of the `(lower, upper)` bracket
+/// * If no bracket is found, or the interval is empty, it returns a `RootFindingError::InvalidBracket`
+///
+/// # Example
+/// ```
+/// use rust_finprim::utils::expand_bracket;
+///
+/// let (lower, upper) = expand_bracket(0.0, 1.0, |x: f64| x * x - 10.0, None, 20).unwrap();
+/// assert!(lower <= 10f64.sqrt() && 10f64.sqrt() <= upper);
+/// ```
+pub fn expand_bracket<T: FloatLike, F>(
+    lower: T,
+    upper: T,
+    f: F,
+    factor: Option<T>,
+    max_iter: u16,
+) -> Result<(T, T), FinPrimError<T>>
+where
+    F: Fn(T) -> T,
+{
+    if lower == upper {
+        return Err(FinPrimError::RootFindingError(RootFindingError::InvalidBracket));
+    }
+    let factor = factor.unwrap_or(T::from_f32(1.6));
+    let (mut a, mut b) = if lower < upper { (lower, upper) } else { (upper, lower) };
+    let mut fa = f(a);
+    let mut fb = f(b);
+    for _ in 0..max_iter {
+        if fa.is_zero() || fb.is_zero() || opposite_signs(fa, fb) {
+            return Ok((a, b));
+        }
+        if fa.abs() < fb.abs() {
+            a += factor * (a - b);
+            fa = f(a);
+        } else {
+            b += factor * (b - a);
+            fb = f(b);
+        }
+    }
+    if fa.is_zero() || fb.is_zero() || opposite_signs(fa, fb) {
+        return Ok((a, b));
+    }
+    Err(FinPrimError::RootFindingError(RootFindingError::InvalidBracket))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{bisection, brent, illinois};
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_bracketing_solvers() {
+        // NPV of -100, 230, -132 has roots at 10% and 20%, Newton's method from 0 wanders off
+        let f = |r: f64| -100.0 + 230.0 / (1.0 + r) - 132.0 / ((1.0 + r) * (1.0 + r));
+        type Solver = fn(f64, f64, &dyn Fn(f64) -> f64, f64, u16) -> Result<f64, FinPrimError<f64>>;
+        let solvers: [(&str, Solver); 3] = [
+            ("bisection", |a, b, f, tol, n| bisection(a, b, f, tol, n)),
+            ("brent", |a, b, f, tol, n| brent(a, b, f, tol, n)),
+            ("illinois", |a, b, f, tol, n| illinois(a, b, f, tol, n)),
+        ];
+        for (name, solver) in solvers {
+            let result = solver(0.15, 1.0, &f, 1e-12, 200).unwrap();
+            assert!(
+                (result - 0.2).abs() < 1e-9,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                name,
+                0.2,
+                result
+            );
+            assert_eq!(
+                solver(0.3, 1.0, &f, 1e-12, 200),
+                Err(FinPrimError::RootFindingError(RootFindingError::InvalidBracket))
+            );
+        }
+
+        let (lower, upper) = expand_bracket(0.16, 0.17, f, None, 20).unwrap();
+        assert!(lower <= 0.2 && 0.2 <= upper);
+        assert_eq!(
+            expand_bracket(0.0, 1.0, |x: f64| x * x + 1.0, None, 20),
+            Err(FinPrimError::RootFindingError(RootFindingError::InvalidBracket))
+        );
+    }
+}
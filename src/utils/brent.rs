@@ -0,0 +1,142 @@
+use crate::utils::bracket::opposite_signs;
+use crate::utils::solver::{SolverConfig, SolverMethod, SolverReport, Termination};
+use crate::FinPrimError;
+use crate::FloatLike;
+
+/// Brent's Method
+///
+/// Finds a root inside a bracket by combining inverse quadratic interpolation and secant steps with bisection,
+/// falling back to bisection whenever an interpolated step would not shrink the bracket enough. It converges as
+/// reliably as bisection and usually about as fast as the secant method.
+///
+/// # Arguments
+/// * `lower` - The lower end of the bracket
+/// * `upper` - The upper end of the bracket
+/// * `f` - The function to find a root of, with opposite signs at `lower` and `upper`
+/// * `tolerance` - The search stops once \\(|f(x)|\\) or the width of the bracket is below the tolerance
+/// * `max_iter` - The maximum number of iterations to perform
+///
+/// # Returns
+/// * Result of the root
+/// * If `f` has the same sign at both ends, it returns a `RootFindingError::InvalidBracket`
+/// * If the root is not found within `max_iter` iterations, it returns a `RootFindingError::FailedToConverge`
+///
+/// # Example
+/// ```
+/// use rust_finprim::utils::brent;
+///
+/// let root = brent(0.0, 2.0, |x: f64| x * x - 2.0, 1e-10, 100).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-9);
+/// ```
+pub fn brent<T: FloatLike, F>(lower: T, upper: T, f: F, tolerance: T, max_iter: u16) -> Result<T, FinPrimError<T>>
+where
+    F: Fn(T) -> T,
+{
+    let config = SolverConfig {
+        method: SolverMethod::Brent,
+        tolerance,
+        step_tolerance: tolerance,
+        max_iter,
+        ..Default::default()
+    };
+    brent_report(lower, upper, f, &config).root_or_error()
+}
+
+/// Brent iterations over a bracket under a solver configuration, ignoring its method, bounds and fallback
+pub(crate) fn brent_report<T: FloatLike, F>(lower: T, upper: T, f: F, config: &SolverConfig<T>) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+{
+    let report = |x, fx, iterations, reason| SolverReport::new(x, fx, iterations, SolverMethod::Brent, reason);
+    let tolerance = config.tolerance;
+    let two = T::two();
+    let three = T::from_u16(3);
+    let (mut a, mut b) = (lower, upper);
+    let mut fa = f(a);
+    let mut fb = f(b);
+    if fa.abs() < tolerance {
+        return report(a, fa, 0, Termination::Residual);
+    }
+    if fb.abs() < tolerance {
+        return report(b, fb, 0, Termination::Residual);
+    }
+    if !opposite_signs(fa, fb) {
+        return report(a, fa, 0, Termination::InvalidBracket);
+    }
+
+    // `b` is the best estimate and `c` the other end of the bracket, `d` is the last step and `e` the one before
+    let mut c = b;
+    let mut fc = fb;
+    let mut d = b - a;
+    let mut e = d;
+    let half_tolerance = config.step_tolerance / two;
+    for iter in 0..config.max_iter {
+        if !opposite_signs(fb, fc) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let midpoint = (c - b) / two;
+        if fb.abs() < tolerance {
+            return report(b, fb, iter, Termination::Residual);
+        }
+        if midpoint.abs() <= half_tolerance {
+            return report(b, fb, iter, Termination::StepSize);
+        }
+
+        if e.abs() >= half_tolerance && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                // Secant step
+                (two * midpoint * s, T::one() - s)
+            } else {
+                // Inverse quadratic interpolation
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (two * midpoint * q * (q - r) - (b - a) * (r - T::one())),
+                    (q - T::one()) * (r - T::one()) * (s - T::one()),
+                )
+            };
+            if p > T::zero() {
+                q = -q;
+            }
+            p = p.abs();
+            let bound = (three * midpoint * q - (half_tolerance * q).abs()).min((e * q).abs());
+            if two * p < bound {
+                e = d;
+                d = p / q;
+            } else {
+                d = midpoint;
+                e = d;
+            }
+        } else {
+            d = midpoint;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        if d.abs() > half_tolerance {
+            b += d;
+        } else if midpoint > T::zero() {
+            b += half_tolerance;
+        } else {
+            b -= half_tolerance;
+        }
+        fb = f(b);
+    }
+    if fb.abs() < tolerance {
+        return report(b, fb, config.max_iter, Termination::Residual);
+    }
+    report(b, fb, config.max_iter, Termination::MaxIterations)
+}
@@ -1,4 +1,4 @@
-use crate::error::RootFindingError;
+use crate::utils::solver::{out_of_bounds, SolverConfig, SolverMethod, SolverReport, Termination};
 use crate::FinPrimError;
 use crate::FloatLike;
 
@@ -24,27 +24,51 @@ where
     D: Fn(T) -> T,
     D2: Fn(T) -> T,
 {
+    let config = SolverConfig {
+        method: SolverMethod::Halley,
+        tolerance,
+        max_iter,
+        ..Default::default()
+    };
+    halley_report(guess, f, f_prime, f_prime2, &config).root_or_error()
+}
+
+/// Halley iterations under a solver configuration, ignoring its method and fallback
+pub(crate) fn halley_report<T: FloatLike, F, D, D2>(
+    guess: T,
+    f: F,
+    f_prime: D,
+    f_prime2: D2,
+    config: &SolverConfig<T>,
+) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+    D: Fn(T) -> T,
+    D2: Fn(T) -> T,
+{
+    let report = |x, fx, iterations, reason| SolverReport::new(x, fx, iterations, SolverMethod::Halley, reason);
     let mut x = guess;
     let mut fx = f(x);
-    for _ in 0..max_iter {
-        if fx.abs() < tolerance {
-            return Ok(x);
-        }
-        let f_prime_x = f_prime(x);
-        let f_prime2 = f_prime2(x);
-
-        let (numerator, denominator) = step_halley(fx, f_prime_x, f_prime2);
+    if fx.abs() < config.tolerance {
+        return report(x, fx, 0, Termination::Residual);
+    }
+    for iter in 1..=config.max_iter {
+        let (numerator, denominator) = step_halley(fx, f_prime(x), f_prime2(x));
         if denominator.is_zero() {
-            return Err(FinPrimError::RootFindingError(RootFindingError::DivideByZero {
-                last_x: x,
-                last_fx: fx,
-            }));
+            return report(x, fx, iter - 1, Termination::ZeroDerivative);
         }
-        x -= numerator / denominator;
+        let step = numerator / denominator;
+        x -= step;
         fx = f(x);
+        if out_of_bounds(x, config.bounds) {
+            return report(x, fx, iter, Termination::OutOfBounds);
+        }
+        if fx.abs() < config.tolerance {
+            return report(x, fx, iter, Termination::Residual);
+        }
+        if step.abs() < config.step_tolerance {
+            return report(x, fx, iter, Termination::StepSize);
+        }
     }
-    Err(FinPrimError::RootFindingError(RootFindingError::FailedToConverge {
-        last_x: x,
-        last_fx: fx,
-    }))
+    report(x, fx, config.max_iter, Termination::MaxIterations)
 }
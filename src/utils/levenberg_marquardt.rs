@@ -0,0 +1,219 @@
+use crate::error::RootFindingError;
+use crate::FinPrimError;
+use crate::FloatLike;
+use core::cmp::Ordering;
+
+/// Levenberg-Marquardt - Nonlinear Least Squares
+///
+/// Finds the parameters that minimize the sum of squared residuals of a model, blending Gauss-Newton steps with
+/// gradient descent steps through a damping factor. The Jacobian is approximated with central differences, and the
+/// parameter count is fixed at compile time so the routine does not allocate.
+///
+/// # Arguments
+/// * `guess` - The initial parameters
+/// * `residual` - The residual of observation `i` for the given parameters, `residual(&params, i)`
+/// * `observations` - The number of observations, should be at least the number of parameters
+/// * `tolerance` - The fit has converged once no parameter moves by more than `tolerance` relative to its size
+/// * `max_iter` - The maximum number of iterations, including rejected steps
+///
+/// # Returns
+/// * Result of the fitted parameters
+/// * If the fit does not converge, it returns a `RootFindingError::FailedToConverge` where `last_x` is the relative
+///   size of the last step and `last_fx` is the sum of squared residuals
+/// * If the normal equations are singular or not a number, for example from a NaN residual, it returns a
+///   `RootFindingError::DivideByZero` where `last_x` is zero and `last_fx` is the sum of squared residuals
+///
+/// # Example
+/// * Fit \\(y = a e^{bx}\\) through three points
+/// ```
+/// use rust_finprim::utils::levenberg_marquardt;
+///
+/// let xs = [0.0, 1.0, 2.0];
+/// let ys = [2.0, 2.0 * 0.5f64.exp(), 2.0 * 1.0f64.exp()];
+/// let residual = |p: &[f64; 2], i: usize| p[0] * (p[1] * xs[i]).exp() - ys[i];
+/// let [a, b] = levenberg_marquardt([1.0, 0.1], residual, 3, 1e-12, 100).unwrap();
+/// assert!((a - 2.0).abs() < 1e-8 && (b - 0.5).abs() < 1e-8);
+/// ```
+pub fn levenberg_marquardt<T: FloatLike, F, const N: usize>(
+    guess: [T; N],
+    residual: F,
+    observations: usize,
+    tolerance: T,
+    max_iter: u16,
+) -> Result<[T; N], FinPrimError<T>>
+where
+    F: Fn(&[T; N], usize) -> T,
+{
+    let sum_of_squares = |params: &[T; N]| {
+        (0..observations)
+            .map(|i| {
+                let r = residual(params, i);
+                r * r
+            })
+            .sum::<T>()
+    };
+    let ten = T::from_u16(10);
+    let bump = T::from_f32(1e-6);
+    let floor = T::from_f32(1e-12);
+    let max_damping = T::from_f32(1e16);
+
+    let mut params = guess;
+    let mut cost = sum_of_squares(&params);
+    let mut damping = T::from_f32(1e-3);
+    let mut last_step = T::zero();
+    let mut iter = 0;
+    while iter < max_iter {
+        // Normal equations J^T J and J^T r
+        let mut jtj = [[T::zero(); N]; N];
+        let mut jtr = [T::zero(); N];
+        for i in 0..observations {
+            let r = residual(&params, i);
+            let mut row = [T::zero(); N];
+            for (k, derivative) in row.iter_mut().enumerate() {
+                let h = bump * (T::one() + params[k].abs());
+                let mut up = params;
+                let mut down = params;
+                up[k] += h;
+                down[k] -= h;
+                *derivative = (residual(&up, i) - residual(&down, i)) / (T::two() * h);
+            }
+            for a in 0..N {
+                jtr[a] += row[a] * r;
+                for b in 0..N {
+                    jtj[a][b] += row[a] * row[b];
+                }
+            }
+        }
+
+        // Try damped steps until one lowers the cost
+        loop {
+            iter += 1;
+            let mut system = jtj;
+            for (k, row) in system.iter_mut().enumerate() {
+                // The floor keeps parameters without influence from making the system singular
+                row[k] += damping * row[k].max(floor);
+            }
+            let Some(step) = solve(system, jtr.map(|x| -x)) else {
+                return Err(FinPrimError::RootFindingError(RootFindingError::DivideByZero {
+                    last_x: T::zero(),
+                    last_fx: cost,
+                }));
+            };
+
+            let mut trial = params;
+            last_step = T::zero();
+            for k in 0..N {
+                trial[k] += step[k];
+                last_step = last_step.max(step[k].abs() / (params[k].abs() + tolerance));
+            }
+            let trial_cost = sum_of_squares(&trial);
+            if trial_cost <= cost {
+                params = trial;
+                cost = trial_cost;
+                damping = damping / ten;
+                if last_step <= tolerance {
+                    return Ok(params);
+                }
+                break;
+            }
+            damping *= ten;
+            if damping > max_damping {
+                // No step lowers the cost, the parameters are at a minimum to working precision
+                return Ok(params);
+            }
+            if iter >= max_iter {
+                break;
+            }
+        }
+    }
+    Err(FinPrimError::RootFindingError(RootFindingError::FailedToConverge {
+        last_x: last_step,
+        last_fx: cost,
+    }))
+}
+
+/// Solves the linear system `a x = b` by Gaussian elimination with partial pivoting, `None` if it is singular or
+/// contains NaN
+fn solve<T: FloatLike, const N: usize>(mut a: [[T; N]; N], mut b: [T; N]) -> Option<[T; N]> {
+    // NaN is the only value not comparable to zero
+    if a.iter()
+        .flatten()
+        .chain(&b)
+        .any(|x| x.partial_cmp(&T::zero()).is_none())
+    {
+        return None;
+    }
+    for col in 0..N {
+        let pivot =
+            (col..N).max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap_or(Ordering::Equal))?;
+        if a[pivot][col].is_zero() {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..N {
+            let factor = a[row][col] / a[col][col];
+            let (pivot_rows, rows) = a.split_at_mut(row);
+            for (x, &p) in rows[0][col..].iter_mut().zip(&pivot_rows[col][col..]) {
+                *x -= factor * p;
+            }
+            let delta = factor * b[col];
+            b[row] -= delta;
+        }
+    }
+    let mut x = [T::zero(); N];
+    for row in (0..N).rev() {
+        let mut sum = b[row];
+        for k in row + 1..N {
+            sum -= a[row][k] * x[k];
+        }
+        x[row] = sum / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+
+    #[test]
+    fn test_levenberg_marquardt() {
+        // Noisy quadratic, the least squares solution of a linear model matches the normal equations
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let ys: [f64; 5] = [1.1, 1.9, 5.2, 9.8, 17.1];
+        let residual = |p: &[f64; 3], i: usize| p[0] + p[1] * xs[i] + p[2] * xs[i] * xs[i] - ys[i];
+        let result = levenberg_marquardt([0.0, 0.0, 0.0], residual, 5, 1e-12, 100).unwrap();
+        let expected = [1.0828571428571, -0.0957142857143, 1.0214285714286];
+        for k in 0..3 {
+            assert!(
+                (result[k] - expected[k]).abs() < 1e-6,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                k,
+                expected[k],
+                result[k]
+            );
+        }
+
+        // Too few iterations
+        let result = levenberg_marquardt([0.0, 0.0, 0.0], residual, 5, 1e-12, 1);
+        assert!(matches!(
+            result,
+            Err(FinPrimError::RootFindingError(
+                RootFindingError::FailedToConverge { .. }
+            ))
+        ));
+
+        // A NaN observation makes the normal equations unsolvable
+        let residual = |p: &[f64; 2], i: usize| p[0] + p[1] * xs[i] - [1.0, f64::NAN, 3.0][i];
+        let result = levenberg_marquardt([0.0, 0.0], residual, 3, 1e-12, 100);
+        assert!(matches!(
+            result,
+            Err(FinPrimError::RootFindingError(RootFindingError::DivideByZero { .. }))
+        ));
+    }
+}
@@ -5,3 +5,25 @@ pub use newton_raphson::newton_raphson;
 
 mod halley;
 pub use halley::halley;
+
+mod levenberg_marquardt;
+pub use levenberg_marquardt::levenberg_marquardt;
+
+mod bracket;
+pub use bracket::expand_bracket;
+
+mod bisection;
+pub use bisection::bisection;
+
+mod brent;
+pub use brent::brent;
+pub(crate) use brent::brent_report;
+
+mod illinois;
+pub use illinois::illinois;
+
+mod solver;
+pub use solver::{solve, SolverConfig, SolverMethod, SolverReport, Termination};
+
+mod normal;
+pub use normal::{norm_cdf, norm_pdf};
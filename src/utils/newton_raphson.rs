@@ -1,4 +1,4 @@
-use crate::error::RootFindingError;
+use crate::utils::solver::{out_of_bounds, SolverConfig, SolverMethod, SolverReport, Termination};
 use crate::FinPrimError;
 use crate::FloatLike;
 
@@ -13,24 +13,49 @@ where
     F: Fn(T) -> T,
     D: Fn(T) -> T,
 {
+    let config = SolverConfig {
+        method: SolverMethod::NewtonRaphson,
+        tolerance,
+        max_iter,
+        ..Default::default()
+    };
+    newton_raphson_report(guess, f, f_prime, &config).root_or_error()
+}
+
+/// Newton-Raphson iterations under a solver configuration, ignoring its method and fallback
+pub(crate) fn newton_raphson_report<T: FloatLike, F, D>(
+    guess: T,
+    f: F,
+    f_prime: D,
+    config: &SolverConfig<T>,
+) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+    D: Fn(T) -> T,
+{
+    let report = |x, fx, iterations, reason| SolverReport::new(x, fx, iterations, SolverMethod::NewtonRaphson, reason);
     let mut x = guess;
     let mut fx = f(x);
-    for _ in 0..max_iter {
-        if fx.abs() < tolerance {
-            return Ok(x);
-        }
+    if fx.abs() < config.tolerance {
+        return report(x, fx, 0, Termination::Residual);
+    }
+    for iter in 1..=config.max_iter {
         let f_prime_x = f_prime(x);
         if f_prime_x.is_zero() {
-            return Err(FinPrimError::RootFindingError(RootFindingError::DivideByZero {
-                last_x: x,
-                last_fx: fx,
-            }));
+            return report(x, fx, iter - 1, Termination::ZeroDerivative);
         }
-        x -= fx / f_prime_x;
+        let step = fx / f_prime_x;
+        x -= step;
         fx = f(x);
+        if out_of_bounds(x, config.bounds) {
+            return report(x, fx, iter, Termination::OutOfBounds);
+        }
+        if fx.abs() < config.tolerance {
+            return report(x, fx, iter, Termination::Residual);
+        }
+        if step.abs() < config.step_tolerance {
+            return report(x, fx, iter, Termination::StepSize);
+        }
     }
-    Err(FinPrimError::RootFindingError(RootFindingError::FailedToConverge {
-        last_x: x,
-        last_fx: fx,
-    }))
+    report(x, fx, config.max_iter, Termination::MaxIterations)
 }
@@ -0,0 +1,136 @@
+use crate::FloatLike;
+
+/// Tails with \\(x^2/2\\) above this are flushed to zero, keeping `exp` within the range of `Decimal`
+const MAX_HALF_SQUARE: u16 = 60;
+
+/// Normal PDF - Standard normal probability density function
+///
+/// # Arguments
+/// * `x` - The value to evaluate the density at
+///
+/// # Returns
+/// * The density of the standard normal distribution at `x`, densities below about 1e-26 are flushed to zero
+///
+/// # Example
+/// ```
+/// use rust_finprim::utils::norm_pdf;
+///
+/// let density: f64 = norm_pdf(0.0);
+/// assert!((density - 0.3989422804014327).abs() < 1e-15);
+/// ```
+///
+/// # Formula
+/// $$\phi(x) = \frac{1}{\sqrt{2\pi}} e^{-x^2/2}$$
+pub fn norm_pdf<T: FloatLike>(x: T) -> T {
+    let half_square = x * x / T::two();
+    if half_square > T::from_u16(MAX_HALF_SQUARE) {
+        return T::zero();
+    }
+    (-half_square).exp() / T::from_f64(2.5066282746310002)
+}
+
+/// Normal CDF - Standard normal cumulative distribution function
+///
+/// Uses Hart's (1968) rational approximation as given by West (2005), accurate to double precision. It only
+/// requires `exp`, so it is available in `no_std` through `libm` and for any `FloatLike` type.
+///
+/// # Arguments
+/// * `x` - The value to evaluate the distribution at
+///
+/// # Returns
+/// * The probability that a standard normal variable is at most `x`, tails below about 1e-26 are flushed to zero
+///
+/// # Example
+/// ```
+/// use rust_finprim::utils::norm_cdf;
+///
+/// let p: f64 = norm_cdf(1.96);
+/// assert!((p - 0.9750021048517795).abs() < 1e-15);
+/// ```
+///
+/// # Formula
+/// $$N(x) = \int_{-\infty}^{x} \phi(u) \\, du$$
+pub fn norm_cdf<T: FloatLike>(x: T) -> T {
+    let z = x.abs();
+    let half_square = z * z / T::two();
+    let tail = if half_square > T::from_u16(MAX_HALF_SQUARE) {
+        T::zero()
+    } else if z < T::from_f64(7.07106781186547) {
+        let numerator = [
+            0.700383064443688,
+            6.37396220353165,
+            33.912866078383,
+            112.079291497871,
+            221.213596169931,
+            220.206867912376,
+        ]
+        .iter()
+        .fold(T::from_f64(3.52624965998911e-2), |acc, &c| acc * z + T::from_f64(c));
+        let denominator = [
+            1.75566716318264,
+            16.064177579207,
+            86.7807322029461,
+            296.564248779674,
+            637.333633378831,
+            793.826512519948,
+            440.413735824752,
+        ]
+        .iter()
+        .fold(T::from_f64(8.83883476483184e-2), |acc, &c| acc * z + T::from_f64(c));
+        (-half_square).exp() * numerator / denominator
+    } else {
+        // Continued fraction for the far tail
+        let fraction = (1..=4)
+            .rev()
+            .fold(z + T::from_f64(0.65), |acc, k| z + T::from_u16(k) / acc);
+        (-half_square).exp() / fraction / T::from_f64(2.506628274631)
+    };
+    if x > T::zero() {
+        T::one() - tail
+    } else {
+        tail
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+
+    #[test]
+    fn test_norm_cdf() {
+        struct TestCase {
+            x: f64,
+            expected: f64,
+        }
+        impl TestCase {
+            fn new(x: f64, expected: f64) -> TestCase {
+                TestCase { x, expected }
+            }
+        }
+        let cases = [
+            TestCase::new(-3.0, 0.0013498980316300957),
+            TestCase::new(-1.0, 0.15865525393145707),
+            TestCase::new(0.0, 0.5),
+            TestCase::new(0.5, 0.6914624612740131),
+            TestCase::new(3.0, 0.9986501019683699),
+            TestCase::new(-8.0, 6.22096057427178e-16),
+            TestCase::new(-12.0, 0.0),
+        ];
+        for case in &cases {
+            let result = norm_cdf(case.x);
+            assert!(
+                (result - case.expected).abs() < 1e-15,
+                "Failed on case: {}. Expected: {}, Result: {}",
+                case.x,
+                case.expected,
+                result
+            );
+        }
+        assert!((norm_pdf(1.0) - 0.24197072451914337f64).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,360 @@
+use crate::error::RootFindingError;
+use crate::utils::bisection::bisection_report;
+use crate::utils::brent::brent_report;
+use crate::utils::expand_bracket;
+use crate::utils::halley::halley_report;
+use crate::utils::illinois::illinois_report;
+use crate::utils::newton_raphson::newton_raphson_report;
+use crate::FinPrimError;
+use crate::FloatLike;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Root Finding Method
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SolverMethod {
+    /// Newton-Raphson method, an open method using the first derivative
+    NewtonRaphson,
+    /// Halley's method, an open method using the first and second derivatives
+    #[default]
+    Halley,
+    /// Bisection, a bracketing method
+    Bisection,
+    /// Brent's method, a bracketing method
+    Brent,
+    /// Illinois method (modified regula falsi), a bracketing method
+    Illinois,
+}
+
+impl SolverMethod {
+    /// Whether the method searches within a bracket rather than from a guess
+    pub fn is_bracketing(&self) -> bool {
+        matches!(
+            self,
+            SolverMethod::Bisection | SolverMethod::Brent | SolverMethod::Illinois
+        )
+    }
+}
+
+/// Solver Configuration
+///
+/// The root finding method and its stopping criteria. Fields are public, start from the default and override what
+/// is needed, e.g. `SolverConfig { max_iter: 50, ..Default::default() }`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SolverConfig<T> {
+    /// The root finding method, defaults to `SolverMethod::Halley`
+    pub method: SolverMethod,
+    /// The search converges once \\(|f(x)|\\) is below the tolerance, defaults to 1e-10
+    pub tolerance: T,
+    /// The search converges once a step, or the width of the bracket, is below the step tolerance. Defaults to
+    /// zero, which only stops on the tolerance on \\(f(x)\\).
+    pub step_tolerance: T,
+    /// The maximum number of iterations, defaults to 100
+    pub max_iter: u16,
+    /// The `(lower, upper)` bounds of the search, defaults to none. Bracketing methods use the bounds as the
+    /// bracket, or expand a bracket around the guess without them. Open methods stop when an iterate leaves them.
+    pub bounds: Option<(T, T)>,
+    /// Whether an open method that fails falls back to Brent's method over the bounds, or a bracket expanded
+    /// around the guess, with at least 100 iterations. Defaults to true.
+    pub fallback: bool,
+}
+
+impl<T: FloatLike> Default for SolverConfig<T> {
+    fn default() -> Self {
+        SolverConfig {
+            method: SolverMethod::Halley,
+            tolerance: T::from_f32(1e-10),
+            step_tolerance: T::zero(),
+            max_iter: 100,
+            bounds: None,
+            fallback: true,
+        }
+    }
+}
+
+/// Why a solver stopped
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Termination {
+    /// \\(|f(x)|\\) fell below the tolerance
+    Residual,
+    /// The step, or the width of the bracket, fell below the step tolerance
+    StepSize,
+    /// The maximum number of iterations was reached
+    MaxIterations,
+    /// The derivative, or the denominator of Halley's step, was zero
+    ZeroDerivative,
+    /// The function has the same sign at both ends of the bracket, or no bracket was found
+    InvalidBracket,
+    /// An iterate left the bounds
+    OutOfBounds,
+}
+
+impl Termination {
+    /// Whether the solver stopped on a convergence criterion
+    pub fn converged(&self) -> bool {
+        matches!(self, Termination::Residual | Termination::StepSize)
+    }
+}
+
+/// Solver Report
+///
+/// The outcome of a root search, for diagnosing convergence.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SolverReport<T> {
+    /// The root, or the last estimate if the solver did not converge
+    pub root: T,
+    /// The function value at `root`
+    pub residual: T,
+    /// The number of iterations used, including any fallback
+    pub iterations: u16,
+    /// The method that produced `root`, which differs from the configured method after a fallback
+    pub method: SolverMethod,
+    /// Why the solver stopped
+    pub reason: Termination,
+}
+
+impl<T: FloatLike> SolverReport<T> {
+    pub(crate) fn new(root: T, residual: T, iterations: u16, method: SolverMethod, reason: Termination) -> Self {
+        SolverReport {
+            root,
+            residual,
+            iterations,
+            method,
+            reason,
+        }
+    }
+
+    /// `Ok` with the report if the solver converged, otherwise a `FinPrimError::SolverFailed` carrying it
+    pub(crate) fn check(self) -> Result<Self, FinPrimError<T>> {
+        if self.reason.converged() {
+            Ok(self)
+        } else {
+            Err(FinPrimError::SolverFailed(self))
+        }
+    }
+
+    /// The root, or the `RootFindingError` the standalone solvers return
+    pub(crate) fn root_or_error(self) -> Result<T, FinPrimError<T>> {
+        let error = match self.reason {
+            Termination::Residual | Termination::StepSize => return Ok(self.root),
+            Termination::ZeroDerivative => RootFindingError::DivideByZero {
+                last_x: self.root,
+                last_fx: self.residual,
+            },
+            Termination::InvalidBracket => RootFindingError::InvalidBracket,
+            Termination::MaxIterations | Termination::OutOfBounds => RootFindingError::FailedToConverge {
+                last_x: self.root,
+                last_fx: self.residual,
+            },
+        };
+        Err(FinPrimError::RootFindingError(error))
+    }
+}
+
+/// Whether `x` lies outside the bounds
+#[inline(always)]
+pub(crate) fn out_of_bounds<T: FloatLike>(x: T, bounds: Option<(T, T)>) -> bool {
+    bounds.is_some_and(|(lower, upper)| !(x >= lower.min(upper) && x <= lower.max(upper)))
+}
+
+/// Solve - Root finding with a configurable method
+///
+/// Finds a root of `f` with the method and stopping criteria of the configuration, and reports how the search went.
+///
+/// # Arguments
+/// * `f` - The function to find a root of
+/// * `f_prime` - The first derivative of `f`, used by Newton-Raphson and Halley's method
+/// * `f_prime2` - The second derivative of `f`, used by Halley's method
+/// * `guess` - The starting point of open methods, and the center of the bracket expanded for bracketing methods
+///   without bounds
+/// * `config` - The solver configuration
+///
+/// # Returns
+/// * The solver report, check `report.reason.converged()` before using the root
+///
+/// # Example
+/// ```
+/// use rust_finprim::utils::{solve, SolverConfig, SolverMethod, Termination};
+///
+/// let config = SolverConfig { method: SolverMethod::NewtonRaphson, ..Default::default() };
+/// let report = solve(|x: f64| x * x - 2.0, |x| 2.0 * x, |_| 2.0, 1.0, &config);
+/// assert_eq!(report.reason, Termination::Residual);
+/// assert!((report.root - 2f64.sqrt()).abs() < 1e-10);
+/// ```
+pub fn solve<T: FloatLike, F, D, D2>(
+    f: F,
+    f_prime: D,
+    f_prime2: D2,
+    guess: T,
+    config: &SolverConfig<T>,
+) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+    D: Fn(T) -> T,
+    D2: Fn(T) -> T,
+{
+    let report = match config.method {
+        SolverMethod::NewtonRaphson => newton_raphson_report(guess, &f, f_prime, config),
+        SolverMethod::Halley => halley_report(guess, &f, f_prime, f_prime2, config),
+        method => return bracketed(method, &f, guess, config, 0),
+    };
+    if report.reason.converged() || !config.fallback {
+        return report;
+    }
+    let fallback_config = SolverConfig {
+        max_iter: config.max_iter.max(100),
+        ..*config
+    };
+    let fallback = bracketed(SolverMethod::Brent, &f, guess, &fallback_config, report.iterations);
+    if fallback.reason.converged() {
+        fallback
+    } else {
+        SolverReport {
+            iterations: fallback.iterations,
+            ..report
+        }
+    }
+}
+
+/// Runs a bracketing method over the bounds, or a bracket expanded around the guess, adding `iterations` used
+/// before it to the report
+fn bracketed<T: FloatLike, F>(
+    method: SolverMethod,
+    f: F,
+    guess: T,
+    config: &SolverConfig<T>,
+    iterations: u16,
+) -> SolverReport<T>
+where
+    F: Fn(T) -> T,
+{
+    let bracket = match config.bounds {
+        Some(bounds) => Ok(bounds),
+        None => {
+            let step = T::from_f32(0.1) * (guess.abs() + T::one());
+            expand_bracket(guess - step, guess + step, &f, None, config.max_iter)
+        }
+    };
+    let mut report = match bracket {
+        Ok((lower, upper)) => match method {
+            SolverMethod::Bisection => bisection_report(lower, upper, &f, config),
+            SolverMethod::Illinois => illinois_report(lower, upper, &f, config),
+            _ => brent_report(lower, upper, &f, config),
+        },
+        Err(_) => SolverReport::new(guess, f(guess), 0, method, Termination::InvalidBracket),
+    };
+    report.iterations = report.iterations.saturating_add(iterations);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{assert, assert_eq};
+
+    #[test]
+    fn test_solve() {
+        let f = |x: f64| x * x * x - 2.0 * x - 5.0;
+        let f_prime = |x: f64| 3.0 * x * x - 2.0;
+        let f_prime2 = |x: f64| 6.0 * x;
+        let expected = 2.0945514815423265;
+
+        let methods = [
+            SolverMethod::NewtonRaphson,
+            SolverMethod::Halley,
+            SolverMethod::Bisection,
+            SolverMethod::Brent,
+            SolverMethod::Illinois,
+        ];
+        for method in methods {
+            let config = SolverConfig {
+                method,
+                bounds: method.is_bracketing().then_some((2.0, 3.0)),
+                ..Default::default()
+            };
+            let report = solve(f, f_prime, f_prime2, 2.0, &config);
+            assert!(
+                report.reason == Termination::Residual && (report.root - expected).abs() < 1e-9,
+                "Failed on case: {:?}. Expected: {}, Result: {:?}",
+                method,
+                expected,
+                report
+            );
+            assert_eq!(report.method, method);
+            assert!(report.iterations > 0 && report.residual.abs() < 1e-10);
+        }
+
+        // Newton-Raphson stalls on the stationary point of x^2 - 2 at zero, Brent takes over
+        let g = |x: f64| x * x - 2.0;
+        let g_prime = |x: f64| 2.0 * x;
+        let config = SolverConfig {
+            method: SolverMethod::NewtonRaphson,
+            ..Default::default()
+        };
+        let report = solve(g, g_prime, |_| 2.0, 0.0, &config);
+        assert_eq!(
+            (report.method, report.reason),
+            (SolverMethod::Brent, Termination::Residual)
+        );
+        let report = solve(
+            g,
+            g_prime,
+            |_| 2.0,
+            0.0,
+            &SolverConfig {
+                fallback: false,
+                ..config
+            },
+        );
+        assert_eq!(report.reason, Termination::ZeroDerivative);
+
+        // Stopping criteria
+        let config = SolverConfig {
+            max_iter: 3,
+            fallback: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            solve(f, f_prime, f_prime2, 10.0, &config).reason,
+            Termination::MaxIterations
+        );
+        let config = SolverConfig {
+            tolerance: 0.0,
+            step_tolerance: 1e-6,
+            max_iter: 20,
+            ..config
+        };
+        assert_eq!(solve(f, f_prime, f_prime2, 10.0, &config).reason, Termination::StepSize);
+        let config = SolverConfig {
+            bounds: Some((5.0, 20.0)),
+            ..Default::default()
+        };
+        let report = solve(
+            f,
+            f_prime,
+            f_prime2,
+            10.0,
+            &SolverConfig {
+                fallback: false,
+                ..config
+            },
+        );
+        assert_eq!(report.reason, Termination::OutOfBounds);
+        let config = SolverConfig {
+            method: SolverMethod::Brent,
+            ..config
+        };
+        assert_eq!(
+            solve(f, f_prime, f_prime2, 10.0, &config).reason,
+            Termination::InvalidBracket
+        );
+    }
+}